@@ -22,6 +22,7 @@ rustup target add wasm32-unknown-unknown
 # web_sys unstable APIs needed for copy to clipboard functionality
 export RUSTFLAGS="--cfg=web_sys_unstable_apis"
 
+./fetch-fonts.sh
 trunk build index.html --release --dist distrib
 
 mv distrib/webworker.js distrib/webworker${RANDOM_SUFFIX}.js
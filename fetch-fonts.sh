@@ -0,0 +1,11 @@
+#!/usr/bin/env bash
+# Downloads the fonts that the app fetches at runtime for the Korean and Chinese locales
+
+set -euxo pipefail
+
+FONT_DIR="assets/cjk-fonts"
+NOTO_CJK_URL="https://github.com/notofonts/noto-cjk/raw/main/Sans/SubsetOTF"
+
+mkdir -p "$FONT_DIR"
+[ -f "$FONT_DIR/NotoSansKR-Regular.otf" ] || curl -sSfL -o "$FONT_DIR/NotoSansKR-Regular.otf" "$NOTO_CJK_URL/KR/NotoSansKR-Regular.otf"
+[ -f "$FONT_DIR/NotoSansSC-Regular.otf" ] || curl -sSfL -o "$FONT_DIR/NotoSansSC-Regular.otf" "$NOTO_CJK_URL/SC/NotoSansSC-Regular.otf"
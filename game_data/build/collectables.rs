@@ -0,0 +1,119 @@
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use crate::utils::{field, CsvTable};
+
+// The data files below are only needed for the collectability thresholds.
+const COLLECTABLE_DATA_FILES: &[&str] = &[
+    "CollectablesShopItem.csv",
+    "CollectablesShopRefine.csv",
+    "CollectablesShopRewardScrip.csv",
+];
+
+const TIER_COLUMNS: [&str; 3] = ["Low", "Mid", "High"];
+
+pub struct CollectableOutput {
+    pub currency: u32,
+    /// Ordered from lowest to highest tier.
+    pub collectability: [u32; 3],
+    pub scrips: [u32; 3],
+}
+
+/// Reads the given columns of every row, keyed by the id of the row.
+fn rows_by_id(
+    table: &CsvTable,
+    columns: &[String],
+) -> Result<HashMap<u32, Vec<u32>>, Box<dyn Error>> {
+    let id_column = table.column("#")?;
+    let columns = columns
+        .iter()
+        .map(|name| table.column(name))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut rows = HashMap::new();
+    for record in table.records.iter() {
+        let values = columns
+            .iter()
+            .map(|column| field(record, *column))
+            .collect::<Result<Vec<u32>, _>>()?;
+        rows.insert(field(record, id_column)?, values);
+    }
+    Ok(rows)
+}
+
+pub fn import_collectable_records() -> Result<(), Box<dyn Error>> {
+    let dir = Path::new("data");
+    if let Some(path) = COLLECTABLE_DATA_FILES
+        .iter()
+        .find(|path| !dir.join(path).exists())
+    {
+        return Err(format!("data/{path} not found").into());
+    }
+
+    let mut collectables = phf_codegen::Map::new();
+    for (item_id, collectable) in import_collectables(dir)? {
+        let tiers: Vec<String> = (0..3)
+            .map(|tier| {
+                format!(
+                    "CollectableTier {{ collectability: {}, scrips: {} }}",
+                    collectable.collectability[tier], collectable.scrips[tier]
+                )
+            })
+            .collect();
+        collectables.entry(
+            item_id,
+            &format!(
+                "Collectable {{ currency: {}, tiers: [{}] }}",
+                collectable.currency,
+                tiers.join(", ")
+            ),
+        );
+    }
+
+    let out_path = Path::new(&std::env::var("OUT_DIR")?).join("collectables.rs");
+    let mut writer = BufWriter::new(File::create(out_path).unwrap());
+    writeln!(writer, "{}", collectables.build())?;
+    Ok(())
+}
+
+/// Reads the collectability thresholds and scrip rewards of every collectable from the data files in `dir`.
+pub fn import_collectables(dir: &Path) -> Result<BTreeMap<u32, CollectableOutput>, Box<dyn Error>> {
+    let refine_columns = TIER_COLUMNS.map(|tier| format!("{tier}Collectability"));
+    let thresholds = rows_by_id(
+        &CsvTable::read(dir.join("CollectablesShopRefine.csv"))?,
+        &refine_columns,
+    )?;
+    let mut reward_columns = vec!["Currency".to_string()];
+    reward_columns.extend(TIER_COLUMNS.map(|tier| format!("{tier}Reward")));
+    let rewards = rows_by_id(
+        &CsvTable::read(dir.join("CollectablesShopRewardScrip.csv"))?,
+        &reward_columns,
+    )?;
+
+    let shop_items = CsvTable::read(dir.join("CollectablesShopItem.csv"))?;
+    let item_column = shop_items.column("Item")?;
+    let refine_column = shop_items.column("CollectablesShopRefine")?;
+    let reward_column = shop_items.column("CollectablesShopRewardScrip")?;
+    let mut items = BTreeMap::new();
+    for record in shop_items.records.iter() {
+        let item_id: u32 = field(record, item_column)?;
+        let (Some(thresholds), Some(rewards)) = (
+            thresholds.get(&field(record, refine_column)?),
+            rewards.get(&field(record, reward_column)?),
+        ) else {
+            continue;
+        };
+        if item_id == 0 || thresholds.iter().all(|&threshold| threshold == 0) {
+            continue;
+        }
+        // the same item can be turned in at several shops, the first one is used
+        items.entry(item_id).or_insert_with(|| CollectableOutput {
+            currency: rewards[0],
+            collectability: [thresholds[0], thresholds[1], thresholds[2]],
+            scrips: [rewards[1], rewards[2], rewards[3]],
+        });
+    }
+    Ok(items)
+}
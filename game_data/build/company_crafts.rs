@@ -0,0 +1,181 @@
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use crate::utils::{field, CsvTable};
+
+// The data files below are only needed for company crafts (airship and submarine parts, housing, etc.).
+const COMPANY_CRAFT_DATA_FILES: &[&str] = &[
+    "CompanyCraftSequence.csv",
+    "CompanyCraftPart.csv",
+    "CompanyCraftProcess.csv",
+    "CompanyCraftSupplyItem.csv",
+];
+
+const PARTS_PER_SEQUENCE: usize = 8;
+const PROCESSES_PER_PART: usize = 3;
+const SUPPLIES_PER_PROCESS: usize = 12;
+
+pub struct CompanyCraftSupplyOutput {
+    pub item_id: u32,
+    pub set_quantity: u32,
+    pub sets_required: u32,
+}
+
+pub struct CompanyCraftPartOutput {
+    /// Supply items of every phase (process) of the part, in order.
+    pub phases: Vec<Vec<CompanyCraftSupplyOutput>>,
+}
+
+pub struct CompanyCraftOutput {
+    pub item_id: u32,
+    pub parts: Vec<CompanyCraftPartOutput>,
+}
+
+/// Reads the values of the columns `{name}[0]` to `{name}[count - 1]`.
+fn array_field(
+    table: &CsvTable,
+    record: &csv::StringRecord,
+    name: &str,
+    count: usize,
+) -> Result<Vec<u32>, Box<dyn Error>> {
+    (0..count)
+        .map(|index| Ok(field(record, table.column(&format!("{name}[{index}]"))?)?))
+        .collect()
+}
+
+fn ids_to_rows(table: &CsvTable) -> Result<HashMap<u32, &csv::StringRecord>, Box<dyn Error>> {
+    let id_column = table.column("#")?;
+    let mut rows = HashMap::new();
+    for record in table.records.iter() {
+        rows.insert(field(record, id_column)?, record);
+    }
+    Ok(rows)
+}
+
+pub fn import_company_craft_records(
+    relevant_items: &mut HashSet<u32>,
+) -> Result<(), Box<dyn Error>> {
+    let dir = Path::new("data");
+    if let Some(path) = COMPANY_CRAFT_DATA_FILES
+        .iter()
+        .find(|path| !dir.join(path).exists())
+    {
+        return Err(format!("data/{path} not found").into());
+    }
+
+    let out_path = Path::new(&std::env::var("OUT_DIR")?).join("company_crafts.rs");
+    let mut writer = BufWriter::new(File::create(out_path).unwrap());
+    writeln!(writer, "&[")?;
+    for sequence in import_company_crafts(dir)? {
+        relevant_items.insert(sequence.item_id);
+        let mut parts: Vec<String> = Vec::new();
+        for part in sequence.parts {
+            let mut phases: Vec<String> = Vec::new();
+            for supplies in part.phases {
+                let supplies: Vec<String> = supplies
+                    .into_iter()
+                    .map(|supply| {
+                        relevant_items.insert(supply.item_id);
+                        format!(
+                            "CompanyCraftSupply {{ item_id: {}, set_quantity: {}, sets_required: {} }}",
+                            supply.item_id, supply.set_quantity, supply.sets_required
+                        )
+                    })
+                    .collect();
+                phases.push(format!(
+                    "CompanyCraftPhase {{ supplies: &[{}] }}",
+                    supplies.join(", ")
+                ));
+            }
+            parts.push(format!(
+                "CompanyCraftPart {{ phases: &[{}] }}",
+                phases.join(", ")
+            ));
+        }
+        writeln!(
+            writer,
+            "CompanyCraftSequence {{ item_id: {}, parts: &[{}] }},",
+            sequence.item_id,
+            parts.join(", ")
+        )?;
+    }
+    writeln!(writer, "]")?;
+    Ok(())
+}
+
+/// Reads the parts, phases and supply items of every company craft from the data files in `dir`.
+/// Sequences without any phase are skipped.
+pub fn import_company_crafts(dir: &Path) -> Result<Vec<CompanyCraftOutput>, Box<dyn Error>> {
+    let supply_items = CsvTable::read(dir.join("CompanyCraftSupplyItem.csv"))?;
+    let item_column = supply_items.column("Item")?;
+    let supply_items: HashMap<u32, u32> = ids_to_rows(&supply_items)?
+        .into_iter()
+        .map(|(id, record)| Ok((id, field(record, item_column)?)))
+        .collect::<Result<_, Box<dyn Error>>>()?;
+    let processes = CsvTable::read(dir.join("CompanyCraftProcess.csv"))?;
+    let process_rows = ids_to_rows(&processes)?;
+    let parts = CsvTable::read(dir.join("CompanyCraftPart.csv"))?;
+    let part_rows = ids_to_rows(&parts)?;
+
+    let sequence_table = CsvTable::read(dir.join("CompanyCraftSequence.csv"))?;
+    let result_column = sequence_table.column("ResultItem")?;
+    let mut sequences = Vec::new();
+    for record in sequence_table.records.iter() {
+        let item_id: u32 = field(record, result_column)?;
+        if item_id == 0 {
+            continue;
+        }
+        let mut sequence_parts = Vec::new();
+        for part_id in array_field(
+            &sequence_table,
+            record,
+            "CompanyCraftPart",
+            PARTS_PER_SEQUENCE,
+        )? {
+            let Some(part) = part_rows.get(&part_id).filter(|_| part_id != 0) else {
+                continue;
+            };
+            let mut phases = Vec::new();
+            for process_id in array_field(&parts, part, "CompanyCraftProcess", PROCESSES_PER_PART)?
+            {
+                let Some(process) = process_rows.get(&process_id).filter(|_| process_id != 0)
+                else {
+                    continue;
+                };
+                let items = array_field(&processes, process, "SupplyItem", SUPPLIES_PER_PROCESS)?;
+                let set_quantities =
+                    array_field(&processes, process, "SetQuantity", SUPPLIES_PER_PROCESS)?;
+                let sets_required =
+                    array_field(&processes, process, "SetsRequired", SUPPLIES_PER_PROCESS)?;
+                let mut supplies = Vec::new();
+                for index in 0..SUPPLIES_PER_PROCESS {
+                    let Some(&supply_item) = supply_items.get(&items[index]) else {
+                        continue;
+                    };
+                    if supply_item == 0 || sets_required[index] == 0 {
+                        continue;
+                    }
+                    supplies.push(CompanyCraftSupplyOutput {
+                        item_id: supply_item,
+                        set_quantity: set_quantities[index],
+                        sets_required: sets_required[index],
+                    });
+                }
+                phases.push(supplies);
+            }
+            if !phases.is_empty() {
+                sequence_parts.push(CompanyCraftPartOutput { phases });
+            }
+        }
+        if !sequence_parts.is_empty() {
+            sequences.push(CompanyCraftOutput {
+                item_id,
+                parts: sequence_parts,
+            });
+        }
+    }
+    Ok(sequences)
+}
@@ -6,22 +6,6 @@ use std::path::Path;
 use crate::records::{ItemActionRecord, ItemFoodRecord, ItemRecord};
 use crate::utils::read_csv_data;
 
-// https://github.com/xivapi/ffxiv-datamining/blob/35e435494317723be856f18fb3b48f526316656e/docs/ItemActions.md#845
-const ITEM_ACTION_BATTLE_FOOD_TYPE_ID: u32 = 844;
-const ITEM_ACTION_DOH_FOOD_TYPE_ID: u32 = 845;
-const ITEM_ACTION_DOH_POTION_TYPE_ID: u32 = 846;
-const VALID_ITEM_ACTION_TYPE_IDS: &[u32] = &[
-    ITEM_ACTION_BATTLE_FOOD_TYPE_ID,
-    ITEM_ACTION_DOH_FOOD_TYPE_ID,
-    ITEM_ACTION_DOH_POTION_TYPE_ID,
-];
-
-// https://github.com/xivapi/ffxiv-datamining/blob/35e435494317723be856f18fb3b48f526316656e/csv/BaseParam.csv
-const CRAFTSMANSHIP_PARAM_ID: u32 = 70;
-const CONTROL_PARAM_ID: u32 = 71;
-const CP_PARAM_ID: u32 = 11;
-const VALID_PARAMS: &[u32] = &[CRAFTSMANSHIP_PARAM_ID, CONTROL_PARAM_ID, CP_PARAM_ID];
-
 pub fn import_consumable_records(
     relevant_items: &mut HashSet<u32>,
 ) -> Result<(), Box<dyn std::error::Error>> {
@@ -33,19 +17,16 @@ pub fn import_consumable_records(
 
     let mut consumable_by_item_action_id = HashMap::new();
     for item_action in read_csv_data::<ItemActionRecord>("data/ItemAction.csv") {
-        if !VALID_ITEM_ACTION_TYPE_IDS.contains(&item_action.type_id) {
+        if !item_action.is_consumable() {
             continue;
         }
 
-        let is_potion = item_action.type_id == ITEM_ACTION_DOH_POTION_TYPE_ID;
         let item_food = *item_food_by_id.get(&item_action.data_1).unwrap();
-
-        let params: &[u32] = &[item_food.param_0, item_food.param_1, item_food.param_2];
-        if params.iter().any(|param| VALID_PARAMS.contains(param)) {
+        if item_food.has_crafting_params() {
             consumable_by_item_action_id.insert(
                 item_action.id,
                 Consumable {
-                    is_potion,
+                    is_potion: item_action.is_potion(),
                     item_food,
                 },
             );
@@ -63,7 +44,7 @@ pub fn import_consumable_records(
 
             relevant_items.insert(item.id);
 
-            let (craftsmanship, control, cp) = get_stats(item_food);
+            let (craftsmanship, control, cp) = item_food.crafting_stats();
 
             let consumable = ConsumableOutput {
                 item_id: item.id,
@@ -108,45 +89,6 @@ pub fn import_consumable_records(
     Ok(())
 }
 
-#[allow(clippy::type_complexity)]
-#[rustfmt::skip]
-fn get_stats(item_food: ItemFoodRecord) -> ([u32; 4], [u32; 4], [u32; 4]) {
-    let ItemFoodRecord {
-        id: _,
-        param_0, param_0_relative, param_0_value, param_0_max, param_0_hq_value, param_0_hq_max,
-        param_1, param_1_relative, param_1_value, param_1_max, param_1_hq_value, param_1_hq_max,
-        param_2, param_2_relative, param_2_value, param_2_max, param_2_hq_value, param_2_hq_max,
-    } = item_food;
-
-    let mut craftsmanship = [0; 4];
-    let mut control = [0; 4];
-    let mut cp = [0; 4];
-
-    for (param, is_relative, value, max, hq_value, hq_max) in [
-        (param_0, param_0_relative, param_0_value, param_0_max, param_0_hq_value, param_0_hq_max),
-        (param_1, param_1_relative, param_1_value, param_1_max, param_1_hq_value, param_1_hq_max),
-        (param_2, param_2_relative, param_2_value, param_2_max, param_2_hq_value, param_2_hq_max),
-    ] {
-        let values: [u32; 4] = [value as u32, max, hq_value as u32, hq_max];
-
-        if param == CRAFTSMANSHIP_PARAM_ID {
-            assert!(is_relative);
-            assert!(values.iter().all(|&v| v > 0));
-            craftsmanship = values;
-        } else if param == CONTROL_PARAM_ID {
-            assert!(is_relative);
-            assert!(values.iter().all(|&v| v > 0));
-            control = values;
-        } else if param == CP_PARAM_ID {
-            assert!(is_relative);
-            assert!(values.iter().all(|&v| v > 0));
-            cp = values;
-        }
-    }
-
-    (craftsmanship, control, cp)
-}
-
 struct Consumable {
     is_potion: bool,
     item_food: ItemFoodRecord,
@@ -0,0 +1,261 @@
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use crate::utils::{field, CsvTable};
+
+// The data files below are only needed for the gear and materia tables (in addition to `en/Item.csv`).
+const GEAR_DATA_FILES: &[&str] = &[
+    "Materia.csv",
+    "ItemLevel.csv",
+    "BaseParam.csv",
+    "ClassJobCategory.csv",
+];
+
+// https://github.com/xivapi/ffxiv-datamining/blob/35e435494317723be856f18fb3b48f526316656e/csv/BaseParam.csv
+const CRAFTSMANSHIP_PARAM_ID: u32 = 70;
+const CONTROL_PARAM_ID: u32 = 71;
+const CP_PARAM_ID: u32 = 11;
+/// Same order as the fields of `GearStats`.
+const CRAFTING_PARAMS: [u32; 3] = [CRAFTSMANSHIP_PARAM_ID, CONTROL_PARAM_ID, CP_PARAM_ID];
+const ITEM_LEVEL_COLUMNS: [&str; 3] = ["Craftsmanship", "Control", "CP"];
+
+// Indexed by EquipSlotCategory
+// https://github.com/xivapi/ffxiv-datamining/blob/35e435494317723be856f18fb3b48f526316656e/csv/EquipSlotCategory.csv
+const SLOT_PERCENTAGE_COLUMNS: [&str; 14] = [
+    "",
+    "1HWpn%",
+    "OH%",
+    "Head%",
+    "Chest%",
+    "Hands%",
+    "Waist%",
+    "Legs%",
+    "Feet%",
+    "Earring%",
+    "Necklace%",
+    "Bracelet%",
+    "Ring%",
+    "2HWpn%",
+];
+
+// Indexed by job id
+const JOB_COLUMNS: [&str; 8] = ["CRP", "BSM", "ARM", "GSM", "LTW", "WVR", "ALC", "CUL"];
+
+pub struct GearOutput {
+    pub item_id: u32,
+    pub equip_slot: u8,
+    pub item_level: u32,
+    pub equip_level: u8,
+    pub jobs: u8,
+    pub stats: [u32; 3],
+    pub hq_stats: [u32; 3],
+    pub meld_caps: [u32; 3],
+    pub materia_slots: u8,
+    pub advanced_melding: bool,
+}
+
+pub struct MateriaOutput {
+    pub item_id: u32,
+    pub grade: u8,
+    pub stats: [u32; 3],
+}
+
+fn export_stats(stats: [u32; 3]) -> String {
+    format!(
+        "GearStats {{ craftsmanship: {}, control: {}, cp: {} }}",
+        stats[0], stats[1], stats[2]
+    )
+}
+
+pub fn import_gear_records(relevant_items: &mut HashSet<u32>) -> Result<(), Box<dyn Error>> {
+    let dir = Path::new("data");
+    if let Some(path) = GEAR_DATA_FILES.iter().find(|path| !dir.join(path).exists()) {
+        return Err(format!("data/{path} not found").into());
+    }
+    let gear = import_gear(dir)?;
+    let materia = import_materia(dir)?;
+
+    let out_path = Path::new(&std::env::var("OUT_DIR")?).join("gear.rs");
+    let mut writer = BufWriter::new(File::create(out_path).unwrap());
+    writeln!(writer, "&[")?;
+    for item in gear {
+        relevant_items.insert(item.item_id);
+        writeln!(writer, "GearItem {{ item_id: {}, equip_slot: {}, item_level: {}, equip_level: {}, jobs: {}, stats: {}, hq_stats: {}, meld_caps: {}, materia_slots: {}, advanced_melding: {} }},", item.item_id, item.equip_slot, item.item_level, item.equip_level, item.jobs, export_stats(item.stats), export_stats(item.hq_stats), export_stats(item.meld_caps), item.materia_slots, item.advanced_melding)?;
+    }
+    writeln!(writer, "]")?;
+
+    let out_path = Path::new(&std::env::var("OUT_DIR")?).join("materia.rs");
+    let mut writer = BufWriter::new(File::create(out_path).unwrap());
+    writeln!(writer, "&[")?;
+    for materia in materia {
+        relevant_items.insert(materia.item_id);
+        writeln!(
+            writer,
+            "Materia {{ item_id: {}, grade: {}, stats: {} }},",
+            materia.item_id,
+            materia.grade,
+            export_stats(materia.stats)
+        )?;
+    }
+    writeln!(writer, "]")?;
+
+    Ok(())
+}
+
+/// Reads the crafter gear from the data files in `dir`.
+pub fn import_gear(dir: &Path) -> Result<Vec<GearOutput>, Box<dyn Error>> {
+    // bitmask of crafter jobs for each ClassJobCategory
+    let class_job_categories = CsvTable::read(dir.join("ClassJobCategory.csv"))?;
+    let id_column = class_job_categories.column("#")?;
+    let job_columns = JOB_COLUMNS
+        .iter()
+        .map(|job| class_job_categories.column(job))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut jobs_by_category: HashMap<u32, u8> = HashMap::new();
+    for record in class_job_categories.records.iter() {
+        let mut jobs = 0;
+        for (job_id, column) in job_columns.iter().enumerate() {
+            if field::<u8>(record, *column)? != 0 {
+                jobs |= 1 << job_id;
+            }
+        }
+        jobs_by_category.insert(field(record, id_column)?, jobs);
+    }
+
+    // stat values that the meld caps are based on, for each item level
+    let item_levels = CsvTable::read(dir.join("ItemLevel.csv"))?;
+    let id_column = item_levels.column("#")?;
+    let stat_columns = ITEM_LEVEL_COLUMNS
+        .iter()
+        .map(|name| item_levels.column(name))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut stats_by_item_level: HashMap<u32, [u32; 3]> = HashMap::new();
+    for record in item_levels.records.iter() {
+        let mut stats = [0; 3];
+        for (stat, column) in stats.iter_mut().zip(stat_columns.iter()) {
+            *stat = field(record, *column)?;
+        }
+        stats_by_item_level.insert(field(record, id_column)?, stats);
+    }
+
+    // per-slot percentage of the item level stat values
+    let base_params = CsvTable::read(dir.join("BaseParam.csv"))?;
+    let id_column = base_params.column("#")?;
+    let mut slot_percentages = [[0u32; 14]; 3];
+    for record in base_params.records.iter() {
+        let param: u32 = field(record, id_column)?;
+        if let Some(index) = CRAFTING_PARAMS.iter().position(|&p| p == param) {
+            for (slot, name) in SLOT_PERCENTAGE_COLUMNS.iter().enumerate().skip(1) {
+                slot_percentages[index][slot] = field(record, base_params.column(name)?)?;
+            }
+        }
+    }
+
+    let items = CsvTable::read(dir.join("en/Item.csv"))?;
+    let id_column = items.column("#")?;
+    let item_level_column = items.column("Level{Item}")?;
+    let equip_level_column = items.column("Level{Equip}")?;
+    let equip_slot_column = items.column("EquipSlotCategory")?;
+    let class_job_category_column = items.column("ClassJobCategory")?;
+    let materia_slots_column = items.column("MateriaSlotCount")?;
+    let advanced_melding_column = items.column("IsAdvancedMeldingPermitted")?;
+    let mut param_columns = Vec::new();
+    for i in 0..6 {
+        param_columns.push((
+            items.column(&format!("BaseParam[{i}]"))?,
+            items.column(&format!("BaseParamValue[{i}]"))?,
+            items.column(&format!("BaseParam{{Special}}[{i}]"))?,
+            items.column(&format!("BaseParamValue{{Special}}[{i}]"))?,
+        ));
+    }
+
+    let mut gear = Vec::new();
+    for record in items.records.iter() {
+        let equip_slot: u8 = field(record, equip_slot_column)?;
+        let category: u32 = field(record, class_job_category_column)?;
+        let jobs = jobs_by_category.get(&category).copied().unwrap_or(0);
+        if jobs == 0 || equip_slot == 0 || equip_slot as usize >= SLOT_PERCENTAGE_COLUMNS.len() {
+            continue;
+        }
+
+        let mut stats = [0; 3];
+        let mut hq_bonus = [0; 3];
+        for (param_column, value_column, special_column, special_value_column) in
+            param_columns.iter()
+        {
+            let param: u32 = field(record, *param_column)?;
+            if let Some(index) = CRAFTING_PARAMS.iter().position(|&p| p == param) {
+                stats[index] += field::<u32>(record, *value_column)?;
+            }
+            let param: u32 = field(record, *special_column)?;
+            if let Some(index) = CRAFTING_PARAMS.iter().position(|&p| p == param) {
+                hq_bonus[index] += field::<u32>(record, *special_value_column)?;
+            }
+        }
+        if stats.iter().all(|&stat| stat == 0) {
+            continue;
+        }
+
+        let item_level: u32 = field(record, item_level_column)?;
+        let item_level_stats = stats_by_item_level
+            .get(&item_level)
+            .ok_or(format!("unknown item level {item_level}"))?;
+        let mut meld_caps = [0; 3];
+        for index in 0..3 {
+            let percentage = slot_percentages[index][equip_slot as usize];
+            meld_caps[index] = (item_level_stats[index] * percentage + 500) / 1000;
+        }
+
+        gear.push(GearOutput {
+            item_id: field(record, id_column)?,
+            equip_slot,
+            item_level,
+            equip_level: field(record, equip_level_column)?,
+            jobs,
+            stats,
+            hq_stats: [0, 1, 2].map(|index| stats[index] + hq_bonus[index]),
+            meld_caps,
+            materia_slots: field(record, materia_slots_column)?,
+            advanced_melding: field::<u8>(record, advanced_melding_column)? != 0,
+        });
+    }
+    Ok(gear)
+}
+
+/// Reads the crafter materia from the data files in `dir`.
+pub fn import_materia(dir: &Path) -> Result<Vec<MateriaOutput>, Box<dyn Error>> {
+    let table = CsvTable::read(dir.join("Materia.csv"))?;
+    let param_column = table.column("BaseParam")?;
+    let mut grade_columns = Vec::new();
+    while let (Ok(item_column), Ok(value_column)) = (
+        table.column(&format!("Item[{}]", grade_columns.len())),
+        table.column(&format!("Value[{}]", grade_columns.len())),
+    ) {
+        grade_columns.push((item_column, value_column));
+    }
+
+    let mut materia = Vec::new();
+    for record in table.records.iter() {
+        let param: u32 = field(record, param_column)?;
+        let Some(index) = CRAFTING_PARAMS.iter().position(|&p| p == param) else {
+            continue;
+        };
+        for (grade, (item_column, value_column)) in grade_columns.iter().enumerate() {
+            let item_id: u32 = field(record, *item_column)?;
+            if item_id == 0 {
+                continue;
+            }
+            let mut stats = [0; 3];
+            stats[index] = field(record, *value_column)?;
+            materia.push(MateriaOutput {
+                item_id,
+                grade: grade as u8 + 1,
+                stats,
+            });
+        }
+    }
+    Ok(materia)
+}
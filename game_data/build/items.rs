@@ -29,6 +29,8 @@ pub fn import_item_records(
     import_item_names(&relevant_items, "de")?;
     import_item_names(&relevant_items, "fr")?;
     import_item_names(&relevant_items, "jp")?;
+    import_item_names(&relevant_items, "ko")?;
+    import_item_names(&relevant_items, "cn")?;
 
     Ok(())
 }
@@ -37,10 +39,12 @@ fn import_item_names(
     relevant_items: &HashSet<u32>,
     lang: &'static str,
 ) -> Result<(), Box<dyn std::error::Error>> {
+    let path = format!("data/{}/Item.csv", lang);
+    if !Path::new(&path).exists() {
+        return Err(format!("{path} not found").into());
+    }
     let mut item_names = phf_codegen::Map::new();
-    for item in read_csv_data::<ItemRecord>(format!("data/{}/Item.csv", lang))
-        .filter(|item| relevant_items.contains(&item.id))
-    {
+    for item in read_csv_data::<ItemRecord>(path).filter(|item| relevant_items.contains(&item.id)) {
         item_names.entry(item.id, &format!("\"{}\"", item.name));
     }
     let out_path = Path::new(&std::env::var("OUT_DIR")?).join(format!("item_names_{}.rs", lang));
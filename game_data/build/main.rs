@@ -2,7 +2,10 @@ mod records;
 use records::*;
 use utils::read_csv_data;
 
+mod collectables;
+mod company_crafts;
 mod consumables;
+mod gear;
 mod items;
 mod utils;
 
@@ -26,6 +29,9 @@ fn import_game_data() -> Result<(), Box<dyn std::error::Error>> {
     import_recipe_records(&mut relevant_items, &rlvls)?;
 
     consumables::import_consumable_records(&mut relevant_items)?;
+    gear::import_gear_records(&mut relevant_items)?;
+    collectables::import_collectable_records()?;
+    company_crafts::import_company_craft_records(&mut relevant_items)?;
     items::import_item_records(relevant_items)?;
     Ok(())
 }
@@ -51,11 +57,8 @@ fn import_recipe_records(
     relevant_items: &mut HashSet<u32>,
     rlvls: &[RecipeLevelRecord],
 ) -> Result<(), Box<dyn std::error::Error>> {
-    fn apply_factor(base: u32, factor: u32) -> u32 {
-        base * factor / 100
-    }
-
     let mut recipes = Vec::new();
+    let mut patch_number = 0;
 
     for recipe_record in read_csv_data::<RecipeRecord>("data/Recipe.csv") {
         // skip the debug recipe (item id 0)
@@ -63,42 +66,36 @@ fn import_recipe_records(
             continue;
         }
 
+        patch_number = std::cmp::max(patch_number, recipe_record.patch_number);
+
         relevant_items.insert(recipe_record.resulting_item);
-        relevant_items.insert(recipe_record.ingredient_id_0);
-        relevant_items.insert(recipe_record.ingredient_id_1);
-        relevant_items.insert(recipe_record.ingredient_id_2);
-        relevant_items.insert(recipe_record.ingredient_id_3);
-        relevant_items.insert(recipe_record.ingredient_id_4);
-        relevant_items.insert(recipe_record.ingredient_id_5);
-
-        let ingredients = format!(
-                "[Ingredient {{ item_id: {}, amount: {} }}, Ingredient {{ item_id: {}, amount: {} }}, Ingredient {{ item_id: {}, amount: {} }}, Ingredient {{ item_id: {}, amount: {} }}, Ingredient {{ item_id: {}, amount: {} }}, Ingredient {{ item_id: {}, amount: {} }}]",
-                recipe_record.ingredient_id_0,
-                recipe_record.ingredient_amount_0,
-                recipe_record.ingredient_id_1,
-                recipe_record.ingredient_amount_1,
-                recipe_record.ingredient_id_2,
-                recipe_record.ingredient_amount_2,
-                recipe_record.ingredient_id_3,
-                recipe_record.ingredient_amount_3,
-                recipe_record.ingredient_id_4,
-                recipe_record.ingredient_amount_4,
-                recipe_record.ingredient_id_5,
-                recipe_record.ingredient_amount_5
-            );
+        for (item_id, _) in recipe_record.ingredients() {
+            relevant_items.insert(item_id);
+        }
+
+        let ingredients = recipe_record
+            .ingredients()
+            .map(|(item_id, amount)| {
+                format!("Ingredient {{ item_id: {item_id}, amount: {amount} }}")
+            })
+            .join(", ");
 
         let rlvl_record = &rlvls[recipe_record.recipe_level as usize];
-        let recipe = format!("Recipe {{ job_id: {job_id}, item_id: {item_id}, level: {level}, recipe_level: {recipe_level}, progress: {progress}, quality: {quality}, durability: {durability}, material_quality_factor: {material_quality_factor}, ingredients: {ingredients}, is_expert: {is_expert} }}",
+        let recipe = format!("Recipe {{ job_id: {job_id}, item_id: {item_id}, level: {level}, recipe_level: {recipe_level}, progress: {progress}, quality: {quality}, durability: {durability}, material_quality_factor: {material_quality_factor}, ingredients: [{ingredients}], is_expert: {is_expert}, required_craftsmanship: {required_craftsmanship}, required_control: {required_control}, required_quality: {required_quality}, result_amount: {result_amount} }}",
                 job_id = recipe_record.job_id,
                 item_id = recipe_record.resulting_item,
                 level = rlvl_record.level,
                 recipe_level = recipe_record.recipe_level,
-                progress = apply_factor(rlvl_record.progress, recipe_record.progress_factor),
-                quality = apply_factor(rlvl_record.quality, recipe_record.quality_factor),
-                durability = apply_factor(rlvl_record.durability, recipe_record.durability_factor),
+                progress = recipe_record.progress(rlvl_record),
+                quality = recipe_record.quality(rlvl_record),
+                durability = recipe_record.durability(rlvl_record),
                 material_quality_factor = recipe_record.material_quality_factor,
                 ingredients = ingredients,
-                is_expert = recipe_record.is_expert
+                is_expert = recipe_record.is_expert,
+                required_craftsmanship = recipe_record.required_craftsmanship,
+                required_control = recipe_record.required_control,
+                required_quality = recipe_record.required_quality,
+                result_amount = recipe_record.result_amount,
         );
 
         recipes.push(recipe);
@@ -112,5 +109,9 @@ fn import_recipe_records(
     }
     writeln!(writer, "]")?;
 
+    let out_path = Path::new(&std::env::var("OUT_DIR")?).join("version.rs");
+    let mut writer = BufWriter::new(File::create(out_path).unwrap());
+    writeln!(writer, "\"{}\"", patch_string(patch_number))?;
+
     Ok(())
 }
@@ -1,5 +1,33 @@
+//! CSV record types and the logic to interpret them.
+//! This file is shared between the build script and the runtime CSV loader of the library.
+
 use serde::{de, Deserialize};
 
+// https://github.com/xivapi/ffxiv-datamining/blob/35e435494317723be856f18fb3b48f526316656e/docs/ItemActions.md#845
+const ITEM_ACTION_BATTLE_FOOD_TYPE_ID: u32 = 844;
+const ITEM_ACTION_DOH_FOOD_TYPE_ID: u32 = 845;
+const ITEM_ACTION_DOH_POTION_TYPE_ID: u32 = 846;
+const VALID_ITEM_ACTION_TYPE_IDS: &[u32] = &[
+    ITEM_ACTION_BATTLE_FOOD_TYPE_ID,
+    ITEM_ACTION_DOH_FOOD_TYPE_ID,
+    ITEM_ACTION_DOH_POTION_TYPE_ID,
+];
+
+// https://github.com/xivapi/ffxiv-datamining/blob/35e435494317723be856f18fb3b48f526316656e/csv/BaseParam.csv
+const CRAFTSMANSHIP_PARAM_ID: u32 = 70;
+const CONTROL_PARAM_ID: u32 = 71;
+const CP_PARAM_ID: u32 = 11;
+const VALID_PARAMS: &[u32] = &[CRAFTSMANSHIP_PARAM_ID, CONTROL_PARAM_ID, CP_PARAM_ID];
+
+/// Formats a `PatchNumber` value (e.g. 700) as a patch string (e.g. "7.0").
+pub fn patch_string(patch_number: u32) -> String {
+    let (major, minor) = (patch_number / 100, patch_number % 100);
+    match minor % 10 {
+        0 => format!("{}.{}", major, minor / 10),
+        _ => format!("{}.{:02}", major, minor),
+    }
+}
+
 fn bool_string<'de, D>(deserializer: D) -> Result<bool, D::Error>
 where
     D: de::Deserializer<'de>,
@@ -22,6 +50,16 @@ pub struct ItemActionRecord {
     pub data_1: u32,
 }
 
+impl ItemActionRecord {
+    pub fn is_consumable(&self) -> bool {
+        VALID_ITEM_ACTION_TYPE_IDS.contains(&self.type_id)
+    }
+
+    pub fn is_potion(&self) -> bool {
+        self.type_id == ITEM_ACTION_DOH_POTION_TYPE_ID
+    }
+}
+
 #[derive(Clone, Copy, Deserialize)]
 pub struct ItemFoodRecord {
     #[serde(rename = "#")]
@@ -67,6 +105,55 @@ pub struct ItemFoodRecord {
     pub param_2_hq_max: u32,
 }
 
+impl ItemFoodRecord {
+    pub fn has_crafting_params(&self) -> bool {
+        [self.param_0, self.param_1, self.param_2]
+            .iter()
+            .any(|param| VALID_PARAMS.contains(param))
+    }
+
+    /// Returns the (craftsmanship, control, cp) bonuses of the item food.
+    /// Each bonus is `[relative value, max value, HQ relative value, HQ max value]`.
+    #[allow(clippy::type_complexity)]
+    #[rustfmt::skip]
+    pub fn crafting_stats(&self) -> ([u32; 4], [u32; 4], [u32; 4]) {
+        let ItemFoodRecord {
+            id: _,
+            param_0, param_0_relative, param_0_value, param_0_max, param_0_hq_value, param_0_hq_max,
+            param_1, param_1_relative, param_1_value, param_1_max, param_1_hq_value, param_1_hq_max,
+            param_2, param_2_relative, param_2_value, param_2_max, param_2_hq_value, param_2_hq_max,
+        } = *self;
+
+        let mut craftsmanship = [0; 4];
+        let mut control = [0; 4];
+        let mut cp = [0; 4];
+
+        for (param, is_relative, value, max, hq_value, hq_max) in [
+            (param_0, param_0_relative, param_0_value, param_0_max, param_0_hq_value, param_0_hq_max),
+            (param_1, param_1_relative, param_1_value, param_1_max, param_1_hq_value, param_1_hq_max),
+            (param_2, param_2_relative, param_2_value, param_2_max, param_2_hq_value, param_2_hq_max),
+        ] {
+            let values: [u32; 4] = [value as u32, max, hq_value as u32, hq_max];
+
+            if param == CRAFTSMANSHIP_PARAM_ID {
+                assert!(is_relative);
+                assert!(values.iter().all(|&v| v > 0));
+                craftsmanship = values;
+            } else if param == CONTROL_PARAM_ID {
+                assert!(is_relative);
+                assert!(values.iter().all(|&v| v > 0));
+                control = values;
+            } else if param == CP_PARAM_ID {
+                assert!(is_relative);
+                assert!(values.iter().all(|&v| v > 0));
+                cp = values;
+            }
+        }
+
+        (craftsmanship, control, cp)
+    }
+}
+
 #[derive(Deserialize)]
 pub struct ItemRecord {
     #[serde(rename = "#")]
@@ -89,6 +176,8 @@ pub struct ItemRecord {
 pub struct RecipeRecord {
     #[serde(rename = "Item{Result}")]
     pub resulting_item: u32,
+    #[serde(rename = "Amount{Result}")]
+    pub result_amount: u32,
     #[serde(rename = "CraftType")]
     pub job_id: u8,
 
@@ -103,6 +192,13 @@ pub struct RecipeRecord {
     #[serde(rename = "MaterialQualityFactor")]
     pub material_quality_factor: u32,
 
+    #[serde(rename = "RequiredCraftsmanship")]
+    pub required_craftsmanship: u32,
+    #[serde(rename = "RequiredControl")]
+    pub required_control: u32,
+    #[serde(rename = "RequiredQuality")]
+    pub required_quality: u32,
+
     #[serde(rename = "Item{Ingredient}[0]")]
     pub ingredient_id_0: u32,
     #[serde(rename = "Amount{Ingredient}[0]")]
@@ -131,6 +227,35 @@ pub struct RecipeRecord {
     #[serde(rename = "IsExpert")]
     #[serde(deserialize_with = "bool_string")]
     pub is_expert: bool,
+
+    #[serde(rename = "PatchNumber")]
+    pub patch_number: u32,
+}
+
+impl RecipeRecord {
+    /// Returns the (item id, amount) pairs of the ingredients.
+    pub fn ingredients(&self) -> [(u32, u32); 6] {
+        [
+            (self.ingredient_id_0, self.ingredient_amount_0),
+            (self.ingredient_id_1, self.ingredient_amount_1),
+            (self.ingredient_id_2, self.ingredient_amount_2),
+            (self.ingredient_id_3, self.ingredient_amount_3),
+            (self.ingredient_id_4, self.ingredient_amount_4),
+            (self.ingredient_id_5, self.ingredient_amount_5),
+        ]
+    }
+
+    pub fn progress(&self, rlvl: &RecipeLevelRecord) -> u32 {
+        rlvl.progress * self.progress_factor / 100
+    }
+
+    pub fn quality(&self, rlvl: &RecipeLevelRecord) -> u32 {
+        rlvl.quality * self.quality_factor / 100
+    }
+
+    pub fn durability(&self, rlvl: &RecipeLevelRecord) -> u32 {
+        rlvl.durability * self.durability_factor / 100
+    }
 }
 
 #[derive(Deserialize)]
@@ -1,4 +1,6 @@
+use std::error::Error;
 use std::path::Path;
+use std::str::FromStr;
 
 pub fn read_csv_data<RecordType>(path: impl AsRef<Path>) -> impl Iterator<Item = RecordType>
 where
@@ -7,3 +9,37 @@ where
     let reader = csv::Reader::from_path(path).unwrap();
     reader.into_deserialize::<RecordType>().map(|r| r.unwrap())
 }
+
+/// Some of the data files have duplicate column names and long arrays of columns,
+/// so they are accessed by column name instead of being deserialized into records.
+pub struct CsvTable {
+    pub headers: csv::StringRecord,
+    pub records: Vec<csv::StringRecord>,
+}
+
+impl CsvTable {
+    pub fn read(path: impl AsRef<Path>) -> Result<Self, Box<dyn Error>> {
+        let mut reader = csv::Reader::from_path(path)?;
+        let headers = reader.headers()?.clone();
+        let records = reader.records().collect::<Result<_, _>>()?;
+        Ok(Self { headers, records })
+    }
+
+    /// Returns the index of the first column with the given name.
+    pub fn column(&self, name: &str) -> Result<usize, String> {
+        self.headers
+            .iter()
+            .position(|header| header == name)
+            .ok_or(format!("column {name} not found"))
+    }
+}
+
+pub fn field<T: FromStr>(record: &csv::StringRecord, column: usize) -> Result<T, String> {
+    let value = record.get(column).unwrap_or_default().trim();
+    match value {
+        "True" => T::from_str("1"),
+        "False" => T::from_str("0"),
+        value => T::from_str(value),
+    }
+    .map_err(|_| format!("invalid value {value}"))
+}
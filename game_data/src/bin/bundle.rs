@@ -0,0 +1,23 @@
+//! Converts the CSV files of the game into a binary bundle that can be loaded with `GameData::from_bundle`.
+//!
+//! Usage: `cargo run -p game-data --bin bundle -- <data dir> <output file>`
+
+use game_data::{GameData, GameDataSource};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = std::env::args().collect();
+    let [_, data_dir, output_file] = args.as_slice() else {
+        return Err("usage: bundle <data dir> <output file>".into());
+    };
+    let game_data = GameData::from_csv(data_dir)?;
+    let bundle = game_data.to_bundle()?;
+    std::fs::write(output_file, &bundle)?;
+    println!(
+        "Wrote game data for patch {} ({} recipes, {} bytes) to {}",
+        game_data.version(),
+        game_data.recipes().len(),
+        bundle.len(),
+        output_file
+    );
+    Ok(())
+}
@@ -0,0 +1,104 @@
+//! Solves every phase of every part of a company craft and prints a macro for each crafted supply item.
+//!
+//! Usage: `cargo run --release -p game-data --bin company_craft -- <item id> <craftsmanship> <control> <cp> <level> [--game-version <patch>]`
+//!
+//! The same stats are used for every job. The game version defaults to the version of the game data. Supply items without a recipe (e.g. gathered items) are listed without a macro.
+
+use game_data::{
+    action_name, get_company_craft, get_game_settings_for_version, get_item_name, get_job_name,
+    BuiltinGameData, CompanyCraftPhase, CrafterStats, GameDataSource, Locale, QualityTarget,
+    RecipeConfiguration,
+};
+use simulator::{state::InProgress, GameVersion, SimulationState};
+use solvers::MacroSolver;
+
+fn print_phase(
+    phase: &CompanyCraftPhase,
+    crafter_stats: CrafterStats,
+    game_version: GameVersion,
+) -> Result<(), Box<dyn std::error::Error>> {
+    for supply in phase.supplies {
+        println!(
+            "  {} x{}",
+            get_item_name(supply.item_id, false, Locale::EN),
+            supply.total_quantity()
+        );
+    }
+    for job_id in phase.jobs() {
+        let job_name = get_job_name(job_id, Locale::EN);
+        for (supply, recipe) in phase.recipes(job_id) {
+            let item_name = get_item_name(supply.item_id, false, Locale::EN);
+            let recipe_config = RecipeConfiguration {
+                recipe,
+                hq_ingredients: [0; 6],
+            };
+            let mut settings = match get_game_settings_for_version(
+                recipe_config,
+                crafter_stats,
+                None,
+                None,
+                false,
+                game_version,
+            ) {
+                Ok(settings) => settings,
+                Err(message) => {
+                    println!("  [{job_name}] {item_name}: {message}");
+                    continue;
+                }
+            };
+            QualityTarget::Full.apply(&recipe, &mut settings);
+            let actions = MacroSolver::new(settings, Box::new(|_| {}))
+                .solve(InProgress::new(&settings), false);
+            match actions {
+                Some(actions) => {
+                    let state = SimulationState::from_macro(&settings, &actions)?;
+                    let quality = settings.max_quality - state.get_missing_quality();
+                    let action_names: Vec<&str> = actions
+                        .iter()
+                        .map(|action| action_name(*action, Locale::EN))
+                        .collect();
+                    println!(
+                        "  [{job_name}] {item_name} ({quality}/{} Quality): {}",
+                        settings.max_quality,
+                        action_names.join(", ")
+                    );
+                }
+                None => println!("  [{job_name}] {item_name}: cannot be completed"),
+            }
+        }
+    }
+    Ok(())
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = std::env::args().collect();
+    let usage =
+        "usage: company_craft <item id> <craftsmanship> <control> <cp> <level> [--game-version <patch>]";
+    let [_, item_id, craftsmanship, control, cp, level, options @ ..] = args.as_slice() else {
+        return Err(usage.into());
+    };
+    let game_version = match options {
+        [] => BuiltinGameData.game_version(),
+        [option, patch] if option == "--game-version" => {
+            GameVersion::from_patch(patch).ok_or("unsupported game version")?
+        }
+        _ => return Err(usage.into()),
+    };
+    let sequence = get_company_craft(item_id.parse()?).ok_or("Unknown company craft")?;
+    let crafter_stats = CrafterStats {
+        craftsmanship: craftsmanship.parse()?,
+        control: control.parse()?,
+        cp: cp.parse()?,
+        level: level.parse()?,
+        manipulation: true,
+    };
+
+    println!("{}", get_item_name(sequence.item_id, false, Locale::EN));
+    for (part_index, part) in sequence.parts.iter().enumerate() {
+        for (phase_index, phase) in part.phases.iter().enumerate() {
+            println!("\nPart {}, phase {}", part_index + 1, phase_index + 1);
+            print_phase(phase, crafter_stats, game_version)?;
+        }
+    }
+    Ok(())
+}
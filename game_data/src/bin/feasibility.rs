@@ -0,0 +1,66 @@
+//! Prints a CSV report of the recipes of a job that the crafter can complete and HQ.
+//!
+//! Usage: `cargo run --release -p game-data --bin feasibility -- <job id> <craftsmanship> <control> <cp> <level> [--full] [--food <item id>] [--potion <item id>] [--game-version <patch>]`
+//!
+//! Food and potion are assumed to be HQ. The game version defaults to the version of the game data, e.g. `--game-version 6.5` simulates with the Endwalker rules. With `--full`, every recipe is solved to get the exact Quality, which takes much longer.
+
+use game_data::{
+    feasibility_csv, feasibility_report, BuiltinGameData, Consumable, CrafterStats, GameDataSource,
+    Locale, MEALS, POTIONS,
+};
+use simulator::GameVersion;
+
+fn find_consumable(consumables: &[Consumable], item_id: &str) -> Result<Consumable, String> {
+    let item_id: u32 = item_id
+        .parse()
+        .map_err(|_| format!("invalid item id {item_id}"))?;
+    consumables
+        .iter()
+        .find(|consumable| consumable.item_id == item_id && consumable.hq)
+        .copied()
+        .ok_or(format!("unknown consumable {item_id}"))
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = std::env::args().collect();
+    let usage = "usage: feasibility <job id> <craftsmanship> <control> <cp> <level> [--full] [--food <item id>] [--potion <item id>] [--game-version <patch>]";
+    let [_, job_id, craftsmanship, control, cp, level, options @ ..] = args.as_slice() else {
+        return Err(usage.into());
+    };
+    let crafter_stats = CrafterStats {
+        craftsmanship: craftsmanship.parse()?,
+        control: control.parse()?,
+        cp: cp.parse()?,
+        level: level.parse()?,
+        manipulation: true,
+    };
+
+    let mut full_solve = false;
+    let mut food = None;
+    let mut potion = None;
+    let mut game_version = BuiltinGameData.game_version();
+    let mut options = options.iter();
+    while let Some(option) = options.next() {
+        match option.as_str() {
+            "--full" => full_solve = true,
+            "--food" => food = Some(find_consumable(MEALS, options.next().ok_or(usage)?)?),
+            "--potion" => potion = Some(find_consumable(POTIONS, options.next().ok_or(usage)?)?),
+            "--game-version" => {
+                game_version = GameVersion::from_patch(options.next().ok_or(usage)?)
+                    .ok_or("unsupported game version")?
+            }
+            _ => return Err(usage.into()),
+        }
+    }
+
+    let report = feasibility_report(
+        job_id.parse()?,
+        crafter_stats,
+        food,
+        potion,
+        game_version,
+        full_solve,
+    );
+    println!("{}", feasibility_csv(&report, Locale::EN));
+    Ok(())
+}
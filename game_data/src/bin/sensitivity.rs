@@ -0,0 +1,77 @@
+//! Prints how many additional points of each stat improve the optimal rotation of a recipe.
+//!
+//! Usage: `cargo run --release -p game-data --bin sensitivity -- <recipe index> <craftsmanship> <control> <cp> <level> [max points] [--game-version <patch>]`
+//!
+//! No food, potion or HQ ingredients are used. Max points defaults to 100 and the game version defaults to the version of the game data.
+
+use game_data::{
+    bottleneck_stat, stat_sensitivity, BuiltinGameData, CrafterStats, GameDataSource,
+    RecipeConfiguration, RECIPES,
+};
+use simulator::GameVersion;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = std::env::args().collect();
+    let usage = "usage: sensitivity <recipe index> <craftsmanship> <control> <cp> <level> [max points] [--game-version <patch>]";
+    let [_, recipe_id, craftsmanship, control, cp, level, options @ ..] = args.as_slice() else {
+        return Err(usage.into());
+    };
+    let (max_points, game_version) = match options {
+        [] => ("100", None),
+        [option, patch] if option == "--game-version" => ("100", Some(patch)),
+        [max_points] => (max_points.as_str(), None),
+        [max_points, option, patch] if option == "--game-version" => {
+            (max_points.as_str(), Some(patch))
+        }
+        _ => return Err(usage.into()),
+    };
+    let max_points: u16 = max_points.parse()?;
+    let game_version = match game_version {
+        Some(patch) => GameVersion::from_patch(patch).ok_or("unsupported game version")?,
+        None => BuiltinGameData.game_version(),
+    };
+    let recipe_id: usize = recipe_id.parse()?;
+    let recipe = *RECIPES.get(recipe_id).ok_or("unknown recipe index")?;
+    let crafter_stats = CrafterStats {
+        craftsmanship: craftsmanship.parse()?,
+        control: control.parse()?,
+        cp: cp.parse()?,
+        level: level.parse()?,
+        manipulation: true,
+    };
+    let recipe_config = RecipeConfiguration {
+        recipe,
+        hq_ingredients: [0; 6],
+    };
+
+    let sensitivities = stat_sensitivity(
+        recipe_config,
+        crafter_stats,
+        None,
+        None,
+        game_version,
+        max_points,
+    )?;
+    for sensitivity in sensitivities.iter() {
+        match sensitivity.improvement {
+            Some((points, quality)) => println!(
+                "{}: +{} improves Quality to {}",
+                sensitivity.stat, points, quality
+            ),
+            None => println!(
+                "{}: no improvement within +{}",
+                sensitivity.stat, max_points
+            ),
+        }
+        for breakpoint in sensitivity.breakpoints.iter() {
+            println!(
+                "  +{}: base Progress {}, base Quality {}",
+                breakpoint.points, breakpoint.base_progress, breakpoint.base_quality
+            );
+        }
+    }
+    if let Some(stat) = bottleneck_stat(&sensitivities) {
+        println!("Bottleneck: {}", stat);
+    }
+    Ok(())
+}
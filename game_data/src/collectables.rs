@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+
+use crate::QualityTarget;
+
+pub static COLLECTABLES: phf::Map<u32, Collectable> =
+    include!(concat!(env!("OUT_DIR"), "/collectables.rs"));
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CollectableTier {
+    pub collectability: u16,
+    /// Number of scrips rewarded for turning in an item of this tier.
+    pub scrips: u16,
+}
+
+impl CollectableTier {
+    /// Collectability is a tenth of the quality, rounded down.
+    pub const fn min_quality(self) -> u16 {
+        self.collectability * 10
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Collectable {
+    /// Currency id of the scrip reward, as in `CollectablesShopRewardScrip`.
+    pub currency: u8,
+    /// Ordered from lowest to highest collectability.
+    pub tiers: [CollectableTier; 3],
+}
+
+impl Collectable {
+    /// Returns the highest tier (1 to 3) reached with the given quality, or 0 if no tier is reached.
+    pub fn tier(&self, quality: u16) -> usize {
+        self.tiers
+            .iter()
+            .filter(|tier| quality >= tier.min_quality())
+            .count()
+    }
+}
+
+pub fn get_collectable(item_id: u32) -> Option<&'static Collectable> {
+    COLLECTABLES.get(&item_id)
+}
+
+impl QualityTarget {
+    /// Returns the collectable tier that the target refers to, if the item has collectability data.
+    pub fn collectable_tier(self, item_id: u32) -> Option<CollectableTier> {
+        let collectable = get_collectable(item_id)?;
+        match self {
+            Self::CollectableT1 => Some(collectable.tiers[0]),
+            Self::CollectableT2 => Some(collectable.tiers[1]),
+            Self::CollectableT3 => Some(collectable.tiers[2]),
+            _ => None,
+        }
+    }
+
+    /// Label of the target for the given item, including the reward of collectable tiers.
+    pub fn label(self, item_id: u32) -> String {
+        match self.collectable_tier(item_id) {
+            Some(tier) => format!(
+                "{} ({} collectability, {} scrips)",
+                match self {
+                    Self::CollectableT1 => "Tier 1",
+                    Self::CollectableT2 => "Tier 2",
+                    _ => "Tier 3",
+                },
+                tier.collectability,
+                tier.scrips
+            ),
+            None => format!("{}", self),
+        }
+    }
+}
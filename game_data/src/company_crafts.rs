@@ -0,0 +1,83 @@
+use crate::{Recipe, RECIPES};
+
+pub const COMPANY_CRAFTS: &[CompanyCraftSequence] =
+    include!(concat!(env!("OUT_DIR"), "/company_crafts.rs"));
+
+/// An item that has to be delivered during a phase of a company craft.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompanyCraftSupply {
+    pub item_id: u32,
+    /// Number of items in a set.
+    pub set_quantity: u32,
+    /// Number of sets that complete the phase.
+    pub sets_required: u32,
+}
+
+impl CompanyCraftSupply {
+    pub const fn total_quantity(&self) -> u32 {
+        self.set_quantity * self.sets_required
+    }
+}
+
+/// A phase is completed by delivering its supply items.
+///
+/// The game data doesn't define any Progress, Quality, durability or Quality target for a phase.
+/// Those only exist on the regular recipes of the supply items (see `CompanyCraftPhase::recipes`),
+/// and supply items are accepted at any Quality, so per-phase Quality targets are not supported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompanyCraftPhase {
+    pub supplies: &'static [CompanyCraftSupply],
+}
+
+impl CompanyCraftPhase {
+    /// Returns the recipe of every supply item that is crafted by the given job.
+    /// Supply items that are gathered or crafted by other jobs are skipped.
+    pub fn recipes(&self, job_id: u8) -> Vec<(CompanyCraftSupply, Recipe)> {
+        self.supplies
+            .iter()
+            .filter_map(|supply| {
+                let recipe = RECIPES
+                    .iter()
+                    .find(|recipe| recipe.job_id == job_id && recipe.item_id == supply.item_id)?;
+                Some((*supply, *recipe))
+            })
+            .collect()
+    }
+
+    /// Returns the ids of the jobs that craft at least one of the supply items.
+    pub fn jobs(&self) -> Vec<u8> {
+        let mut jobs: Vec<u8> = RECIPES
+            .iter()
+            .filter(|recipe| {
+                self.supplies
+                    .iter()
+                    .any(|supply| supply.item_id == recipe.item_id)
+            })
+            .map(|recipe| recipe.job_id)
+            .collect();
+        jobs.sort_unstable();
+        jobs.dedup();
+        jobs
+    }
+}
+
+/// A part of a company craft, e.g. the hull of an airship, which is completed in phases.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompanyCraftPart {
+    /// Phases of the part, in order.
+    pub phases: &'static [CompanyCraftPhase],
+}
+
+/// A company workshop project (airship and submarine parts, housing, etc.), which consists of one or more parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompanyCraftSequence {
+    /// The item that is produced once every part is completed.
+    pub item_id: u32,
+    pub parts: &'static [CompanyCraftPart],
+}
+
+pub fn get_company_craft(item_id: u32) -> Option<&'static CompanyCraftSequence> {
+    COMPANY_CRAFTS
+        .iter()
+        .find(|sequence| sequence.item_id == item_id)
+}
@@ -1,4 +1,7 @@
 use serde::{Deserialize, Serialize};
+use simulator::{GameVersion, Settings};
+
+use crate::Recipe;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
 pub struct CrafterStats {
@@ -20,3 +23,112 @@ impl Default for CrafterStats {
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum QualityTarget {
+    Zero,
+    CollectableT1,
+    CollectableT2,
+    CollectableT3,
+    Full,
+    Custom(u16),
+    /// Highest reachable HQ%, with as little quality as possible.
+    MaximizeHq,
+    /// Lowest quality that results in the given HQ%.
+    Hq(u8),
+}
+
+impl QualityTarget {
+    /// Collectable tiers use the collectability thresholds of the item.
+    /// Items without collectability data fall back to fixed percentages of max quality.
+    pub fn get_target(self, item_id: u32, max_quality: u16) -> u16 {
+        if let Some(tier) = self.collectable_tier(item_id) {
+            return std::cmp::min(tier.min_quality(), max_quality);
+        }
+        match self {
+            Self::Zero => 0,
+            Self::CollectableT1 => (max_quality as f64 * 0.55).ceil() as u16,
+            Self::CollectableT2 => (max_quality as f64 * 0.75).ceil() as u16,
+            Self::CollectableT3 => (max_quality as f64 * 0.95).ceil() as u16,
+            Self::Full => max_quality,
+            Self::Custom(quality) => quality,
+            Self::MaximizeHq => max_quality,
+            Self::Hq(hq) => crate::hq_breakpoints(max_quality)
+                .into_iter()
+                .find(|(_, breakpoint_hq)| *breakpoint_hq >= hq)
+                .map_or(max_quality, |(quality, _)| quality),
+        }
+    }
+
+    /// Returns the quality targets for `solvers::QualityTargetSolver` if quality in between them is worthless.
+    pub fn solver_targets(self, max_quality: u16) -> Option<Vec<u16>> {
+        match self {
+            Self::MaximizeHq => Some(
+                crate::hq_breakpoints(max_quality)
+                    .into_iter()
+                    .map(|(quality, _)| quality)
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+
+    /// Same as `get_target`, but recipes with a required Quality always target exactly that Quality.
+    pub fn recipe_target(self, recipe: &Recipe, max_quality: u16) -> u16 {
+        match recipe.required_quality {
+            0 => self.get_target(recipe.item_id, max_quality),
+            required_quality => std::cmp::min(required_quality, max_quality),
+        }
+    }
+
+    /// Same as `solver_targets`, but recipes with a required Quality only have a single target.
+    pub fn recipe_solver_targets(self, recipe: &Recipe, max_quality: u16) -> Option<Vec<u16>> {
+        match recipe.required_quality {
+            0 => self.solver_targets(max_quality),
+            _ => None,
+        }
+    }
+
+    /// Lowers max Quality of the settings to the target for the recipe, so that the solver doesn't spend resources on Quality beyond it.
+    pub fn apply(self, recipe: &Recipe, settings: &mut Settings) {
+        settings.max_quality = std::cmp::max(
+            settings.initial_quality,
+            self.recipe_target(recipe, settings.max_quality),
+        );
+    }
+}
+
+impl Default for QualityTarget {
+    fn default() -> Self {
+        Self::Full
+    }
+}
+
+impl std::fmt::Display for QualityTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Zero => "0% quality",
+                Self::CollectableT1 => "55% quality",
+                Self::CollectableT2 => "75% quality",
+                Self::CollectableT3 => "95% quality",
+                Self::Full => "100% quality",
+                Self::Custom(_) => "Custom",
+                Self::MaximizeHq => "Maximize HQ%",
+                Self::Hq(_) => "HQ% target",
+            }
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SolverConfig {
+    pub quality_target: QualityTarget,
+    pub backload_progress: bool,
+    pub adversarial: bool,
+    /// Rules that are used for the simulation, e.g. to check rotations from before 7.0.
+    #[serde(default)]
+    pub game_version: GameVersion,
+}
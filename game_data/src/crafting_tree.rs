@@ -0,0 +1,171 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{GameDataSource, Recipe, RecipeConfiguration};
+
+/// An item together with everything that is needed to craft it.
+/// Ingredients that can't be crafted are leaves of the tree (raw materials).
+#[derive(Debug, Clone)]
+pub struct CraftingTree {
+    pub item_id: u32,
+    /// Number of items that are needed.
+    pub amount: u32,
+    /// `None` if the item is a raw material.
+    pub recipe: Option<Recipe>,
+    /// Number of times the recipe needs to be crafted to produce `amount` items.
+    pub crafts: u32,
+    pub ingredients: Vec<CraftingTree>,
+}
+
+/// A single intermediate (or final) craft of a `CraftingTree`.
+#[derive(Debug, Clone, Copy)]
+pub struct SolveJob {
+    /// `hq_ingredients` is set according to the HQ choices of the previous steps.
+    pub recipe_config: RecipeConfiguration,
+    /// Number of times the recipe needs to be crafted.
+    pub crafts: u32,
+    /// Whether the result of this step is needed in HQ.
+    pub hq: bool,
+}
+
+impl CraftingTree {
+    /// Builds the crafting tree for `amount` of the given item.
+    /// If an item can be crafted with multiple recipes, the first one is used.
+    pub fn new(source: &impl GameDataSource, item_id: u32, amount: u32) -> Self {
+        let mut recipes: HashMap<u32, Recipe> = HashMap::new();
+        for recipe in source.recipes() {
+            recipes.entry(recipe.item_id).or_insert(*recipe);
+        }
+        Self::build(&recipes, item_id, amount, &mut Vec::new())
+    }
+
+    fn build(
+        recipes: &HashMap<u32, Recipe>,
+        item_id: u32,
+        amount: u32,
+        path: &mut Vec<u32>,
+    ) -> Self {
+        // an item that is needed to craft itself is treated as a raw material
+        let recipe = match path.contains(&item_id) {
+            true => None,
+            false => recipes.get(&item_id).copied(),
+        };
+        let Some(recipe) = recipe else {
+            return Self {
+                item_id,
+                amount,
+                recipe: None,
+                crafts: 0,
+                ingredients: Vec::new(),
+            };
+        };
+        let crafts = amount.div_ceil(std::cmp::max(recipe.result_amount, 1));
+        path.push(item_id);
+        let ingredients = recipe
+            .ingredients
+            .iter()
+            .filter(|ingredient| ingredient.item_id != 0)
+            .map(|ingredient| {
+                Self::build(
+                    recipes,
+                    ingredient.item_id,
+                    ingredient.amount * crafts,
+                    path,
+                )
+            })
+            .collect();
+        path.pop();
+        Self {
+            item_id,
+            amount,
+            recipe: Some(recipe),
+            crafts,
+            ingredients,
+        }
+    }
+
+    pub fn is_craftable(&self) -> bool {
+        self.recipe.is_some()
+    }
+
+    /// Returns the total amount of each raw material, ordered by item id.
+    pub fn raw_materials(&self) -> Vec<(u32, u32)> {
+        let (_, materials) = self.totals();
+        let mut materials: Vec<(u32, u32)> = materials.into_iter().collect();
+        materials.sort_unstable();
+        materials
+    }
+
+    /// Returns one job per crafted item, in an order in which they can be crafted (ingredients first).
+    /// Crafts of the same item in different branches of the tree are merged into one job.
+    /// `hq_items` contains the items (crafted or not) that are provided in HQ.
+    pub fn solve_jobs(&self, hq_items: &HashSet<u32>) -> Vec<SolveJob> {
+        let (crafts, _) = self.totals();
+        crafts
+            .into_iter()
+            .map(|(recipe, crafts)| {
+                let mut hq_ingredients = [0; 6];
+                for (index, ingredient) in recipe.ingredients.iter().enumerate() {
+                    if ingredient.item_id != 0 && hq_items.contains(&ingredient.item_id) {
+                        hq_ingredients[index] = ingredient.amount as u8;
+                    }
+                }
+                SolveJob {
+                    recipe_config: RecipeConfiguration {
+                        recipe,
+                        hq_ingredients,
+                    },
+                    crafts,
+                    hq: hq_items.contains(&recipe.item_id),
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the number of crafts of each crafted item (ingredients first) and the total amount of each raw material.
+    /// The demand for an item is summed over all branches of the tree before it is rounded up to whole crafts,
+    /// so that an item with a result amount greater than one isn't crafted more often than needed.
+    fn totals(&self) -> (Vec<(Recipe, u32)>, HashMap<u32, u32>) {
+        // first craftable node of each item, which comes after the nodes of all of its crafted ingredients
+        let mut nodes: Vec<&Self> = Vec::new();
+        self.visit(&mut |node| {
+            if node.is_craftable() && !nodes.iter().any(|other| other.item_id == node.item_id) {
+                nodes.push(node);
+            }
+        });
+
+        let mut demand: HashMap<u32, u32> = HashMap::from([(self.item_id, self.amount)]);
+        let mut materials: HashMap<u32, u32> = HashMap::new();
+        if !self.is_craftable() {
+            materials.insert(self.item_id, self.amount);
+        }
+        let mut crafts = Vec::with_capacity(nodes.len());
+        // items that need an ingredient are visited before the ingredient
+        for node in nodes.iter().rev() {
+            let recipe = node.recipe.unwrap();
+            let amount = demand.get(&node.item_id).copied().unwrap_or_default();
+            let node_crafts = amount.div_ceil(std::cmp::max(recipe.result_amount, 1));
+            let ingredients = recipe
+                .ingredients
+                .iter()
+                .filter(|ingredient| ingredient.item_id != 0);
+            for (ingredient, child) in ingredients.zip(node.ingredients.iter()) {
+                let total = match child.is_craftable() {
+                    true => demand.entry(ingredient.item_id).or_default(),
+                    false => materials.entry(ingredient.item_id).or_default(),
+                };
+                *total += ingredient.amount * node_crafts;
+            }
+            crafts.push((recipe, node_crafts));
+        }
+        crafts.reverse();
+        (crafts, materials)
+    }
+
+    /// Visits all nodes of the tree, children before their parent.
+    fn visit<'a>(&'a self, f: &mut impl FnMut(&'a Self)) {
+        for ingredient in self.ingredients.iter() {
+            ingredient.visit(f);
+        }
+        f(self);
+    }
+}
@@ -0,0 +1,188 @@
+//! Loads `GameData` at runtime from the same CSV files that the build script uses.
+
+#[path = "../build/records.rs"]
+mod records;
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use records::*;
+use serde::de::DeserializeOwned;
+
+use crate::{Consumable, GameData, Ingredient, Item, Locale, Recipe, RecipeLevel};
+
+type Error = Box<dyn std::error::Error>;
+
+fn read_csv<RecordType: DeserializeOwned>(path: &Path) -> Result<Vec<RecordType>, Error> {
+    let mut reader = csv::Reader::from_path(path)
+        .map_err(|error| format!("failed to read {}: {}", path.display(), error))?;
+    Ok(reader.deserialize().collect::<Result<_, _>>()?)
+}
+
+const fn locale_dir(locale: Locale) -> &'static str {
+    match locale {
+        Locale::EN => "en",
+        Locale::DE => "de",
+        Locale::FR => "fr",
+        Locale::JP => "jp",
+        Locale::KO => "ko",
+        Locale::CN => "cn",
+    }
+}
+
+impl GameData {
+    /// Loads the game data from a directory with the same layout as the `data` directory of this crate.
+    pub fn from_csv(dir: impl AsRef<Path>) -> Result<Self, Error> {
+        let dir = dir.as_ref();
+        // keep track of relevant item IDs so that we don't load items that are never used
+        let mut relevant_items: HashSet<u32> = HashSet::new();
+
+        let rlvl_records: Vec<RecipeLevelRecord> = read_csv(&dir.join("RecipeLevelTable.csv"))?;
+        let rlvls = rlvl_records
+            .iter()
+            .map(|record| RecipeLevel {
+                progress_div: record.progress_divider as u16,
+                quality_div: record.quality_divider as u16,
+                progress_mod: record.progress_modifier as u16,
+                quality_mod: record.quality_modifier as u16,
+            })
+            .collect();
+
+        let mut recipes = Vec::new();
+        let mut patch_number = 0;
+        for record in read_csv::<RecipeRecord>(&dir.join("Recipe.csv"))? {
+            // skip the debug recipe (item id 0)
+            if record.resulting_item == 0 {
+                continue;
+            }
+            patch_number = std::cmp::max(patch_number, record.patch_number);
+
+            relevant_items.insert(record.resulting_item);
+            for (item_id, _) in record.ingredients() {
+                relevant_items.insert(item_id);
+            }
+
+            let rlvl = rlvl_records
+                .get(record.recipe_level as usize)
+                .ok_or("recipe has an unknown recipe level")?;
+            recipes.push(Recipe {
+                job_id: record.job_id,
+                item_id: record.resulting_item,
+                level: rlvl.level,
+                recipe_level: record.recipe_level as u16,
+                progress: record.progress(rlvl) as u16,
+                quality: record.quality(rlvl) as u16,
+                durability: record.durability(rlvl) as i8,
+                material_quality_factor: record.material_quality_factor as u16,
+                ingredients: record
+                    .ingredients()
+                    .map(|(item_id, amount)| Ingredient { item_id, amount }),
+                is_expert: record.is_expert,
+                required_craftsmanship: record.required_craftsmanship as u16,
+                required_control: record.required_control as u16,
+                required_quality: record.required_quality as u16,
+                result_amount: record.result_amount,
+            });
+        }
+
+        let item_food_by_id: HashMap<u32, ItemFoodRecord> =
+            read_csv::<ItemFoodRecord>(&dir.join("ItemFood.csv"))?
+                .into_iter()
+                .map(|item_food| (item_food.id, item_food))
+                .collect();
+        let mut consumable_by_item_action_id = HashMap::new();
+        for item_action in read_csv::<ItemActionRecord>(&dir.join("ItemAction.csv"))? {
+            if !item_action.is_consumable() {
+                continue;
+            }
+            let item_food = item_food_by_id
+                .get(&item_action.data_1)
+                .ok_or("item action refers to an unknown item food")?;
+            if item_food.has_crafting_params() {
+                consumable_by_item_action_id
+                    .insert(item_action.id, (item_action.is_potion(), *item_food));
+            }
+        }
+
+        let item_records: Vec<ItemRecord> = read_csv(&dir.join("en/Item.csv"))?;
+        let mut meals = Vec::new();
+        let mut potions = Vec::new();
+        for item in item_records.iter() {
+            if let Some((is_potion, item_food)) =
+                consumable_by_item_action_id.get(&item.item_action)
+            {
+                relevant_items.insert(item.id);
+                match is_potion {
+                    true => potions.push((item.id, item.item_level, item_food.crafting_stats())),
+                    false => meals.push((item.id, item.item_level, item_food.crafting_stats())),
+                }
+            }
+        }
+
+        let items = item_records
+            .iter()
+            .filter(|item| relevant_items.contains(&item.id))
+            .map(|item| {
+                let stats = Item {
+                    item_level: item.item_level as u16,
+                    can_be_hq: item.can_be_hq,
+                    is_collectable: item.is_collectable,
+                };
+                (item.id, stats)
+            })
+            .collect();
+
+        relevant_items.remove(&0);
+        let mut item_names = HashMap::new();
+        for locale in [
+            Locale::EN,
+            Locale::DE,
+            Locale::FR,
+            Locale::JP,
+            Locale::KO,
+            Locale::CN,
+        ] {
+            let path = dir.join(locale_dir(locale)).join("Item.csv");
+            let names = read_csv::<ItemRecord>(&path)?
+                .into_iter()
+                .filter(|item| relevant_items.contains(&item.id))
+                .map(|item| (item.id, item.name))
+                .collect();
+            item_names.insert(locale, names);
+        }
+
+        Ok(Self {
+            version: patch_string(patch_number),
+            rlvls,
+            recipes,
+            items,
+            item_names,
+            meals: export_consumables(meals),
+            potions: export_consumables(potions),
+        })
+    }
+}
+
+type ConsumableStats = (u32, u32, ([u32; 4], [u32; 4], [u32; 4]));
+
+/// Same order as the built-in consumables: by item level decreasing, HQ before NQ.
+fn export_consumables(mut consumables: Vec<ConsumableStats>) -> Vec<Consumable> {
+    consumables.sort_by_key(|consumable| std::cmp::Reverse(consumable.1));
+    let mut result = Vec::new();
+    for (item_id, item_level, (craftsmanship, control, cp)) in consumables {
+        for (hq, offset) in [(true, 2), (false, 0)] {
+            result.push(Consumable {
+                item_id,
+                item_level,
+                hq,
+                craft_rel: craftsmanship[offset] as u16,
+                craft_max: craftsmanship[offset + 1] as u16,
+                control_rel: control[offset] as u16,
+                control_max: control[offset + 1] as u16,
+                cp_rel: cp[offset] as u16,
+                cp_max: cp[offset + 1] as u16,
+            });
+        }
+    }
+    result
+}
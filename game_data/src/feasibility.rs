@@ -0,0 +1,194 @@
+use simulator::{state::InProgress, GameVersion, Settings, SimulationState};
+use solvers::{FinishSolver, MacroSolver, UpperBoundSolver};
+
+use crate::{
+    get_game_settings_for_version, get_item_name, hq_percentage, Consumable, CrafterStats, Locale,
+    QualityTarget, Recipe, RecipeConfiguration, RECIPES,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Feasibility {
+    /// False if the crafter doesn't meet the stat requirements or cannot max out Progress.
+    pub can_complete: bool,
+    /// Quality of the optimal rotation if `exact`, otherwise an upper bound on it.
+    /// Capped at the required Quality for recipes that have one.
+    pub quality: u16,
+    pub exact: bool,
+}
+
+impl Feasibility {
+    const INFEASIBLE: Self = Self {
+        can_complete: false,
+        quality: 0,
+        exact: true,
+    };
+
+    /// Returns true if max Quality is reached, or may be reached if the Quality is not exact.
+    pub fn may_reach_max_quality(&self, max_quality: u16) -> bool {
+        self.can_complete && self.quality >= max_quality
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct RecipeFeasibility {
+    pub recipe: Recipe,
+    pub feasibility: Feasibility,
+    /// True if the crafter can only complete the recipe or reach max Quality with the given food and potion.
+    pub needs_consumables: bool,
+}
+
+impl RecipeFeasibility {
+    pub fn hq(&self) -> u8 {
+        match self.recipe.quality {
+            0 => 0,
+            max_quality => hq_percentage(self.feasibility.quality, max_quality),
+        }
+    }
+}
+
+fn check_settings(settings: &Settings, full_solve: bool) -> Feasibility {
+    let state = InProgress::new(settings);
+    if !FinishSolver::new(*settings).can_finish(&state) {
+        return Feasibility::INFEASIBLE;
+    }
+    if full_solve {
+        if let Some(actions) = MacroSolver::new(*settings, Box::new(|_| {})).solve(state, false) {
+            let final_state = SimulationState::from_macro(settings, &actions).unwrap();
+            return Feasibility {
+                can_complete: true,
+                quality: settings.max_quality - final_state.get_missing_quality(),
+                exact: true,
+            };
+        }
+    }
+    Feasibility {
+        can_complete: true,
+        quality: UpperBoundSolver::new(*settings).quality_upper_bound(state),
+        exact: false,
+    }
+}
+
+fn check_recipe(
+    recipe: Recipe,
+    crafter_stats: CrafterStats,
+    food: Option<Consumable>,
+    potion: Option<Consumable>,
+    game_version: GameVersion,
+    full_solve: bool,
+) -> Feasibility {
+    let recipe_config = RecipeConfiguration {
+        recipe,
+        hq_ingredients: [0; 6],
+    };
+    match get_game_settings_for_version(
+        recipe_config,
+        crafter_stats,
+        food,
+        potion,
+        false,
+        game_version,
+    ) {
+        Ok(mut settings) => {
+            QualityTarget::Full.apply(&recipe, &mut settings);
+            check_settings(&settings, full_solve)
+        }
+        Err(_) => Feasibility::INFEASIBLE,
+    }
+}
+
+/// Checks a single recipe, assuming no HQ ingredients.
+/// Recipes with a required Quality only need to reach that Quality, see `QualityTarget::recipe_target`.
+/// Without `full_solve`, only the fast `FinishSolver` and `UpperBoundSolver` checks are run, so the Quality is an upper bound.
+pub fn recipe_feasibility(
+    recipe: Recipe,
+    crafter_stats: CrafterStats,
+    food: Option<Consumable>,
+    potion: Option<Consumable>,
+    game_version: GameVersion,
+    full_solve: bool,
+) -> RecipeFeasibility {
+    let feasibility = check_recipe(
+        recipe,
+        crafter_stats,
+        food,
+        potion,
+        game_version,
+        full_solve,
+    );
+    let target_quality = QualityTarget::Full.recipe_target(&recipe, recipe.quality);
+    let needs_consumables = match food.is_some() || potion.is_some() {
+        true => {
+            let without = check_recipe(recipe, crafter_stats, None, None, game_version, full_solve);
+            (feasibility.can_complete && !without.can_complete)
+                || (feasibility.may_reach_max_quality(target_quality)
+                    && !without.may_reach_max_quality(target_quality))
+        }
+        false => false,
+    };
+    RecipeFeasibility {
+        recipe,
+        feasibility,
+        needs_consumables,
+    }
+}
+
+/// Recipes of the job up to the level of the crafter, which are the recipes of the feasibility report.
+pub fn feasibility_recipes(job_id: u8, level: u8) -> impl Iterator<Item = &'static Recipe> {
+    RECIPES
+        .iter()
+        .filter(move |recipe| recipe.job_id == job_id && recipe.level <= level)
+}
+
+/// Checks every recipe of the job up to the level of the crafter, see `recipe_feasibility`.
+pub fn feasibility_report(
+    job_id: u8,
+    crafter_stats: CrafterStats,
+    food: Option<Consumable>,
+    potion: Option<Consumable>,
+    game_version: GameVersion,
+    full_solve: bool,
+) -> Vec<RecipeFeasibility> {
+    feasibility_recipes(job_id, crafter_stats.level)
+        .map(|recipe| {
+            recipe_feasibility(
+                *recipe,
+                crafter_stats,
+                food,
+                potion,
+                game_version,
+                full_solve,
+            )
+        })
+        .collect()
+}
+
+fn csv_field(value: &str) -> String {
+    match value.contains([',', '"', '\n']) {
+        true => format!("\"{}\"", value.replace('"', "\"\"")),
+        false => value.to_string(),
+    }
+}
+
+/// Formats the report as CSV, with one row per recipe.
+pub fn feasibility_csv(report: &[RecipeFeasibility], locale: Locale) -> String {
+    let mut lines = vec![
+        "Item,Level,Recipe level,Can complete,Quality,Max quality,Quality is exact,HQ%,Needs consumables"
+            .to_string(),
+    ];
+    for entry in report {
+        let recipe = entry.recipe;
+        lines.push(format!(
+            "{},{},{},{},{},{},{},{},{}",
+            csv_field(&get_item_name(recipe.item_id, false, locale)),
+            recipe.level,
+            recipe.recipe_level,
+            entry.feasibility.can_complete,
+            entry.feasibility.quality,
+            recipe.quality,
+            entry.feasibility.exact,
+            entry.hq(),
+            entry.needs_consumables
+        ));
+    }
+    lines.join("\n")
+}
@@ -0,0 +1,228 @@
+use serde::{Deserialize, Serialize};
+
+use crate::CrafterStats;
+
+pub const GEAR: &[GearItem] = include!(concat!(env!("OUT_DIR"), "/gear.rs"));
+pub const MATERIA: &[Materia] = include!(concat!(env!("OUT_DIR"), "/materia.rs"));
+
+/// CP of a crafter without any gear.
+pub const BASE_CP: u16 = 180;
+
+/// Number of materia that can be melded into a piece of gear that allows advanced melding.
+pub const MAX_ADVANCED_MELDS: u8 = 5;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GearStats {
+    pub craftsmanship: u16,
+    pub control: u16,
+    pub cp: u16,
+}
+
+impl std::ops::Add for GearStats {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            craftsmanship: self.craftsmanship + rhs.craftsmanship,
+            control: self.control + rhs.control,
+            cp: self.cp + rhs.cp,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct GearItem {
+    pub item_id: u32,
+    /// `EquipSlotCategory` of the item, e.g. 1 for main hand and 12 for rings.
+    pub equip_slot: u8,
+    pub item_level: u32,
+    pub equip_level: u8,
+    /// Bit `i` is set if the item can be equipped by job `i`.
+    pub jobs: u8,
+    pub stats: GearStats,
+    /// Stats of the HQ version of the item. Same as `stats` if the item can't be HQ.
+    pub hq_stats: GearStats,
+    /// Maximum value of each stat after melding.
+    pub meld_caps: GearStats,
+    pub materia_slots: u8,
+    pub advanced_melding: bool,
+}
+
+impl GearItem {
+    pub fn can_be_equipped_by(&self, job_id: u8) -> bool {
+        self.jobs & (1 << job_id) != 0
+    }
+
+    pub fn max_melds(&self) -> u8 {
+        match self.advanced_melding {
+            true => std::cmp::max(self.materia_slots, MAX_ADVANCED_MELDS),
+            false => self.materia_slots,
+        }
+    }
+
+    /// Returns the stats of the item with the given materia melded.
+    /// The stat bonus of the materia is limited by the meld caps of the item.
+    pub fn stats(&self, hq: bool, melds: &[Materia]) -> Result<GearStats, &'static str> {
+        if melds.len() > self.max_melds() as usize {
+            return Err("Too many materia melded");
+        }
+        if melds
+            .iter()
+            .skip(self.materia_slots as usize)
+            .any(|materia| !materia.can_be_overmelded())
+        {
+            return Err("Materia of this grade can't be used for advanced melding");
+        }
+        let base = match hq {
+            true => self.hq_stats,
+            false => self.stats,
+        };
+        let bonus = melds
+            .iter()
+            .fold(GearStats::default(), |acc, materia| acc + materia.stats);
+        let capped_bonus =
+            |base: u16, bonus: u16, cap: u16| std::cmp::min(bonus, cap.saturating_sub(base));
+        Ok(base
+            + GearStats {
+                craftsmanship: capped_bonus(
+                    base.craftsmanship,
+                    bonus.craftsmanship,
+                    self.meld_caps.craftsmanship,
+                ),
+                control: capped_bonus(base.control, bonus.control, self.meld_caps.control),
+                cp: capped_bonus(base.cp, bonus.cp, self.meld_caps.cp),
+            })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Materia {
+    pub item_id: u32,
+    /// 1 for grade I materia, 2 for grade II, etc.
+    pub grade: u8,
+    pub stats: GearStats,
+}
+
+impl Materia {
+    /// Grade VI, VIII, X and XII materia can only be melded into the regular materia slots of an item.
+    pub fn can_be_overmelded(&self) -> bool {
+        self.grade < 6 || self.grade % 2 == 1
+    }
+}
+
+/// Gear and materia tables that item ids are looked up in.
+#[derive(Debug, Clone, Copy)]
+pub struct GearData<'a> {
+    pub gear: &'a [GearItem],
+    pub materia: &'a [Materia],
+}
+
+impl GearData<'static> {
+    /// The tables that are compiled into the binary.
+    pub const BUILTIN: Self = Self {
+        gear: GEAR,
+        materia: MATERIA,
+    };
+}
+
+impl<'a> GearData<'a> {
+    pub fn gear(&self, item_id: u32) -> Option<&'a GearItem> {
+        self.gear.iter().find(|item| item.item_id == item_id)
+    }
+
+    pub fn materia(&self, item_id: u32) -> Option<&'a Materia> {
+        self.materia
+            .iter()
+            .find(|materia| materia.item_id == item_id)
+    }
+}
+
+pub fn get_gear(item_id: u32) -> Option<&'static GearItem> {
+    GearData::BUILTIN.gear(item_id)
+}
+
+pub fn get_materia(item_id: u32) -> Option<&'static Materia> {
+    GearData::BUILTIN.materia(item_id)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GearPiece {
+    pub item_id: u32,
+    pub hq: bool,
+    /// Item ids of the melded materia.
+    pub melds: Vec<u32>,
+}
+
+impl GearPiece {
+    pub fn stats(&self) -> Result<GearStats, &'static str> {
+        self.stats_in(GearData::BUILTIN)
+    }
+
+    pub fn stats_in(&self, gear_data: GearData) -> Result<GearStats, &'static str> {
+        let item = gear_data.gear(self.item_id).ok_or("Unknown gear item")?;
+        let melds = self
+            .melds
+            .iter()
+            .map(|&item_id| gear_data.materia(item_id).copied().ok_or("Unknown materia"))
+            .collect::<Result<Vec<Materia>, _>>()?;
+        item.stats(self.hq, &melds)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Gearset {
+    pub pieces: Vec<GearPiece>,
+}
+
+impl Gearset {
+    pub fn stats(&self) -> Result<GearStats, &'static str> {
+        self.stats_in(GearData::BUILTIN)
+    }
+
+    pub fn stats_in(&self, gear_data: GearData) -> Result<GearStats, &'static str> {
+        let mut stats = GearStats {
+            cp: BASE_CP,
+            ..Default::default()
+        };
+        for piece in self.pieces.iter() {
+            stats = stats + piece.stats_in(gear_data)?;
+        }
+        Ok(stats)
+    }
+
+    /// Returns an error if the gearset contains items that the job can't equip.
+    pub fn crafter_stats(
+        &self,
+        job_id: u8,
+        level: u8,
+        manipulation: bool,
+    ) -> Result<CrafterStats, &'static str> {
+        self.crafter_stats_in(GearData::BUILTIN, job_id, level, manipulation)
+    }
+
+    pub fn crafter_stats_in(
+        &self,
+        gear_data: GearData,
+        job_id: u8,
+        level: u8,
+        manipulation: bool,
+    ) -> Result<CrafterStats, &'static str> {
+        for piece in self.pieces.iter() {
+            let item = gear_data.gear(piece.item_id).ok_or("Unknown gear item")?;
+            if !item.can_be_equipped_by(job_id) {
+                return Err("Gear can't be equipped by this job");
+            }
+            if item.equip_level > level {
+                return Err("Gear level is higher than the job level");
+            }
+        }
+        let stats = self.stats_in(gear_data)?;
+        Ok(CrafterStats {
+            craftsmanship: stats.craftsmanship,
+            control: stats.control,
+            cp: stats.cp,
+            level,
+            manipulation,
+        })
+    }
+}
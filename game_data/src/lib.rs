@@ -1,16 +1,66 @@
 mod consumables;
 pub use consumables::*;
 
+mod collectables;
+pub use collectables::*;
+
+mod company_crafts;
+pub use company_crafts::*;
+
 mod config;
 pub use config::*;
 
+mod profiles;
+pub use profiles::*;
+
 mod locales;
 pub use locales::*;
 
+mod search;
+pub use search::*;
+
+mod crafting_tree;
+pub use crafting_tree::*;
+
+mod gear;
+pub use gear::*;
+
+mod meld_optimizer;
+pub use meld_optimizer::*;
+
+mod rotation_formats;
+pub use rotation_formats::*;
+
+mod macro_library;
+pub use macro_library::*;
+
+mod feasibility;
+pub use feasibility::*;
+
+mod sensitivity;
+pub use sensitivity::*;
+
+mod source;
+pub use source::*;
+
+mod runtime;
+pub use runtime::*;
+
+#[cfg(feature = "csv")]
+mod csv_import;
+
+#[cfg(feature = "share")]
+mod share;
+#[cfg(feature = "share")]
+pub use share::*;
+
 use serde::{Deserialize, Serialize};
-use simulator::{ActionMask, Settings};
+use simulator::{GameVersion, Settings};
+
+/// Patch of the game that the built-in data was extracted from, e.g. "7.0".
+pub const GAME_DATA_VERSION: &str = include!(concat!(env!("OUT_DIR"), "/version.rs"));
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct Item {
     pub item_level: u16,
     pub can_be_hq: bool,
@@ -23,7 +73,7 @@ pub struct Ingredient {
     pub amount: u32,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct RecipeLevel {
     pub progress_div: u16,
     pub quality_div: u16,
@@ -43,6 +93,15 @@ pub struct Recipe {
     pub material_quality_factor: u16,
     pub ingredients: [Ingredient; 6],
     pub is_expert: bool,
+    #[serde(default)]
+    pub required_craftsmanship: u16,
+    #[serde(default)]
+    pub required_control: u16,
+    #[serde(default)]
+    pub required_quality: u16,
+    /// Number of items produced by a single craft.
+    #[serde(default)]
+    pub result_amount: u32,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
@@ -56,70 +115,34 @@ pub const RECIPES: &[Recipe] = include!(concat!(env!("OUT_DIR"), "/recipes.rs"))
 
 pub static ITEMS: phf::OrderedMap<u32, Item> = include!(concat!(env!("OUT_DIR"), "/items.rs"));
 
+/// Returns an error if the crafter (including food and potion bonuses) doesn't meet the stat requirements of the recipe.
 pub fn get_game_settings(
     recipe_config: RecipeConfiguration,
     crafter_stats: CrafterStats,
     food: Option<Consumable>,
     potion: Option<Consumable>,
     adversarial: bool,
-) -> Settings {
-    let recipe = recipe_config.recipe;
-    let rlvl = &RLVLS[recipe.recipe_level as usize];
-
-    let craftsmanship = crafter_stats.craftsmanship
-        + craftsmanship_bonus(crafter_stats.craftsmanship, &[food, potion]);
-    let control = crafter_stats.control + control_bonus(crafter_stats.control, &[food, potion]);
-    let cp = crafter_stats.cp + cp_bonus(crafter_stats.cp, &[food, potion]);
-
-    let mut base_progress = craftsmanship * 10 / rlvl.progress_div + 2;
-    let mut base_quality = control * 10 / rlvl.quality_div + 35;
-    if crafter_stats.level <= recipe.level {
-        base_progress = base_progress * rlvl.progress_mod / 100;
-        base_quality = base_quality * rlvl.quality_mod / 100;
-    }
+) -> Result<Settings, &'static str> {
+    BuiltinGameData.get_game_settings(recipe_config, crafter_stats, food, potion, adversarial)
+}
 
-    let ingredients: Vec<(Item, u32)> = recipe
-        .ingredients
-        .iter()
-        .filter_map(|ingredient| match ingredient.item_id {
-            0 => None,
-            id => Some((*ITEMS.get(&id).unwrap(), ingredient.amount)),
-        })
-        .collect();
-    let initial_quality = {
-        let mut max_ilvl = 0;
-        let mut provided_ilvl = 0;
-        for (index, (item, max_amount)) in ingredients.into_iter().enumerate() {
-            if item.can_be_hq {
-                max_ilvl += max_amount as u16 * item.item_level;
-                provided_ilvl += recipe_config.hq_ingredients[index] as u16 * item.item_level;
-            }
-        }
-        if max_ilvl != 0 {
-            (recipe.quality as u64 * recipe.material_quality_factor as u64 * provided_ilvl as u64
-                / max_ilvl as u64
-                / 100) as u16
-        } else {
-            0
-        }
-    };
-
-    Settings {
-        max_cp: cp as _,
-        max_durability: recipe.durability as _,
-        max_progress: recipe.progress,
-        max_quality: recipe.quality,
-        base_progress,
-        base_quality,
-        initial_quality,
-        job_level: crafter_stats.level,
-        allowed_actions: ActionMask::from_level(
-            crafter_stats.level as _,
-            crafter_stats.manipulation,
-            !recipe.is_expert && crafter_stats.level >= recipe.level + 10, // Trained Eye condition
-        ),
+/// Same as `get_game_settings`, but simulates with the rules of the given game version.
+pub fn get_game_settings_for_version(
+    recipe_config: RecipeConfiguration,
+    crafter_stats: CrafterStats,
+    food: Option<Consumable>,
+    potion: Option<Consumable>,
+    adversarial: bool,
+    game_version: GameVersion,
+) -> Result<Settings, &'static str> {
+    BuiltinGameData.get_game_settings_for_version(
+        recipe_config,
+        crafter_stats,
+        food,
+        potion,
         adversarial,
-    }
+        game_version,
+    )
 }
 
 const HQ_LOOKUP: [u8; 101] = [
@@ -129,9 +152,55 @@ const HQ_LOOKUP: [u8; 101] = [
     71, 74, 76, 78, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 94, 96, 98, 100,
 ];
 
+fn hq_lookup_index(quality: u16, max_quality: u16) -> usize {
+    let ratio = quality as f64 / max_quality as f64;
+    (ratio * 100.0).floor() as usize
+}
+
 pub fn hq_percentage(quality: u16, max_quality: u16) -> u8 {
     // TODO: switch to std::num::NonZeroU32 at some point
     assert!(max_quality != 0, "max_quality must be non-zero");
-    let ratio = quality as f64 / max_quality as f64;
-    HQ_LOOKUP[(ratio * 100.0).floor() as usize]
+    HQ_LOOKUP[hq_lookup_index(quality, max_quality)]
+}
+
+/// Returns the minimum quality of every HQ% as (quality, HQ%) pairs, in ascending order.
+/// Any quality in between two breakpoints has the same HQ% as the lower breakpoint.
+/// Recipes without quality have no breakpoints.
+pub fn hq_breakpoints(max_quality: u16) -> Vec<(u16, u8)> {
+    if max_quality == 0 {
+        return Vec::new();
+    }
+    let mut breakpoints: Vec<(u16, u8)> = Vec::new();
+    for (index, hq) in HQ_LOOKUP.iter().enumerate() {
+        if breakpoints.last().is_some_and(|(_, last_hq)| last_hq == hq) {
+            continue;
+        }
+        // binary search for the lowest quality that reaches the index
+        let (mut low, mut high) = (0, max_quality);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            match hq_lookup_index(middle, max_quality) >= index {
+                true => high = middle,
+                false => low = middle + 1,
+            }
+        }
+        // with low max_quality, several HQ% can share the same quality, in which case the highest one applies
+        if breakpoints
+            .last()
+            .is_some_and(|(last_quality, _)| *last_quality == low)
+        {
+            breakpoints.pop();
+        }
+        breakpoints.push((low, *hq));
+    }
+    breakpoints
+}
+
+/// Returns the additional quality that is needed to reach the next higher HQ%, together with that HQ%.
+/// Returns `None` if the quality already results in 100% HQ.
+pub fn next_hq_breakpoint(quality: u16, max_quality: u16) -> Option<(u16, u8)> {
+    hq_breakpoints(max_quality)
+        .into_iter()
+        .find(|(breakpoint, _)| *breakpoint > quality)
+        .map(|(breakpoint, hq)| (breakpoint - quality, hq))
 }
@@ -1,12 +1,16 @@
 use serde::{Deserialize, Serialize};
 use simulator::Action;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+use crate::{BuiltinGameData, GameDataSource};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
 pub enum Locale {
     EN,
     DE,
     FR,
     JP,
+    KO,
+    CN,
 }
 
 impl std::fmt::Display for Locale {
@@ -16,6 +20,8 @@ impl std::fmt::Display for Locale {
             Self::DE => write!(f, "Deutsch"),
             Self::FR => write!(f, "Français"),
             Self::JP => write!(f, "日本語"),
+            Self::KO => write!(f, "한국어"),
+            Self::CN => write!(f, "中文"),
         }
     }
 }
@@ -23,6 +29,12 @@ impl std::fmt::Display for Locale {
 const JOB_NAMES_EN: [&str; 8] = ["CRP", "BSM", "ARM", "GSM", "LTW", "WVR", "ALC", "CUL"];
 const JOB_NAMES_DE: [&str; 8] = ["ZMR", "GRS", "PLA", "GLD", "GER", "WEB", "ALC", "GRM"];
 const JOB_NAMES_FR: [&str; 8] = ["MEN", "FRG", "ARM", "ORF", "TAN", "COU", "ALC", "CUI"];
+const JOB_NAMES_KO: [&str; 8] = [
+    "목수", "대장", "갑주", "보석", "가죽", "재봉", "연금", "요리",
+];
+const JOB_NAMES_CN: [&str; 8] = [
+    "刻木", "锻铁", "铸甲", "雕金", "制革", "裁衣", "炼金", "烹调",
+];
 
 pub fn get_job_name(job_id: u8, locale: Locale) -> &'static str {
     match locale {
@@ -30,6 +42,8 @@ pub fn get_job_name(job_id: u8, locale: Locale) -> &'static str {
         Locale::DE => JOB_NAMES_DE[job_id as usize],
         Locale::FR => JOB_NAMES_FR[job_id as usize],
         Locale::JP => JOB_NAMES_EN[job_id as usize], // JP job abbreviations are the same as EN
+        Locale::KO => JOB_NAMES_KO[job_id as usize],
+        Locale::CN => JOB_NAMES_CN[job_id as usize],
     }
 }
 
@@ -41,30 +55,25 @@ static ITEM_NAMES_FR: phf::Map<u32, &'static str> =
     include!(concat!(env!("OUT_DIR"), "/item_names_fr.rs"));
 static ITEM_NAMES_JP: phf::Map<u32, &'static str> =
     include!(concat!(env!("OUT_DIR"), "/item_names_jp.rs"));
+static ITEM_NAMES_KO: phf::Map<u32, &'static str> =
+    include!(concat!(env!("OUT_DIR"), "/item_names_ko.rs"));
+static ITEM_NAMES_CN: phf::Map<u32, &'static str> =
+    include!(concat!(env!("OUT_DIR"), "/item_names_cn.rs"));
 
 pub fn get_item_name(item_id: u32, hq: bool, locale: Locale) -> String {
-    let item_name = match locale {
-        Locale::EN => ITEM_NAMES_EN
-            .get(&item_id)
-            .copied()
-            .unwrap_or("Unknown item"),
-        Locale::DE => ITEM_NAMES_DE
-            .get(&item_id)
-            .copied()
-            .unwrap_or("Unknown item"),
-        Locale::FR => ITEM_NAMES_FR
-            .get(&item_id)
-            .copied()
-            .unwrap_or("Unknown item"),
-        Locale::JP => ITEM_NAMES_JP
-            .get(&item_id)
-            .copied()
-            .unwrap_or("Unknown item"),
-    };
-    match hq {
-        true => format!("{} (HQ)", item_name),
-        false => item_name.to_string(),
+    BuiltinGameData.get_item_name(item_id, hq, locale)
+}
+
+pub(crate) fn builtin_item_name(item_id: u32, locale: Locale) -> Option<&'static str> {
+    match locale {
+        Locale::EN => ITEM_NAMES_EN.get(&item_id),
+        Locale::DE => ITEM_NAMES_DE.get(&item_id),
+        Locale::FR => ITEM_NAMES_FR.get(&item_id),
+        Locale::JP => ITEM_NAMES_JP.get(&item_id),
+        Locale::KO => ITEM_NAMES_KO.get(&item_id),
+        Locale::CN => ITEM_NAMES_CN.get(&item_id),
     }
+    .copied()
 }
 
 pub const fn action_name(action: Action, locale: Locale) -> &'static str {
@@ -73,6 +82,8 @@ pub const fn action_name(action: Action, locale: Locale) -> &'static str {
         Locale::DE => action_name_de(action),
         Locale::FR => action_name_fr(action),
         Locale::JP => action_name_jp(action),
+        Locale::KO => action_name_ko(action),
+        Locale::CN => action_name_cn(action),
     }
 }
 
@@ -207,3 +218,69 @@ const fn action_name_jp(action: Action) -> &'static str {
         Action::TrainedEye => "匠の早業",
     }
 }
+
+const fn action_name_ko(action: Action) -> &'static str {
+    match action {
+        Action::BasicSynthesis => "작업",
+        Action::BasicTouch => "가공",
+        Action::MasterMend => "능숙한 솜씨",
+        Action::Observe => "경계",
+        Action::WasteNot => "근검절약",
+        Action::Veneration => "공경",
+        Action::StandardTouch | Action::ComboStandardTouch => "중급 가공",
+        Action::GreatStrides => "장족의 발전",
+        Action::Innovation => "혁신",
+        Action::WasteNot2 => "장기 절약",
+        Action::ByregotsBlessing => "비레고의 축복",
+        Action::PreciseTouch => "집중 가공",
+        Action::MuscleMemory => "확신",
+        Action::CarefulSynthesis => "모범 작업",
+        Action::Manipulation => "교묘한 손놀림",
+        Action::PrudentTouch => "절약 가공",
+        Action::AdvancedTouch | Action::ComboAdvancedTouch => "상급 가공",
+        Action::Reflect => "성찰",
+        Action::PreparatoryTouch => "밑작업 가공",
+        Action::Groundwork => "밑작업",
+        Action::DelicateSynthesis => "정밀 작업",
+        Action::IntensiveSynthesis => "집중 작업",
+        Action::PrudentSynthesis => "절약 작업",
+        Action::TrainedFinesse => "장인의 황금손",
+        Action::ComboRefinedTouch => "세련 가공",
+        Action::ImmaculateMend => "완벽한 수선",
+        Action::TrainedPerfection => "장인의 초절기술",
+        Action::TrainedEye => "장인의 날랜손",
+    }
+}
+
+const fn action_name_cn(action: Action) -> &'static str {
+    match action {
+        Action::BasicSynthesis => "制作",
+        Action::BasicTouch => "加工",
+        Action::MasterMend => "精修",
+        Action::Observe => "观察",
+        Action::WasteNot => "俭约",
+        Action::Veneration => "崇敬",
+        Action::StandardTouch | Action::ComboStandardTouch => "中级加工",
+        Action::GreatStrides => "阔步",
+        Action::Innovation => "改革",
+        Action::WasteNot2 => "长期俭约",
+        Action::ByregotsBlessing => "比尔格的祝福",
+        Action::PreciseTouch => "集中加工",
+        Action::MuscleMemory => "坚信",
+        Action::CarefulSynthesis => "模范制作",
+        Action::Manipulation => "掌握",
+        Action::PrudentTouch => "俭约加工",
+        Action::AdvancedTouch | Action::ComboAdvancedTouch => "上级加工",
+        Action::Reflect => "闲静",
+        Action::PreparatoryTouch => "坯料加工",
+        Action::Groundwork => "坯料制作",
+        Action::DelicateSynthesis => "精密制作",
+        Action::IntensiveSynthesis => "集中制作",
+        Action::PrudentSynthesis => "俭约制作",
+        Action::TrainedFinesse => "工匠的神技",
+        Action::ComboRefinedTouch => "精炼加工",
+        Action::ImmaculateMend => "巧夺天工",
+        Action::TrainedPerfection => "工匠的绝技",
+        Action::TrainedEye => "工匠的神速技巧",
+    }
+}
@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+use simulator::{Action, Settings, SimulationState};
+
+use crate::hq_percentage;
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SavedMacro {
+    pub name: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub actions: Vec<Action>,
+}
+
+impl SavedMacro {
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t.eq_ignore_ascii_case(tag))
+    }
+}
+
+/// A saved macro that completes the craft, see `MacroLibrary::find_working`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MacroMatch {
+    /// Index of the macro in `MacroLibrary::macros`.
+    pub index: usize,
+    /// Quality reached by the macro, capped at `max_quality`.
+    pub quality: u16,
+    pub hq: u8,
+    /// Duration of the macro in seconds.
+    pub duration: i16,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct MacroLibrary {
+    pub macros: Vec<SavedMacro>,
+}
+
+impl MacroLibrary {
+    /// Adds the macro to the library. A saved macro with the same name is replaced.
+    pub fn save(&mut self, saved_macro: SavedMacro) {
+        match self.macros.iter_mut().find(|m| m.name == saved_macro.name) {
+            Some(existing) => *existing = saved_macro,
+            None => self.macros.push(saved_macro),
+        }
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<SavedMacro> {
+        let index = self.macros.iter().position(|m| m.name == name)?;
+        Some(self.macros.remove(index))
+    }
+
+    /// Simulates every saved macro (assuming Normal conditions) and returns the ones that max out Progress.
+    /// The result is ordered by Quality (and therefore HQ%) from highest to lowest, then by duration from shortest to longest.
+    pub fn find_working(&self, settings: &Settings) -> Vec<MacroMatch> {
+        let mut matches: Vec<MacroMatch> = self
+            .macros
+            .iter()
+            .enumerate()
+            .filter_map(|(index, saved_macro)| {
+                let state = SimulationState::from_macro(settings, &saved_macro.actions).ok()?;
+                if state.missing_progress != 0 {
+                    return None;
+                }
+                let quality = settings.max_quality - state.get_missing_quality();
+                Some(MacroMatch {
+                    index,
+                    quality,
+                    hq: match settings.max_quality {
+                        0 => 0,
+                        max_quality => hq_percentage(quality, max_quality),
+                    },
+                    duration: saved_macro
+                        .actions
+                        .iter()
+                        .map(|action| action.time_cost())
+                        .sum(),
+                })
+            })
+            .collect();
+        matches.sort_by(|a, b| b.quality.cmp(&a.quality).then(a.duration.cmp(&b.duration)));
+        matches
+    }
+}
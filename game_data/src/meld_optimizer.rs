@@ -0,0 +1,413 @@
+use simulator::{state::InProgress, Action, GameVersion, Settings, SimulationState};
+use solvers::{MacroSolver, UpperBoundSolver};
+
+use crate::{
+    get_game_settings_for_version, Consumable, CrafterStats, GearData, GearStats, Gearset, Materia,
+    QualityTarget, RecipeConfiguration,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MeldObjective {
+    /// Maximize the quality that the solver can reach. Ties are broken by the number of overmelds.
+    MaximizeQuality,
+    /// Reach the given quality with as few overmelds as possible.
+    ReachQuality(u16),
+}
+
+/// A way of filling the open materia slots of a gearset.
+#[derive(Debug, Clone)]
+pub struct MeldCandidate {
+    pub gearset: Gearset,
+    pub crafter_stats: CrafterStats,
+    /// Number of materia in slots beyond the regular materia slots of the gear.
+    pub overmelds: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct MeldSolution {
+    pub candidate: MeldCandidate,
+    pub quality: u16,
+    pub actions: Vec<Action>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct MeldOptimizer<'a> {
+    /// Tables that the gear and materia of the gearset and the inventory are looked up in.
+    pub gear_data: GearData<'a>,
+    pub recipe_config: RecipeConfiguration,
+    pub job_id: u8,
+    pub level: u8,
+    pub manipulation: bool,
+    pub food: Option<Consumable>,
+    pub potion: Option<Consumable>,
+    pub objective: MeldObjective,
+    pub game_version: GameVersion,
+}
+
+/// Melds added to a single piece of gear.
+#[derive(Debug, Clone)]
+struct PieceOption {
+    /// Number of materia used of each inventory entry.
+    usage: Vec<u32>,
+    stats: GearStats,
+    overmelds: u32,
+}
+
+/// Partial assignment of melds to the first few pieces of the gearset.
+#[derive(Debug, Clone)]
+struct PartialCandidate {
+    usage: Vec<u32>,
+    stats: GearStats,
+    overmelds: u32,
+    options: Vec<usize>,
+}
+
+fn stats_le(a: GearStats, b: GearStats) -> bool {
+    a.craftsmanship <= b.craftsmanship && a.control <= b.control && a.cp <= b.cp
+}
+
+fn usage_le(a: &[u32], b: &[u32]) -> bool {
+    a.iter().zip(b.iter()).all(|(a, b)| a <= b)
+}
+
+fn stats_sum(stats: GearStats) -> u32 {
+    stats.craftsmanship as u32 + stats.control as u32 + stats.cp as u32
+}
+
+/// Removes all elements that are dominated by another element.
+/// Of multiple equivalent elements, only the first one is kept.
+///
+/// `key` must order an element before all elements it dominates, so that each element only needs to be
+/// compared to the frontier that has been built so far.
+fn retain_pareto_optimal<T, K: Ord>(
+    elements: &mut Vec<T>,
+    key: impl Fn(&T) -> K,
+    dominates: impl Fn(&T, &T) -> bool,
+) {
+    // stable sort, so that the first of multiple equivalent elements stays first
+    elements.sort_by_key(|element| key(element));
+    let mut frontier: Vec<T> = Vec::new();
+    for element in elements.drain(..) {
+        if !frontier.iter().any(|other| dominates(other, &element)) {
+            frontier.push(element);
+        }
+    }
+    *elements = frontier;
+}
+
+/// Puts materia that can't be overmelded first, so that they end up in the regular materia slots.
+fn sort_new_melds(melds: &mut [Materia]) {
+    melds.sort_by_key(|materia| materia.can_be_overmelded());
+}
+
+impl<'a> MeldOptimizer<'a> {
+    /// Returns the combinations of melds that are worth evaluating with the solver.
+    /// Existing melds of the gearset are kept, only open slots are filled.
+    /// `inventory` contains the available materia as (item id, amount) pairs.
+    ///
+    /// A combination is pruned if another combination results in the same or better
+    /// base progress, base quality and CP with the same or fewer overmelds.
+    pub fn candidates(
+        &self,
+        gearset: &Gearset,
+        inventory: &[(u32, u32)],
+    ) -> Result<Vec<MeldCandidate>, &'static str> {
+        let materia = inventory
+            .iter()
+            .map(|(item_id, _)| {
+                self.gear_data
+                    .materia(*item_id)
+                    .copied()
+                    .ok_or("Unknown materia")
+            })
+            .collect::<Result<Vec<Materia>, _>>()?;
+        let amounts: Vec<u32> = inventory.iter().map(|(_, amount)| *amount).collect();
+
+        let mut piece_options = Vec::new();
+        for piece in gearset.pieces.iter() {
+            let item = self
+                .gear_data
+                .gear(piece.item_id)
+                .ok_or("Unknown gear item")?;
+            let melds = piece
+                .melds
+                .iter()
+                .map(|&item_id| {
+                    self.gear_data
+                        .materia(item_id)
+                        .copied()
+                        .ok_or("Unknown materia")
+                })
+                .collect::<Result<Vec<Materia>, _>>()?;
+            let open_slots = (item.max_melds() as usize).saturating_sub(melds.len());
+
+            let mut options = Vec::new();
+            let mut usage = vec![0; materia.len()];
+            let mut stack = melds.clone();
+            Self::enumerate_melds(
+                &materia,
+                &amounts,
+                0,
+                open_slots,
+                &mut usage,
+                &mut stack,
+                &mut |usage, melds| {
+                    let mut melds = melds.to_vec();
+                    sort_new_melds(&mut melds[piece.melds.len()..]);
+                    // too many materia that can't be overmelded
+                    let Ok(stats) = item.stats(piece.hq, &melds) else {
+                        return;
+                    };
+                    options.push(PieceOption {
+                        usage: usage.to_vec(),
+                        stats,
+                        overmelds: melds.len().saturating_sub(item.materia_slots as usize) as u32,
+                    });
+                },
+            );
+            // melds that don't add any stats (because of meld caps) are pruned here
+            retain_pareto_optimal(
+                &mut options,
+                |option| {
+                    (
+                        std::cmp::Reverse(stats_sum(option.stats)),
+                        option.usage.iter().sum::<u32>(),
+                        option.overmelds,
+                    )
+                },
+                |a, b| {
+                    stats_le(b.stats, a.stats)
+                        && usage_le(&a.usage, &b.usage)
+                        && a.overmelds <= b.overmelds
+                },
+            );
+            piece_options.push(options);
+        }
+
+        let mut partial_candidates = vec![PartialCandidate {
+            usage: vec![0; materia.len()],
+            stats: GearStats::default(),
+            overmelds: 0,
+            options: Vec::new(),
+        }];
+        for options in piece_options.iter() {
+            let mut next = Vec::new();
+            for partial in partial_candidates.iter() {
+                for (index, option) in options.iter().enumerate() {
+                    let usage: Vec<u32> = partial
+                        .usage
+                        .iter()
+                        .zip(option.usage.iter())
+                        .map(|(a, b)| a + b)
+                        .collect();
+                    if !usage_le(&usage, &amounts) {
+                        continue;
+                    }
+                    let mut options = partial.options.clone();
+                    options.push(index);
+                    next.push(PartialCandidate {
+                        usage,
+                        stats: partial.stats + option.stats,
+                        overmelds: partial.overmelds + option.overmelds,
+                        options,
+                    });
+                }
+            }
+            retain_pareto_optimal(
+                &mut next,
+                |partial| {
+                    (
+                        std::cmp::Reverse(stats_sum(partial.stats)),
+                        partial.usage.iter().sum::<u32>(),
+                        partial.overmelds,
+                    )
+                },
+                |a, b| {
+                    stats_le(b.stats, a.stats)
+                        && usage_le(&a.usage, &b.usage)
+                        && a.overmelds <= b.overmelds
+                },
+            );
+            partial_candidates = next;
+        }
+
+        let mut candidates = Vec::new();
+        for partial in partial_candidates {
+            let mut gearset = gearset.clone();
+            for (piece_index, option_index) in partial.options.into_iter().enumerate() {
+                let option = &piece_options[piece_index][option_index];
+                let mut melds = Vec::new();
+                for (materia, amount) in materia.iter().zip(option.usage.iter()) {
+                    for _ in 0..*amount {
+                        melds.push(*materia);
+                    }
+                }
+                sort_new_melds(&mut melds);
+                gearset.pieces[piece_index]
+                    .melds
+                    .extend(melds.iter().map(|materia| materia.item_id));
+            }
+            let crafter_stats = gearset.crafter_stats_in(
+                self.gear_data,
+                self.job_id,
+                self.level,
+                self.manipulation,
+            )?;
+            let Ok(settings) = self.settings(crafter_stats) else {
+                // stat requirements of the recipe are not met
+                continue;
+            };
+            candidates.push((
+                settings,
+                MeldCandidate {
+                    gearset,
+                    crafter_stats,
+                    overmelds: partial.overmelds,
+                },
+            ));
+        }
+        // stat differences that don't change the Settings can't help
+        retain_pareto_optimal(
+            &mut candidates,
+            |(settings, candidate)| {
+                (
+                    std::cmp::Reverse(
+                        settings.base_progress as i32
+                            + settings.base_quality as i32
+                            + settings.max_cp as i32,
+                    ),
+                    candidate.overmelds,
+                )
+            },
+            |(a, a_candidate), (b, b_candidate)| {
+                a.base_progress >= b.base_progress
+                    && a.base_quality >= b.base_quality
+                    && a.max_cp >= b.max_cp
+                    && a_candidate.overmelds <= b_candidate.overmelds
+            },
+        );
+        Ok(candidates
+            .into_iter()
+            .map(|(_, candidate)| candidate)
+            .collect())
+    }
+
+    /// Calls `f` for every multiset of at most `open_slots` materia that fits the inventory.
+    fn enumerate_melds(
+        materia: &[Materia],
+        amounts: &[u32],
+        start: usize,
+        open_slots: usize,
+        usage: &mut [u32],
+        melds: &mut Vec<Materia>,
+        f: &mut impl FnMut(&[u32], &[Materia]),
+    ) {
+        f(usage, melds);
+        if open_slots == 0 {
+            return;
+        }
+        for index in start..materia.len() {
+            if usage[index] < amounts[index] {
+                usage[index] += 1;
+                melds.push(materia[index]);
+                Self::enumerate_melds(materia, amounts, index, open_slots - 1, usage, melds, f);
+                melds.pop();
+                usage[index] -= 1;
+            }
+        }
+    }
+
+    /// Max Quality is lowered to the required Quality for recipes that have one.
+    fn settings(&self, crafter_stats: CrafterStats) -> Result<Settings, &'static str> {
+        let mut settings = get_game_settings_for_version(
+            self.recipe_config,
+            crafter_stats,
+            self.food,
+            self.potion,
+            false,
+            self.game_version,
+        )?;
+        QualityTarget::Full.apply(&self.recipe_config.recipe, &mut settings);
+        Ok(settings)
+    }
+
+    /// Returns `None` if no combination of melds reaches the objective.
+    /// Candidates are evaluated with the `MacroSolver` in order of their quality upper bound,
+    /// so candidates that can't beat the best solution found so far are never solved.
+    pub fn optimize(
+        &self,
+        gearset: &Gearset,
+        inventory: &[(u32, u32)],
+    ) -> Result<Option<MeldSolution>, &'static str> {
+        let mut candidates: Vec<(u16, MeldCandidate)> = self
+            .candidates(gearset, inventory)?
+            .into_iter()
+            .map(|candidate| {
+                let settings = self.settings(candidate.crafter_stats).unwrap();
+                let upper_bound =
+                    UpperBoundSolver::new(settings).quality_upper_bound(InProgress::new(&settings));
+                (upper_bound, candidate)
+            })
+            .collect();
+
+        match self.objective {
+            MeldObjective::MaximizeQuality => {
+                candidates.sort_by_key(|(upper_bound, candidate)| {
+                    (std::cmp::Reverse(*upper_bound), candidate.overmelds)
+                });
+                let mut best: Option<MeldSolution> = None;
+                for (upper_bound, candidate) in candidates {
+                    if let Some(best) = best.as_ref() {
+                        if upper_bound < best.quality
+                            || (upper_bound == best.quality
+                                && candidate.overmelds >= best.candidate.overmelds)
+                        {
+                            continue;
+                        }
+                    }
+                    if let Some(solution) = self.solve(candidate) {
+                        let is_better = match best.as_ref() {
+                            Some(best) => {
+                                solution.quality > best.quality
+                                    || (solution.quality == best.quality
+                                        && solution.candidate.overmelds < best.candidate.overmelds)
+                            }
+                            None => true,
+                        };
+                        if is_better {
+                            best = Some(solution);
+                        }
+                    }
+                }
+                Ok(best)
+            }
+            MeldObjective::ReachQuality(target) => {
+                candidates.sort_by_key(|(upper_bound, candidate)| {
+                    (candidate.overmelds, std::cmp::Reverse(*upper_bound))
+                });
+                for (upper_bound, candidate) in candidates {
+                    if upper_bound < target {
+                        continue;
+                    }
+                    match self.solve(candidate) {
+                        Some(solution) if solution.quality >= target => return Ok(Some(solution)),
+                        _ => (),
+                    }
+                }
+                Ok(None)
+            }
+        }
+    }
+
+    fn solve(&self, candidate: MeldCandidate) -> Option<MeldSolution> {
+        let settings = self.settings(candidate.crafter_stats).ok()?;
+        let actions = MacroSolver::new(settings, Box::new(|_| {}))
+            .solve(InProgress::new(&settings), false)?;
+        let state = SimulationState::from_macro(&settings, &actions).ok()?;
+        Some(MeldSolution {
+            candidate,
+            quality: settings.max_quality - state.get_missing_quality(),
+            actions,
+        })
+    }
+}
@@ -0,0 +1,218 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{Consumable, CrafterStats, Gearset};
+
+/// Version of the profile file format written by `CrafterConfig::export_profiles`.
+const PROFILE_FILE_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CrafterProfile {
+    pub name: String,
+    pub crafter_stats: CrafterStats,
+    #[serde(default)]
+    pub gearset: Gearset,
+    /// Food that is selected when switching to this profile.
+    pub food: Option<Consumable>,
+    /// Potion that is selected when switching to this profile.
+    pub potion: Option<Consumable>,
+}
+
+impl CrafterProfile {
+    pub fn new(name: impl Into<String>, crafter_stats: CrafterStats) -> Self {
+        Self {
+            name: name.into(),
+            crafter_stats,
+            gearset: Gearset::default(),
+            food: None,
+            potion: None,
+        }
+    }
+}
+
+impl Default for CrafterProfile {
+    fn default() -> Self {
+        Self::new("Default", CrafterStats::default())
+    }
+}
+
+/// Format of `CrafterConfig` before profiles were introduced, stored under the `CRAFTER_CONFIG` key.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct LegacyCrafterConfig {
+    pub selected_job: u8,
+    pub crafter_stats: [CrafterStats; 8],
+}
+
+#[derive(Deserialize, Serialize)]
+struct ProfileFile {
+    version: u32,
+    profiles: [Vec<CrafterProfile>; 8],
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CrafterConfig {
+    pub selected_job: u8,
+    /// Named profiles of each job. Every job has at least one profile.
+    pub profiles: [Vec<CrafterProfile>; 8],
+    /// Index into `profiles` of the active profile of each job.
+    pub selected_profiles: [usize; 8],
+}
+
+impl CrafterConfig {
+    /// Converts the legacy config into one profile per job, with the gearset of the job.
+    /// The previously selected consumables only become the defaults of the profile of the selected job.
+    pub fn migrate(
+        legacy: LegacyCrafterConfig,
+        food: Option<Consumable>,
+        potion: Option<Consumable>,
+        gearsets: [Gearset; 8],
+    ) -> Self {
+        let mut gearsets = gearsets.into_iter();
+        let mut job_id = 0;
+        Self {
+            selected_job: legacy.selected_job,
+            profiles: legacy.crafter_stats.map(|crafter_stats| {
+                let selected = job_id == legacy.selected_job;
+                job_id += 1;
+                vec![CrafterProfile {
+                    gearset: gearsets.next().unwrap(),
+                    food: food.filter(|_| selected),
+                    potion: potion.filter(|_| selected),
+                    ..CrafterProfile::new("Default", crafter_stats)
+                }]
+            }),
+            selected_profiles: [0; 8],
+        }
+    }
+
+    /// Makes sure that every job has a profile and that the selected profiles exist.
+    pub fn normalize(&mut self) {
+        for (profiles, selected) in self
+            .profiles
+            .iter_mut()
+            .zip(self.selected_profiles.iter_mut())
+        {
+            if profiles.is_empty() {
+                profiles.push(CrafterProfile::default());
+            }
+            *selected = (*selected).min(profiles.len() - 1);
+        }
+    }
+
+    pub fn job_profile(&self, job_id: u8) -> &CrafterProfile {
+        &self.profiles[job_id as usize][self.selected_profiles[job_id as usize]]
+    }
+
+    pub fn job_profile_mut(&mut self, job_id: u8) -> &mut CrafterProfile {
+        &mut self.profiles[job_id as usize][self.selected_profiles[job_id as usize]]
+    }
+
+    pub fn profile(&self) -> &CrafterProfile {
+        self.job_profile(self.selected_job)
+    }
+
+    pub fn profile_mut(&mut self) -> &mut CrafterProfile {
+        self.job_profile_mut(self.selected_job)
+    }
+
+    /// Adds a copy of the active profile of the job and selects it.
+    pub fn duplicate_profile(&mut self, job_id: u8, name: impl Into<String>) {
+        let profile = CrafterProfile {
+            name: name.into(),
+            ..self.job_profile(job_id).clone()
+        };
+        self.profiles[job_id as usize].push(profile);
+        self.selected_profiles[job_id as usize] = self.profiles[job_id as usize].len() - 1;
+    }
+
+    /// Removes the active profile of the job, unless it is the only one.
+    pub fn remove_profile(&mut self, job_id: u8) {
+        let profiles = &mut self.profiles[job_id as usize];
+        if profiles.len() > 1 {
+            let index = self.selected_profiles[job_id as usize];
+            profiles.remove(index);
+            self.selected_profiles[job_id as usize] = index.saturating_sub(1);
+        }
+    }
+
+    pub fn export_profiles(&self) -> String {
+        let file = ProfileFile {
+            version: PROFILE_FILE_VERSION,
+            profiles: self.profiles.clone(),
+        };
+        serde_json::to_string_pretty(&file).unwrap()
+    }
+
+    /// Adds the profiles of an exported profile file.
+    /// Existing profiles with the same name are replaced. Returns the number of imported profiles.
+    pub fn import_profiles(&mut self, json: &str) -> Result<usize, &'static str> {
+        let file: ProfileFile = serde_json::from_str(json).map_err(|_| "Invalid profile file")?;
+        if file.version > PROFILE_FILE_VERSION {
+            return Err("Profile file was created by a newer version");
+        }
+        let mut count = 0;
+        for (profiles, imported) in self.profiles.iter_mut().zip(file.profiles) {
+            for profile in imported {
+                match profiles.iter_mut().find(|p| p.name == profile.name) {
+                    Some(existing) => *existing = profile,
+                    None => profiles.push(profile),
+                }
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
+    pub fn stats(&self) -> CrafterStats {
+        self.profile().crafter_stats
+    }
+
+    pub fn craftsmanship(&self) -> u16 {
+        self.profile().crafter_stats.craftsmanship
+    }
+
+    pub fn craftsmanship_mut(&mut self) -> &mut u16 {
+        &mut self.profile_mut().crafter_stats.craftsmanship
+    }
+
+    pub fn control(&self) -> u16 {
+        self.profile().crafter_stats.control
+    }
+
+    pub fn control_mut(&mut self) -> &mut u16 {
+        &mut self.profile_mut().crafter_stats.control
+    }
+
+    pub fn cp(&self) -> u16 {
+        self.profile().crafter_stats.cp
+    }
+
+    pub fn cp_mut(&mut self) -> &mut u16 {
+        &mut self.profile_mut().crafter_stats.cp
+    }
+
+    pub fn level(&self) -> u8 {
+        self.profile().crafter_stats.level
+    }
+
+    pub fn level_mut(&mut self) -> &mut u8 {
+        &mut self.profile_mut().crafter_stats.level
+    }
+
+    pub fn manipulation(&self) -> bool {
+        self.profile().crafter_stats.manipulation
+    }
+
+    pub fn manipulation_mut(&mut self) -> &mut bool {
+        &mut self.profile_mut().crafter_stats.manipulation
+    }
+}
+
+impl Default for CrafterConfig {
+    fn default() -> Self {
+        Self {
+            selected_job: 1,
+            profiles: std::array::from_fn(|_| vec![CrafterProfile::default()]),
+            selected_profiles: [0; 8],
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use serde::{Deserialize, Serialize};
+use simulator::{Action, ComboAction, GameVersion};
+
+/// JSON rotation formats of other crafting tools.
+/// Both formats store a rotation as an array of action identifiers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum RotationFormat {
+    /// FFXIV Teamcraft, e.g. `["MuscleMemory", "WasteNotII"]`.
+    #[default]
+    Teamcraft,
+    /// ffxiv-craft-opt-web, e.g. `["muscleMemory", "wasteNot2"]`.
+    CraftOpt,
+}
+
+impl std::fmt::Display for RotationFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Teamcraft => write!(f, "Teamcraft"),
+            Self::CraftOpt => write!(f, "Craft Opt"),
+        }
+    }
+}
+
+/// An action identifier of an imported rotation that doesn't correspond to any `Action`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownAction {
+    /// Position of the action in the imported rotation.
+    pub index: usize,
+    pub id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportedRotation {
+    pub actions: Vec<Action>,
+    /// Actions that were skipped because they couldn't be mapped.
+    pub unknown_actions: Vec<UnknownAction>,
+}
+
+/// Combo and non-combo versions of an action share the same identifier.
+pub const fn action_id(action: Action, format: RotationFormat) -> &'static str {
+    match format {
+        RotationFormat::Teamcraft => teamcraft_action_id(action),
+        RotationFormat::CraftOpt => craft_opt_action_id(action),
+    }
+}
+
+const fn teamcraft_action_id(action: Action) -> &'static str {
+    match action {
+        Action::BasicSynthesis => "BasicSynthesis",
+        Action::BasicTouch => "BasicTouch",
+        Action::MasterMend => "MastersMend",
+        Action::Observe => "Observe",
+        Action::WasteNot => "WasteNot",
+        Action::Veneration => "Veneration",
+        Action::StandardTouch | Action::ComboStandardTouch => "StandardTouch",
+        Action::GreatStrides => "GreatStrides",
+        Action::Innovation => "Innovation",
+        Action::WasteNot2 => "WasteNotII",
+        Action::ByregotsBlessing => "ByregotsBlessing",
+        Action::PreciseTouch => "PreciseTouch",
+        Action::MuscleMemory => "MuscleMemory",
+        Action::CarefulSynthesis => "CarefulSynthesis",
+        Action::Manipulation => "Manipulation",
+        Action::PrudentTouch => "PrudentTouch",
+        Action::AdvancedTouch | Action::ComboAdvancedTouch => "AdvancedTouch",
+        Action::Reflect => "Reflect",
+        Action::PreparatoryTouch => "PreparatoryTouch",
+        Action::Groundwork => "Groundwork",
+        Action::DelicateSynthesis => "DelicateSynthesis",
+        Action::IntensiveSynthesis => "IntensiveSynthesis",
+        Action::PrudentSynthesis => "PrudentSynthesis",
+        Action::TrainedFinesse => "TrainedFinesse",
+        Action::ComboRefinedTouch => "RefinedTouch",
+        Action::ImmaculateMend => "ImmaculateMend",
+        Action::TrainedPerfection => "TrainedPerfection",
+        Action::TrainedEye => "TrainedEye",
+    }
+}
+
+const fn craft_opt_action_id(action: Action) -> &'static str {
+    match action {
+        Action::BasicSynthesis => "basicSynth",
+        Action::BasicTouch => "basicTouch",
+        Action::MasterMend => "mastersMend",
+        Action::Observe => "observe",
+        Action::WasteNot => "wasteNot",
+        Action::Veneration => "veneration",
+        Action::StandardTouch | Action::ComboStandardTouch => "standardTouch",
+        Action::GreatStrides => "greatStrides",
+        Action::Innovation => "innovation",
+        Action::WasteNot2 => "wasteNot2",
+        Action::ByregotsBlessing => "byregotsBlessing",
+        Action::PreciseTouch => "preciseTouch",
+        Action::MuscleMemory => "muscleMemory",
+        Action::CarefulSynthesis => "carefulSynthesis",
+        Action::Manipulation => "manipulation",
+        Action::PrudentTouch => "prudentTouch",
+        Action::AdvancedTouch | Action::ComboAdvancedTouch => "advancedTouch",
+        Action::Reflect => "reflect",
+        Action::PreparatoryTouch => "preparatoryTouch",
+        Action::Groundwork => "groundwork",
+        Action::DelicateSynthesis => "delicateSynthesis",
+        Action::IntensiveSynthesis => "intensiveSynthesis",
+        Action::PrudentSynthesis => "prudentSynthesis",
+        Action::TrainedFinesse => "trainedFinesse",
+        Action::ComboRefinedTouch => "refinedTouch",
+        Action::ImmaculateMend => "immaculateMend",
+        Action::TrainedPerfection => "trainedPerfection",
+        Action::TrainedEye => "trainedEye",
+    }
+}
+
+// Combo versions are derived from the preceding action when importing.
+const IMPORTABLE_ACTIONS: [Action; 28] = [
+    Action::BasicSynthesis,
+    Action::BasicTouch,
+    Action::MasterMend,
+    Action::Observe,
+    Action::WasteNot,
+    Action::Veneration,
+    Action::StandardTouch,
+    Action::GreatStrides,
+    Action::Innovation,
+    Action::WasteNot2,
+    Action::ByregotsBlessing,
+    Action::PreciseTouch,
+    Action::MuscleMemory,
+    Action::CarefulSynthesis,
+    Action::Manipulation,
+    Action::PrudentTouch,
+    Action::AdvancedTouch,
+    Action::Reflect,
+    Action::PreparatoryTouch,
+    Action::Groundwork,
+    Action::DelicateSynthesis,
+    Action::IntensiveSynthesis,
+    Action::PrudentSynthesis,
+    Action::TrainedFinesse,
+    Action::ComboRefinedTouch,
+    Action::ImmaculateMend,
+    Action::TrainedPerfection,
+    Action::TrainedEye,
+];
+
+fn parse_action_id(id: &str, format: RotationFormat) -> Option<Action> {
+    // ffxiv-craft-opt-web has separate actions for the versions upgraded by traits
+    let id = match (format, id) {
+        (RotationFormat::CraftOpt, "basicSynth2") => "basicSynth",
+        (RotationFormat::CraftOpt, "carefulSynthesis2") => "carefulSynthesis",
+        (RotationFormat::CraftOpt, "groundwork2") => "groundwork",
+        (_, id) => id,
+    };
+    IMPORTABLE_ACTIONS
+        .iter()
+        .find(|action| action_id(**action, format) == id)
+        .copied()
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RotationJson {
+    Actions(Vec<String>),
+    /// Rotation objects saved by Teamcraft also carry a name and other metadata.
+    Rotation {
+        rotation: Vec<String>,
+    },
+}
+
+pub fn export_rotation(actions: &[Action], format: RotationFormat) -> String {
+    let ids: Vec<&str> = actions
+        .iter()
+        .map(|action| action_id(*action, format))
+        .collect();
+    serde_json::to_string(&ids).unwrap()
+}
+
+/// Returns the combo version of an action that shares its identifier.
+const fn combo_version(action: Action) -> Option<Action> {
+    match action {
+        Action::StandardTouch => Some(Action::ComboStandardTouch),
+        Action::AdvancedTouch => Some(Action::ComboAdvancedTouch),
+        _ => None,
+    }
+}
+
+/// Parses a rotation of the given format.
+/// Actions that can't be mapped are skipped and reported in `ImportedRotation::unknown_actions`.
+/// They still break the combo, because they were used in the source rotation.
+/// Actions are imported as their combo version whenever the combo is fulfilled in the given game version.
+pub fn import_rotation(
+    json: &str,
+    format: RotationFormat,
+    game_version: GameVersion,
+) -> Result<ImportedRotation, &'static str> {
+    let ids = match serde_json::from_str(json).map_err(|_| "Invalid rotation JSON")? {
+        RotationJson::Actions(ids) => ids,
+        RotationJson::Rotation { rotation } => rotation,
+    };
+    let mut actions: Vec<Action> = Vec::new();
+    let mut unknown_actions = Vec::new();
+    let mut combo = Some(ComboAction::SynthesisBegin);
+    for (index, id) in ids.into_iter().enumerate() {
+        let Some(action) = parse_action_id(&id, format) else {
+            unknown_actions.push(UnknownAction { index, id });
+            combo = None;
+            continue;
+        };
+        let action = match combo_version(action) {
+            Some(combo_action) if combo_action.combo_fulfilled(combo) => combo_action,
+            _ => action,
+        };
+        combo = action.to_combo(game_version);
+        actions.push(action);
+    }
+    Ok(ImportedRotation {
+        actions,
+        unknown_actions,
+    })
+}
@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{BuiltinGameData, Consumable, GameDataSource, Item, Locale, Recipe, RecipeLevel};
+
+/// Game data that is loaded at runtime, either from the game's CSV files or from a bundle.
+/// Allows using data of a new patch without rebuilding the application.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GameData {
+    pub version: String,
+    /// Indexed by recipe level.
+    pub rlvls: Vec<RecipeLevel>,
+    pub recipes: Vec<Recipe>,
+    pub items: HashMap<u32, Item>,
+    pub item_names: HashMap<Locale, HashMap<u32, String>>,
+    /// Sorted by decreasing item level, with the HQ variant of each item before its NQ variant.
+    pub meals: Vec<Consumable>,
+    /// Sorted by decreasing item level, with the HQ variant of each item before its NQ variant.
+    pub potions: Vec<Consumable>,
+}
+
+const LOCALES: [Locale; 6] = [
+    Locale::EN,
+    Locale::DE,
+    Locale::FR,
+    Locale::JP,
+    Locale::KO,
+    Locale::CN,
+];
+
+impl GameData {
+    /// Copies the data that is compiled into the binary.
+    pub fn builtin() -> Self {
+        let source = BuiltinGameData;
+        let items: HashMap<u32, Item> = crate::ITEMS
+            .entries()
+            .map(|(item_id, item)| (*item_id, *item))
+            .collect();
+        let item_names = LOCALES
+            .into_iter()
+            .map(|locale| {
+                let names = items
+                    .keys()
+                    .filter_map(|&item_id| {
+                        source
+                            .item_name(item_id, locale)
+                            .map(|name| (item_id, name.to_string()))
+                    })
+                    .collect();
+                (locale, names)
+            })
+            .collect();
+        Self {
+            version: source.version().to_string(),
+            rlvls: crate::RLVLS.to_vec(),
+            recipes: source.recipes().to_vec(),
+            items,
+            item_names,
+            meals: source.meals().to_vec(),
+            potions: source.potions().to_vec(),
+        }
+    }
+
+    /// Serializes the data into a compact binary bundle that can be loaded with `GameData::from_bundle`.
+    #[cfg(feature = "bundle")]
+    pub fn to_bundle(&self) -> Result<Vec<u8>, bincode::Error> {
+        bincode::serialize(self)
+    }
+
+    #[cfg(feature = "bundle")]
+    pub fn from_bundle(bytes: &[u8]) -> Result<Self, bincode::Error> {
+        bincode::deserialize(bytes)
+    }
+}
+
+impl GameDataSource for GameData {
+    fn version(&self) -> &str {
+        &self.version
+    }
+
+    fn recipes(&self) -> &[Recipe] {
+        &self.recipes
+    }
+
+    fn rlvl(&self, recipe_level: u16) -> Option<&RecipeLevel> {
+        self.rlvls.get(recipe_level as usize)
+    }
+
+    fn item(&self, item_id: u32) -> Option<&Item> {
+        self.items.get(&item_id)
+    }
+
+    fn item_name(&self, item_id: u32, locale: Locale) -> Option<&str> {
+        self.item_names
+            .get(&locale)?
+            .get(&item_id)
+            .map(String::as_str)
+    }
+
+    fn meals(&self) -> &[Consumable] {
+        &self.meals
+    }
+
+    fn potions(&self) -> &[Consumable] {
+        &self.potions
+    }
+}
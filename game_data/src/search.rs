@@ -0,0 +1,136 @@
+use std::ops::RangeInclusive;
+
+use crate::{get_item_name, Locale, Recipe, ITEMS, RECIPES};
+
+/// Returns true if every whitespace-separated word of `pattern` is contained in a word of `string`,
+/// with the words of `pattern` appearing in the same order as in `string`.
+pub fn contains_noncontiguous(string: &str, pattern: &str) -> bool {
+    let mut it = string.split_whitespace();
+    for c in pattern.split_whitespace() {
+        loop {
+            let Some(c2) = it.next() else {
+                return false;
+            };
+            if c2.contains(c) {
+                break;
+            }
+        }
+    }
+    true
+}
+
+/// How well an item name matches the search text. Higher is better.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MatchRank {
+    Words,
+    Substring,
+    Prefix,
+    Exact,
+}
+
+impl MatchRank {
+    /// Both `name` and `pattern` are expected to be lowercase.
+    pub fn of(name: &str, pattern: &str) -> Option<Self> {
+        let pattern = pattern.trim();
+        if name == pattern {
+            Some(Self::Exact)
+        } else if name.starts_with(pattern) {
+            Some(Self::Prefix)
+        } else if name.contains(pattern) {
+            Some(Self::Substring)
+        } else if contains_noncontiguous(name, pattern) {
+            Some(Self::Words)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RecipeSearch {
+    /// Matched against the localized name of the resulting item. An empty string matches everything.
+    pub text: String,
+    pub job_id: Option<u8>,
+    pub level: Option<RangeInclusive<u8>>,
+    pub recipe_level: Option<RangeInclusive<u16>>,
+    pub is_expert: Option<bool>,
+    pub is_collectable: Option<bool>,
+    /// Matched against the localized names of the ingredients. An empty string matches everything.
+    pub ingredient_text: String,
+}
+
+impl RecipeSearch {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the indices into `RECIPES` of all matching recipes.
+    /// Recipes are ranked by how well the item name matches the search text.
+    /// Recipes with the same rank keep the order in which they appear in `RECIPES`.
+    pub fn indices(&self, locale: Locale) -> Vec<usize> {
+        let pattern = self.text.to_lowercase();
+        let ingredient_pattern = self.ingredient_text.to_lowercase();
+        let mut matches: Vec<(MatchRank, usize)> = RECIPES
+            .iter()
+            .enumerate()
+            .filter(|(_, recipe)| self.matches_filters(recipe))
+            .filter(|(_, recipe)| {
+                ingredient_pattern.trim().is_empty()
+                    || recipe.ingredients.iter().any(|ingredient| {
+                        ingredient.item_id != 0
+                            && contains_noncontiguous(
+                                &get_item_name(ingredient.item_id, false, locale).to_lowercase(),
+                                &ingredient_pattern,
+                            )
+                    })
+            })
+            .filter_map(|(index, recipe)| {
+                let item_name = get_item_name(recipe.item_id, false, locale).to_lowercase();
+                MatchRank::of(&item_name, &pattern).map(|rank| (rank, index))
+            })
+            .collect();
+        // sort_by is stable, so recipes of the same rank stay in their original order
+        matches.sort_by_key(|&(rank, _)| std::cmp::Reverse(rank));
+        matches.into_iter().map(|(_, index)| index).collect()
+    }
+
+    /// Same as `indices`, but returns the matching recipes themselves.
+    pub fn recipes(&self, locale: Locale) -> Vec<Recipe> {
+        self.indices(locale)
+            .into_iter()
+            .map(|index| RECIPES[index])
+            .collect()
+    }
+
+    fn matches_filters(&self, recipe: &Recipe) -> bool {
+        if self.job_id.is_some_and(|job_id| job_id != recipe.job_id) {
+            return false;
+        }
+        if let Some(level) = &self.level {
+            if !level.contains(&recipe.level) {
+                return false;
+            }
+        }
+        if let Some(recipe_level) = &self.recipe_level {
+            if !recipe_level.contains(&recipe.recipe_level) {
+                return false;
+            }
+        }
+        if self
+            .is_expert
+            .is_some_and(|is_expert| is_expert != recipe.is_expert)
+        {
+            return false;
+        }
+        if let Some(is_collectable) = self.is_collectable {
+            match ITEMS.get(&recipe.item_id) {
+                Some(item) if item.is_collectable == is_collectable => (),
+                _ => return false,
+            }
+        }
+        true
+    }
+}
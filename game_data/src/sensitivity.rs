@@ -0,0 +1,202 @@
+use simulator::{state::InProgress, GameVersion, Settings, SimulationState};
+use solvers::{FinishSolver, MacroSolver, UpperBoundSolver};
+
+use crate::{
+    get_game_settings_for_version, Consumable, CrafterStats, QualityTarget, RecipeConfiguration,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrafterStat {
+    Craftsmanship,
+    Control,
+    Cp,
+}
+
+impl CrafterStat {
+    pub const ALL: [CrafterStat; 3] = [Self::Craftsmanship, Self::Control, Self::Cp];
+
+    fn add(self, crafter_stats: CrafterStats, points: u16) -> CrafterStats {
+        let mut crafter_stats = crafter_stats;
+        match self {
+            Self::Craftsmanship => crafter_stats.craftsmanship += points,
+            Self::Control => crafter_stats.control += points,
+            Self::Cp => crafter_stats.cp += points,
+        }
+        crafter_stats
+    }
+}
+
+impl std::fmt::Display for CrafterStat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Craftsmanship => write!(f, "Craftsmanship"),
+            Self::Control => write!(f, "Control"),
+            Self::Cp => write!(f, "CP"),
+        }
+    }
+}
+
+/// Additional stat points at which base Progress or base Quality changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatBreakpoint {
+    pub points: u16,
+    pub base_progress: u16,
+    pub base_quality: u16,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatSensitivity {
+    pub stat: CrafterStat,
+    /// Base Progress and base Quality are integer-divided, so Craftsmanship and Control only matter at these breakpoints.
+    /// CP doesn't affect either, so it never has breakpoints.
+    pub breakpoints: Vec<StatBreakpoint>,
+    /// Fewest additional points that improve the optimal rotation, together with the Quality it reaches.
+    /// `None` if there is no improvement within the searched range.
+    pub improvement: Option<(u16, u16)>,
+}
+
+/// Quality of the optimal rotation, capped at max Quality. `None` if the recipe cannot be completed.
+fn optimal_quality(settings: &Settings) -> Option<u16> {
+    let state = InProgress::new(settings);
+    if !FinishSolver::new(*settings).can_finish(&state) {
+        return None;
+    }
+    let actions = MacroSolver::new(*settings, Box::new(|_| {})).solve(state, false)?;
+    let final_state = SimulationState::from_macro(settings, &actions).ok()?;
+    Some(settings.max_quality - final_state.get_missing_quality())
+}
+
+/// Returns true if the rotation is better than the baseline. Completing the recipe at all is an improvement.
+fn is_improvement(quality: Option<u16>, baseline: Option<u16>) -> bool {
+    match (quality, baseline) {
+        (Some(quality), Some(baseline)) => quality > baseline,
+        (Some(_), None) => true,
+        (None, _) => false,
+    }
+}
+
+/// Analyzes how much each stat improves the optimal rotation of the recipe, looking up to `max_points` additional points ahead.
+///
+/// Adding stats never makes the optimal rotation worse, so the fewest points that improve it are found with a binary search over the candidates.
+/// Candidates are the breakpoints for Craftsmanship and Control and every point for CP.
+/// Before searching, an upper-bound probe at the largest candidate rules out stats that cannot improve the rotation at all.
+/// Returns an error if the crafter doesn't meet the stat requirements of the recipe.
+pub fn stat_sensitivity(
+    recipe_config: RecipeConfiguration,
+    crafter_stats: CrafterStats,
+    food: Option<Consumable>,
+    potion: Option<Consumable>,
+    game_version: GameVersion,
+    max_points: u16,
+) -> Result<Vec<StatSensitivity>, &'static str> {
+    let recipe = recipe_config.recipe;
+    let mut settings = get_game_settings_for_version(
+        recipe_config,
+        crafter_stats,
+        food,
+        potion,
+        false,
+        game_version,
+    )?;
+    QualityTarget::Full.apply(&recipe, &mut settings);
+    let baseline = optimal_quality(&settings);
+    let settings_with = |stat: CrafterStat, points: u16| {
+        let mut settings = get_game_settings_for_version(
+            recipe_config,
+            stat.add(crafter_stats, points),
+            food,
+            potion,
+            false,
+            game_version,
+        )
+        .unwrap();
+        QualityTarget::Full.apply(&recipe, &mut settings);
+        settings
+    };
+
+    Ok(CrafterStat::ALL
+        .into_iter()
+        .map(|stat| {
+            let mut breakpoints: Vec<StatBreakpoint> = Vec::new();
+            if stat != CrafterStat::Cp {
+                let (mut base_progress, mut base_quality) =
+                    (settings.base_progress, settings.base_quality);
+                for points in 1..=max_points {
+                    let settings = settings_with(stat, points);
+                    if settings.base_progress != base_progress
+                        || settings.base_quality != base_quality
+                    {
+                        base_progress = settings.base_progress;
+                        base_quality = settings.base_quality;
+                        breakpoints.push(StatBreakpoint {
+                            points,
+                            base_progress,
+                            base_quality,
+                        });
+                    }
+                }
+            }
+
+            let candidates: Vec<u16> = match stat {
+                CrafterStat::Cp => (1..=max_points).collect(),
+                _ => breakpoints
+                    .iter()
+                    .map(|breakpoint| breakpoint.points)
+                    .collect(),
+            };
+            let improvement = match (candidates.last(), baseline) {
+                (None, _) => None,
+                (Some(_), Some(quality)) if quality == settings.max_quality => None,
+                (Some(&last), _) => {
+                    let last_settings = settings_with(stat, last);
+                    let state = InProgress::new(&last_settings);
+                    let may_improve = FinishSolver::new(last_settings).can_finish(&state)
+                        && baseline.is_none_or(|quality| {
+                            UpperBoundSolver::new(last_settings).quality_upper_bound(state)
+                                > quality
+                        });
+                    match may_improve {
+                        true => {
+                            // find the first candidate that improves the rotation
+                            let (mut low, mut high) = (0, candidates.len());
+                            let mut best = None;
+                            while low < high {
+                                let mid = (low + high) / 2;
+                                let quality =
+                                    optimal_quality(&settings_with(stat, candidates[mid]));
+                                match is_improvement(quality, baseline) {
+                                    true => {
+                                        best = Some((candidates[mid], quality.unwrap()));
+                                        high = mid;
+                                    }
+                                    false => low = mid + 1,
+                                }
+                            }
+                            best
+                        }
+                        false => None,
+                    }
+                }
+            };
+
+            StatSensitivity {
+                stat,
+                breakpoints,
+                improvement,
+            }
+        })
+        .collect())
+}
+
+/// Returns the stat that improves the rotation with the fewest additional points.
+pub fn bottleneck_stat(sensitivities: &[StatSensitivity]) -> Option<CrafterStat> {
+    sensitivities
+        .iter()
+        .filter_map(|sensitivity| {
+            sensitivity
+                .improvement
+                .map(|(points, _)| (points, sensitivity.stat))
+        })
+        .min_by_key(|(points, _)| *points)
+        .map(|(_, stat)| stat)
+}
@@ -0,0 +1,363 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use bincode::Options;
+use serde::{Deserialize, Serialize};
+use simulator::{Action, GameVersion};
+
+use crate::{
+    Consumable, CrafterStats, Ingredient, QualityTarget, Recipe, RecipeConfiguration, SolverConfig,
+    MEALS, POTIONS,
+};
+
+/// Version of the encoding produced by `encode_share_fragment`.
+/// Links of all older versions can still be decoded.
+pub const SHARE_FORMAT_VERSION: u8 = 3;
+
+/// Everything that is needed to reproduce a solve on another machine.
+#[derive(Debug, Clone)]
+pub struct SharedSetup {
+    pub recipe_config: RecipeConfiguration,
+    pub crafter_stats: CrafterStats,
+    pub food: Option<Consumable>,
+    pub potion: Option<Consumable>,
+    pub solver_config: SolverConfig,
+    pub actions: Option<Vec<Action>>,
+}
+
+// Actions are encoded by their index in this table.
+// Only ever append to it, otherwise existing links decode to different actions.
+const ACTIONS_V1: [Action; 30] = [
+    Action::BasicSynthesis,
+    Action::BasicTouch,
+    Action::MasterMend,
+    Action::Observe,
+    Action::WasteNot,
+    Action::Veneration,
+    Action::StandardTouch,
+    Action::ComboStandardTouch,
+    Action::GreatStrides,
+    Action::Innovation,
+    Action::WasteNot2,
+    Action::ByregotsBlessing,
+    Action::PreciseTouch,
+    Action::MuscleMemory,
+    Action::CarefulSynthesis,
+    Action::Manipulation,
+    Action::PrudentTouch,
+    Action::AdvancedTouch,
+    Action::ComboAdvancedTouch,
+    Action::Reflect,
+    Action::PreparatoryTouch,
+    Action::Groundwork,
+    Action::DelicateSynthesis,
+    Action::IntensiveSynthesis,
+    Action::PrudentSynthesis,
+    Action::TrainedFinesse,
+    Action::ComboRefinedTouch,
+    Action::ImmaculateMend,
+    Action::TrainedPerfection,
+    Action::TrainedEye,
+];
+
+// The types below define the encoding of each version and must not be changed.
+// Changes to the shared state require a new version with its own types.
+// Version 2 added the HQ% quality targets, everything else is the same as in version 1.
+// Version 3 appended the game version to the encoding of version 2.
+
+#[derive(Serialize, Deserialize)]
+struct RecipeV1 {
+    job_id: u8,
+    item_id: u32,
+    level: u8,
+    recipe_level: u16,
+    progress: u16,
+    quality: u16,
+    durability: i8,
+    material_quality_factor: u16,
+    ingredients: [(u32, u32); 6],
+    is_expert: bool,
+    required_craftsmanship: u16,
+    required_control: u16,
+    required_quality: u16,
+    result_amount: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+enum QualityTargetV1 {
+    Zero,
+    CollectableT1,
+    CollectableT2,
+    CollectableT3,
+    Full,
+    Custom(u16),
+}
+
+#[derive(Serialize, Deserialize)]
+enum QualityTargetV2 {
+    Zero,
+    CollectableT1,
+    CollectableT2,
+    CollectableT3,
+    Full,
+    Custom(u16),
+    MaximizeHq,
+    Hq(u8),
+}
+
+impl From<QualityTargetV1> for QualityTargetV2 {
+    fn from(quality_target: QualityTargetV1) -> Self {
+        match quality_target {
+            QualityTargetV1::Zero => Self::Zero,
+            QualityTargetV1::CollectableT1 => Self::CollectableT1,
+            QualityTargetV1::CollectableT2 => Self::CollectableT2,
+            QualityTargetV1::CollectableT3 => Self::CollectableT3,
+            QualityTargetV1::Full => Self::Full,
+            QualityTargetV1::Custom(quality) => Self::Custom(quality),
+        }
+    }
+}
+
+/// `Q` is the encoding of the quality target, which is the only difference between versions 1 and 2.
+#[derive(Serialize, Deserialize)]
+struct SharedSetupWire<Q> {
+    recipe: RecipeV1,
+    hq_ingredients: [u8; 6],
+    craftsmanship: u16,
+    control: u16,
+    cp: u16,
+    level: u8,
+    manipulation: bool,
+    /// Item id and HQ flag of the food.
+    food: Option<(u32, bool)>,
+    /// Item id and HQ flag of the potion.
+    potion: Option<(u32, bool)>,
+    quality_target: Q,
+    backload_progress: bool,
+    adversarial: bool,
+    actions: Option<Vec<u8>>,
+}
+
+fn bincode_options() -> impl Options {
+    bincode::DefaultOptions::new().reject_trailing_bytes()
+}
+
+fn find_consumable(
+    consumables: &[Consumable],
+    consumable: Option<(u32, bool)>,
+) -> Result<Option<Consumable>, &'static str> {
+    match consumable {
+        Some((item_id, hq)) => consumables
+            .iter()
+            .find(|item| item.item_id == item_id && item.hq == hq)
+            .copied()
+            .map(Some)
+            .ok_or("Unknown consumable"),
+        None => Ok(None),
+    }
+}
+
+type SharedSetupV1 = SharedSetupWire<QualityTargetV1>;
+type SharedSetupV2 = SharedSetupWire<QualityTargetV2>;
+
+#[derive(Serialize, Deserialize)]
+enum GameVersionV3 {
+    Endwalker,
+    Dawntrail,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SharedSetupV3 {
+    setup: SharedSetupV2,
+    game_version: GameVersionV3,
+}
+
+impl SharedSetupV1 {
+    fn into_v2(self) -> SharedSetupV2 {
+        SharedSetupWire {
+            recipe: self.recipe,
+            hq_ingredients: self.hq_ingredients,
+            craftsmanship: self.craftsmanship,
+            control: self.control,
+            cp: self.cp,
+            level: self.level,
+            manipulation: self.manipulation,
+            food: self.food,
+            potion: self.potion,
+            quality_target: self.quality_target.into(),
+            backload_progress: self.backload_progress,
+            adversarial: self.adversarial,
+            actions: self.actions,
+        }
+    }
+}
+
+impl SharedSetupV2 {
+    fn new(setup: &SharedSetup) -> Self {
+        let recipe = setup.recipe_config.recipe;
+        let stats = setup.crafter_stats;
+        Self {
+            recipe: RecipeV1 {
+                job_id: recipe.job_id,
+                item_id: recipe.item_id,
+                level: recipe.level,
+                recipe_level: recipe.recipe_level,
+                progress: recipe.progress,
+                quality: recipe.quality,
+                durability: recipe.durability,
+                material_quality_factor: recipe.material_quality_factor,
+                ingredients: recipe.ingredients.map(|item| (item.item_id, item.amount)),
+                is_expert: recipe.is_expert,
+                required_craftsmanship: recipe.required_craftsmanship,
+                required_control: recipe.required_control,
+                required_quality: recipe.required_quality,
+                result_amount: recipe.result_amount,
+            },
+            hq_ingredients: setup.recipe_config.hq_ingredients,
+            craftsmanship: stats.craftsmanship,
+            control: stats.control,
+            cp: stats.cp,
+            level: stats.level,
+            manipulation: stats.manipulation,
+            food: setup.food.map(|item| (item.item_id, item.hq)),
+            potion: setup.potion.map(|item| (item.item_id, item.hq)),
+            quality_target: match setup.solver_config.quality_target {
+                QualityTarget::Zero => QualityTargetV2::Zero,
+                QualityTarget::CollectableT1 => QualityTargetV2::CollectableT1,
+                QualityTarget::CollectableT2 => QualityTargetV2::CollectableT2,
+                QualityTarget::CollectableT3 => QualityTargetV2::CollectableT3,
+                QualityTarget::Full => QualityTargetV2::Full,
+                QualityTarget::Custom(quality) => QualityTargetV2::Custom(quality),
+                QualityTarget::MaximizeHq => QualityTargetV2::MaximizeHq,
+                QualityTarget::Hq(hq) => QualityTargetV2::Hq(hq),
+            },
+            backload_progress: setup.solver_config.backload_progress,
+            adversarial: setup.solver_config.adversarial,
+            actions: setup.actions.as_ref().map(|actions| {
+                actions
+                    .iter()
+                    .map(|action| ACTIONS_V1.iter().position(|a| a == action).unwrap() as u8)
+                    .collect()
+            }),
+        }
+    }
+
+    fn into_setup(self) -> Result<SharedSetup, &'static str> {
+        let recipe = self.recipe;
+        let actions = match self.actions {
+            Some(actions) => Some(
+                actions
+                    .into_iter()
+                    .map(|index| ACTIONS_V1.get(index as usize).copied())
+                    .collect::<Option<Vec<Action>>>()
+                    .ok_or("Unknown action")?,
+            ),
+            None => None,
+        };
+        Ok(SharedSetup {
+            recipe_config: RecipeConfiguration {
+                recipe: Recipe {
+                    job_id: recipe.job_id,
+                    item_id: recipe.item_id,
+                    level: recipe.level,
+                    recipe_level: recipe.recipe_level,
+                    progress: recipe.progress,
+                    quality: recipe.quality,
+                    durability: recipe.durability,
+                    material_quality_factor: recipe.material_quality_factor,
+                    ingredients: recipe
+                        .ingredients
+                        .map(|(item_id, amount)| Ingredient { item_id, amount }),
+                    is_expert: recipe.is_expert,
+                    required_craftsmanship: recipe.required_craftsmanship,
+                    required_control: recipe.required_control,
+                    required_quality: recipe.required_quality,
+                    result_amount: recipe.result_amount,
+                },
+                hq_ingredients: self.hq_ingredients,
+            },
+            crafter_stats: CrafterStats {
+                craftsmanship: self.craftsmanship,
+                control: self.control,
+                cp: self.cp,
+                level: self.level,
+                manipulation: self.manipulation,
+            },
+            food: find_consumable(MEALS, self.food)?,
+            potion: find_consumable(POTIONS, self.potion)?,
+            solver_config: SolverConfig {
+                quality_target: match self.quality_target {
+                    QualityTargetV2::Zero => QualityTarget::Zero,
+                    QualityTargetV2::CollectableT1 => QualityTarget::CollectableT1,
+                    QualityTargetV2::CollectableT2 => QualityTarget::CollectableT2,
+                    QualityTargetV2::CollectableT3 => QualityTarget::CollectableT3,
+                    QualityTargetV2::Full => QualityTarget::Full,
+                    QualityTargetV2::Custom(quality) => QualityTarget::Custom(quality),
+                    QualityTargetV2::MaximizeHq => QualityTarget::MaximizeHq,
+                    QualityTargetV2::Hq(hq) => QualityTarget::Hq(hq),
+                },
+                backload_progress: self.backload_progress,
+                adversarial: self.adversarial,
+                // Links of versions 1 and 2 were created before there was a choice of game version
+                game_version: GameVersion::default(),
+            },
+            actions,
+        })
+    }
+}
+
+impl SharedSetupV3 {
+    fn new(setup: &SharedSetup) -> Self {
+        Self {
+            setup: SharedSetupV2::new(setup),
+            game_version: match setup.solver_config.game_version {
+                GameVersion::Endwalker => GameVersionV3::Endwalker,
+                GameVersion::Dawntrail => GameVersionV3::Dawntrail,
+            },
+        }
+    }
+
+    fn into_setup(self) -> Result<SharedSetup, &'static str> {
+        let mut setup = self.setup.into_setup()?;
+        setup.solver_config.game_version = match self.game_version {
+            GameVersionV3::Endwalker => GameVersion::Endwalker,
+            GameVersionV3::Dawntrail => GameVersion::Dawntrail,
+        };
+        Ok(setup)
+    }
+}
+
+/// Encodes the setup into a string that can be used as the fragment of a URL.
+pub fn encode_share_fragment(setup: &SharedSetup) -> String {
+    let mut bytes = vec![SHARE_FORMAT_VERSION];
+    bincode_options()
+        .serialize_into(&mut bytes, &SharedSetupV3::new(setup))
+        .unwrap();
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+/// Decodes a string created by `encode_share_fragment` of this or any earlier version.
+/// A leading '#' is ignored.
+pub fn decode_share_fragment(fragment: &str) -> Result<SharedSetup, &'static str> {
+    let fragment = fragment.strip_prefix('#').unwrap_or(fragment);
+    let bytes = URL_SAFE_NO_PAD
+        .decode(fragment)
+        .map_err(|_| "Invalid link")?;
+    match bytes.split_first() {
+        Some((1, payload)) => bincode_options()
+            .deserialize::<SharedSetupV1>(payload)
+            .map_err(|_| "Invalid link")?
+            .into_v2()
+            .into_setup(),
+        Some((2, payload)) => bincode_options()
+            .deserialize::<SharedSetupV2>(payload)
+            .map_err(|_| "Invalid link")?
+            .into_setup(),
+        Some((3, payload)) => bincode_options()
+            .deserialize::<SharedSetupV3>(payload)
+            .map_err(|_| "Invalid link")?
+            .into_setup(),
+        Some((version, _)) if *version > SHARE_FORMAT_VERSION => {
+            Err("Link was created by a newer version")
+        }
+        _ => Err("Invalid link"),
+    }
+}
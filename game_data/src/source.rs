@@ -0,0 +1,176 @@
+use simulator::{ActionMask, GameVersion, Settings};
+
+use crate::{
+    control_bonus, cp_bonus, craftsmanship_bonus, Consumable, CrafterStats, Item, Locale, Recipe,
+    RecipeConfiguration, RecipeLevel, GAME_DATA_VERSION, ITEMS, MEALS, POTIONS, RECIPES, RLVLS,
+};
+
+/// Recipes, items, and consumables, either compiled into the binary (`BuiltinGameData`) or loaded at runtime (`GameData`).
+pub trait GameDataSource {
+    /// Patch of the game that the data was extracted from, e.g. "7.0".
+    fn version(&self) -> &str;
+    fn recipes(&self) -> &[Recipe];
+    fn rlvl(&self, recipe_level: u16) -> Option<&RecipeLevel>;
+    fn item(&self, item_id: u32) -> Option<&Item>;
+    /// Returns `None` if the item has no name in the given locale.
+    fn item_name(&self, item_id: u32, locale: Locale) -> Option<&str>;
+    fn meals(&self) -> &[Consumable];
+    fn potions(&self) -> &[Consumable];
+
+    /// Rules of the game version that the data was extracted from, falling back to the latest version for unsupported patches.
+    fn game_version(&self) -> GameVersion {
+        GameVersion::from_patch(self.version()).unwrap_or_default()
+    }
+
+    fn get_item_name(&self, item_id: u32, hq: bool, locale: Locale) -> String {
+        let item_name = match locale {
+            Locale::EN | Locale::DE | Locale::FR | Locale::JP => self.item_name(item_id, locale),
+            // The KO and CN clients lag behind the global client, so newer items fall back to EN names
+            Locale::KO | Locale::CN => self
+                .item_name(item_id, locale)
+                .or(self.item_name(item_id, Locale::EN)),
+        }
+        .unwrap_or("Unknown item");
+        match hq {
+            true => format!("{} (HQ)", item_name),
+            false => item_name.to_string(),
+        }
+    }
+
+    /// Returns an error if the crafter (including food and potion bonuses) doesn't meet the stat requirements of the recipe.
+    fn get_game_settings(
+        &self,
+        recipe_config: RecipeConfiguration,
+        crafter_stats: CrafterStats,
+        food: Option<Consumable>,
+        potion: Option<Consumable>,
+        adversarial: bool,
+    ) -> Result<Settings, &'static str> {
+        self.get_game_settings_for_version(
+            recipe_config,
+            crafter_stats,
+            food,
+            potion,
+            adversarial,
+            self.game_version(),
+        )
+    }
+
+    /// Same as `get_game_settings`, but simulates with the rules of the given game version instead of the version of the data.
+    fn get_game_settings_for_version(
+        &self,
+        recipe_config: RecipeConfiguration,
+        crafter_stats: CrafterStats,
+        food: Option<Consumable>,
+        potion: Option<Consumable>,
+        adversarial: bool,
+        game_version: GameVersion,
+    ) -> Result<Settings, &'static str> {
+        let recipe = recipe_config.recipe;
+        let rlvl = self
+            .rlvl(recipe.recipe_level)
+            .ok_or("Unknown recipe level")?;
+
+        let craftsmanship = crafter_stats.craftsmanship
+            + craftsmanship_bonus(crafter_stats.craftsmanship, &[food, potion]);
+        let control = crafter_stats.control + control_bonus(crafter_stats.control, &[food, potion]);
+        let cp = crafter_stats.cp + cp_bonus(crafter_stats.cp, &[food, potion]);
+
+        if craftsmanship < recipe.required_craftsmanship {
+            return Err("Craftsmanship requirement not met");
+        }
+        if control < recipe.required_control {
+            return Err("Control requirement not met");
+        }
+
+        let mut base_progress = craftsmanship * 10 / rlvl.progress_div + 2;
+        let mut base_quality = control * 10 / rlvl.quality_div + 35;
+        if crafter_stats.level <= recipe.level {
+            base_progress = base_progress * rlvl.progress_mod / 100;
+            base_quality = base_quality * rlvl.quality_mod / 100;
+        }
+
+        let mut ingredients: Vec<(Item, u32)> = Vec::new();
+        for ingredient in recipe.ingredients.iter() {
+            if ingredient.item_id != 0 {
+                let item = self.item(ingredient.item_id).ok_or("Unknown ingredient")?;
+                ingredients.push((*item, ingredient.amount));
+            }
+        }
+        let initial_quality = {
+            let mut max_ilvl = 0;
+            let mut provided_ilvl = 0;
+            for (index, (item, max_amount)) in ingredients.into_iter().enumerate() {
+                if item.can_be_hq {
+                    max_ilvl += max_amount as u16 * item.item_level;
+                    provided_ilvl += recipe_config.hq_ingredients[index] as u16 * item.item_level;
+                }
+            }
+            if max_ilvl != 0 {
+                (recipe.quality as u64
+                    * recipe.material_quality_factor as u64
+                    * provided_ilvl as u64
+                    / max_ilvl as u64
+                    / 100) as u16
+            } else {
+                0
+            }
+        };
+
+        Ok(Settings {
+            max_cp: cp as _,
+            max_durability: recipe.durability as _,
+            max_progress: recipe.progress,
+            max_quality: recipe.quality,
+            base_progress,
+            base_quality,
+            initial_quality,
+            job_level: crafter_stats.level,
+            allowed_actions: ActionMask::from_level(
+                crafter_stats.level as _,
+                crafter_stats.manipulation,
+                !recipe.is_expert && crafter_stats.level >= recipe.level + 10, // Trained Eye condition
+            )
+            .intersection(game_version.action_mask(crafter_stats.level)),
+            adversarial,
+            game_version,
+        })
+    }
+}
+
+// `RLVLS` is a const, so it needs a static to hand out references to its elements
+static BUILTIN_RLVLS: [RecipeLevel; 800] = RLVLS;
+
+/// The game data that is generated by the build script and compiled into the binary.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BuiltinGameData;
+
+impl GameDataSource for BuiltinGameData {
+    fn version(&self) -> &str {
+        GAME_DATA_VERSION
+    }
+
+    fn recipes(&self) -> &[Recipe] {
+        RECIPES
+    }
+
+    fn rlvl(&self, recipe_level: u16) -> Option<&RecipeLevel> {
+        BUILTIN_RLVLS.get(recipe_level as usize)
+    }
+
+    fn item(&self, item_id: u32) -> Option<&Item> {
+        ITEMS.get(&item_id)
+    }
+
+    fn item_name(&self, item_id: u32, locale: Locale) -> Option<&str> {
+        crate::locales::builtin_item_name(item_id, locale)
+    }
+
+    fn meals(&self) -> &[Consumable] {
+        MEALS
+    }
+
+    fn potions(&self) -> &[Consumable] {
+        POTIONS
+    }
+}
@@ -0,0 +1,75 @@
+use game_data::{
+    get_collectable, Collectable, CollectableTier, QualityTarget, COLLECTABLES, ITEMS,
+};
+
+const fn tier(collectability: u16, scrips: u16) -> CollectableTier {
+    CollectableTier {
+        collectability,
+        scrips,
+    }
+}
+
+const COLLECTABLE: Collectable = Collectable {
+    currency: 2,
+    tiers: [tier(600, 54), tier(800, 72), tier(1000, 144)],
+};
+
+#[test]
+fn test_tier() {
+    assert_eq!(COLLECTABLE.tier(0), 0);
+    assert_eq!(COLLECTABLE.tier(5999), 0);
+    // collectability is a tenth of the quality, rounded down
+    assert_eq!(COLLECTABLE.tier(6000), 1);
+    assert_eq!(COLLECTABLE.tier(7999), 1);
+    assert_eq!(COLLECTABLE.tier(8000), 2);
+    assert_eq!(COLLECTABLE.tier(10000), 3);
+    assert_eq!(COLLECTABLE.tier(u16::MAX), 3);
+}
+
+#[test]
+fn test_fallback_targets() {
+    // item 0 never has collectability data
+    assert!(get_collectable(0).is_none());
+    assert_eq!(QualityTarget::CollectableT1.get_target(0, 10000), 5500);
+    assert_eq!(QualityTarget::CollectableT2.get_target(0, 10000), 7500);
+    assert_eq!(QualityTarget::CollectableT3.get_target(0, 10000), 9500);
+    assert_eq!(QualityTarget::Full.get_target(0, 10000), 10000);
+    assert!(QualityTarget::CollectableT1.collectable_tier(0).is_none());
+}
+
+#[test]
+fn test_collectable_targets() {
+    assert!(!COLLECTABLES.is_empty());
+    for (item_id, collectable) in COLLECTABLES.entries() {
+        let targets = [
+            QualityTarget::CollectableT1,
+            QualityTarget::CollectableT2,
+            QualityTarget::CollectableT3,
+        ];
+        for (target, tier) in targets.into_iter().zip(collectable.tiers) {
+            assert_eq!(target.collectable_tier(*item_id), Some(tier));
+            assert_eq!(target.get_target(*item_id, u16::MAX), tier.min_quality());
+            // the target never exceeds the max quality of the recipe
+            assert_eq!(
+                target.get_target(*item_id, 100),
+                tier.min_quality().min(100)
+            );
+            assert!(target.label(*item_id).contains(&tier.scrips.to_string()));
+        }
+        // non-collectable targets are not affected
+        assert_eq!(QualityTarget::Full.get_target(*item_id, 12345), 12345);
+    }
+}
+
+#[test]
+fn test_collectables_table() {
+    assert!(!COLLECTABLES.is_empty());
+    for (item_id, collectable) in COLLECTABLES.entries() {
+        if let Some(item) = ITEMS.get(item_id) {
+            assert!(item.is_collectable);
+        }
+        for tiers in collectable.tiers.windows(2) {
+            assert!(tiers[0].collectability <= tiers[1].collectability);
+        }
+    }
+}
@@ -0,0 +1,44 @@
+//! Runs the collectables importer of the build script on a small fixture with the layout of the game's data files.
+
+#[allow(dead_code)]
+#[path = "../build/utils.rs"]
+mod utils;
+
+#[allow(dead_code)]
+#[path = "../build/collectables.rs"]
+mod collectables;
+
+use std::path::Path;
+
+fn fixture_dir() -> &'static Path {
+    Path::new(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/data/collectables"
+    ))
+}
+
+#[test]
+fn test_import_collectables() {
+    let collectables = collectables::import_collectables(fixture_dir()).unwrap();
+    // items without thresholds or with unknown thresholds are skipped
+    assert_eq!(
+        collectables.keys().copied().collect::<Vec<_>>(),
+        [3001, 3002]
+    );
+
+    // the first shop of an item is used
+    let collectable = &collectables[&3001];
+    assert_eq!(collectable.currency, 2);
+    assert_eq!(collectable.collectability, [600, 800, 1000]);
+    assert_eq!(collectable.scrips, [54, 72, 144]);
+
+    let collectable = &collectables[&3002];
+    assert_eq!(collectable.currency, 4);
+    assert_eq!(collectable.collectability, [550, 750, 950]);
+    assert_eq!(collectable.scrips, [20, 30, 40]);
+}
+
+#[test]
+fn test_missing_data() {
+    assert!(collectables::import_collectables(Path::new("does/not/exist")).is_err());
+}
@@ -0,0 +1,103 @@
+use game_data::{
+    get_company_craft, get_game_settings, CompanyCraftPart, CompanyCraftPhase,
+    CompanyCraftSequence, CompanyCraftSupply, CrafterStats, RecipeConfiguration, COMPANY_CRAFTS,
+    RECIPES,
+};
+use simulator::{state::InProgress, SimulationState};
+use solvers::MacroSolver;
+
+// An item with recipes for two jobs and an item without any recipe
+const SUPPLIES: [CompanyCraftSupply; 2] = [
+    CompanyCraftSupply {
+        item_id: 5056,
+        set_quantity: 3,
+        sets_required: 2,
+    },
+    CompanyCraftSupply {
+        item_id: 5380,
+        set_quantity: 10,
+        sets_required: 1,
+    },
+];
+const PHASE: CompanyCraftPhase = CompanyCraftPhase {
+    supplies: &SUPPLIES,
+};
+// Two parts, the second one has a phase that only needs the item without any recipe
+const SEQUENCE: CompanyCraftSequence = CompanyCraftSequence {
+    item_id: 0,
+    parts: &[
+        CompanyCraftPart {
+            phases: &[PHASE, PHASE],
+        },
+        CompanyCraftPart {
+            phases: &[
+                CompanyCraftPhase {
+                    supplies: &[SUPPLIES[1]],
+                },
+                PHASE,
+            ],
+        },
+    ],
+};
+
+#[test]
+fn test_phase_recipes() {
+    assert_eq!(SUPPLIES[0].total_quantity(), 6);
+    let jobs = PHASE.jobs();
+    assert!(!jobs.is_empty());
+    for job_id in jobs {
+        let recipes = PHASE.recipes(job_id);
+        assert_eq!(recipes.len(), 1);
+        let (supply, recipe) = recipes[0];
+        assert_eq!(supply, SUPPLIES[0]);
+        assert_eq!(recipe.item_id, 5056);
+        assert_eq!(recipe.job_id, job_id);
+    }
+    assert!(RECIPES.iter().all(|recipe| recipe.item_id != 5380));
+}
+
+#[test]
+fn test_company_crafts() {
+    assert!(!COMPANY_CRAFTS.is_empty());
+    for sequence in COMPANY_CRAFTS {
+        assert!(!sequence.parts.is_empty());
+        assert!(sequence.parts.iter().all(|part| !part.phases.is_empty()));
+        assert_eq!(get_company_craft(sequence.item_id), Some(sequence));
+    }
+    assert_eq!(get_company_craft(5056), None);
+}
+
+#[test]
+fn test_solve_sequence() {
+    let crafter_stats = CrafterStats {
+        craftsmanship: 100,
+        control: 100,
+        cp: 200,
+        level: 10,
+        manipulation: false,
+    };
+    let mut solved_phases = 0;
+    for part in SEQUENCE.parts {
+        for phase in part.phases {
+            for job_id in phase.jobs() {
+                for (_, recipe) in phase.recipes(job_id) {
+                    let recipe_config = RecipeConfiguration {
+                        recipe,
+                        hq_ingredients: [0; 6],
+                    };
+                    let settings =
+                        get_game_settings(recipe_config, crafter_stats, None, None, false).unwrap();
+                    let actions = MacroSolver::new(settings, Box::new(|_| {}))
+                        .solve(InProgress::new(&settings), false)
+                        .unwrap();
+                    let state = SimulationState::from_macro(&settings, &actions).unwrap();
+                    assert_eq!(state.missing_progress, 0);
+                }
+            }
+            if !phase.jobs().is_empty() {
+                solved_phases += 1;
+            }
+        }
+    }
+    assert_eq!(solved_phases, 3);
+}
@@ -0,0 +1,65 @@
+//! Runs the company craft importer of the build script on a small fixture with the layout of the game's data files.
+
+#[allow(dead_code)]
+#[path = "../build/utils.rs"]
+mod utils;
+
+#[allow(dead_code)]
+#[path = "../build/company_crafts.rs"]
+mod company_crafts;
+
+use std::path::Path;
+
+fn fixture_dir() -> &'static Path {
+    Path::new(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/data/company_crafts"
+    ))
+}
+
+/// (item id, set quantity, sets required) of every supply item of every phase of every part.
+fn supplies(sequence: &company_crafts::CompanyCraftOutput) -> Vec<Vec<Vec<(u32, u32, u32)>>> {
+    sequence
+        .parts
+        .iter()
+        .map(|part| {
+            part.phases
+                .iter()
+                .map(|phase| {
+                    phase
+                        .iter()
+                        .map(|supply| (supply.item_id, supply.set_quantity, supply.sets_required))
+                        .collect()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn test_import_company_crafts() {
+    let sequences = company_crafts::import_company_crafts(fixture_dir()).unwrap();
+    // sequences without any phase are skipped
+    assert_eq!(
+        sequences
+            .iter()
+            .map(|sequence| sequence.item_id)
+            .collect::<Vec<_>>(),
+        [6001, 6003]
+    );
+    // supplies that aren't required or don't have an item are skipped
+    assert_eq!(
+        supplies(&sequences[0]),
+        [
+            vec![vec![(5056, 3, 2), (5380, 10, 1)], vec![(5380, 5, 3)]],
+            vec![vec![(5056, 1, 1)]],
+        ]
+    );
+    // unknown parts are skipped
+    assert_eq!(supplies(&sequences[1]), [vec![vec![(5056, 1, 1)]]]);
+}
+
+#[test]
+fn test_missing_data() {
+    assert!(company_crafts::import_company_crafts(Path::new("does/not/exist")).is_err());
+}
@@ -0,0 +1,150 @@
+use std::collections::HashSet;
+
+use game_data::{BuiltinGameData, CraftingTree, GameData, GameDataSource, Ingredient};
+
+// 2x Copper Ore, 1x Tin Ore
+const BRONZE_INGOT: u32 = 5056;
+const COPPER_ORE: u32 = 5106;
+const TIN_ORE: u32 = 5107;
+// 1x Bronze Ingot
+const BRONZE_RIVETS: u32 = 5091;
+// 3 items per craft, 2x ALC_MATERIAL
+const ALC_INTERMEDIATE: u32 = 4856;
+const ALC_MATERIAL: u32 = 4831;
+// 1x Bronze Ingot, 1x Bronze Rivets, 1x ALC_INTERMEDIATE
+const BRONZE_ITEM: u32 = 2381;
+// 1x Bronze Ingot, 1x ALC_INTERMEDIATE, ...
+const SHARED_INTERMEDIATE_PART: u32 = 2346;
+// 1x SHARED_INTERMEDIATE_PART, 1x ALC_INTERMEDIATE, ...
+const SHARED_INTERMEDIATE_ITEM: u32 = 2347;
+
+#[test]
+fn test_raw_material() {
+    let tree = CraftingTree::new(&BuiltinGameData, COPPER_ORE, 5);
+    assert!(!tree.is_craftable());
+    assert_eq!(tree.raw_materials(), [(COPPER_ORE, 5)]);
+    assert!(tree.solve_jobs(&HashSet::new()).is_empty());
+}
+
+#[test]
+fn test_tree() {
+    let tree = CraftingTree::new(&BuiltinGameData, BRONZE_ITEM, 1);
+    assert!(tree.is_craftable());
+    assert_eq!(tree.crafts, 1);
+    assert_eq!(
+        tree.ingredients
+            .iter()
+            .map(|ingredient| (ingredient.item_id, ingredient.amount, ingredient.crafts))
+            .collect::<Vec<_>>(),
+        [
+            (BRONZE_INGOT, 1, 1),
+            (BRONZE_RIVETS, 1, 1),
+            (ALC_INTERMEDIATE, 1, 1)
+        ]
+    );
+    assert_eq!(
+        tree.raw_materials(),
+        [(ALC_MATERIAL, 2), (COPPER_ORE, 4), (TIN_ORE, 2)]
+    );
+}
+
+#[test]
+fn test_result_amount() {
+    let tree = CraftingTree::new(&BuiltinGameData, BRONZE_ITEM, 4);
+    let intermediate = &tree.ingredients[2];
+    assert_eq!(intermediate.amount, 4);
+    assert_eq!(intermediate.crafts, 2);
+    assert_eq!(
+        tree.raw_materials(),
+        [(ALC_MATERIAL, 4), (COPPER_ORE, 16), (TIN_ORE, 8)]
+    );
+}
+
+#[test]
+fn test_solve_jobs() {
+    let tree = CraftingTree::new(&BuiltinGameData, BRONZE_ITEM, 1);
+    let hq_items = HashSet::from([BRONZE_INGOT, ALC_INTERMEDIATE]);
+    let jobs = tree.solve_jobs(&hq_items);
+    assert_eq!(
+        jobs.iter()
+            .map(|job| (job.recipe_config.recipe.item_id, job.crafts, job.hq))
+            .collect::<Vec<_>>(),
+        [
+            (BRONZE_INGOT, 2, true),
+            (BRONZE_RIVETS, 1, false),
+            (ALC_INTERMEDIATE, 1, true),
+            (BRONZE_ITEM, 1, false),
+        ]
+    );
+    assert_eq!(jobs[0].recipe_config.hq_ingredients, [0; 6]);
+    assert_eq!(jobs[1].recipe_config.hq_ingredients, [1, 0, 0, 0, 0, 0]);
+    assert_eq!(jobs[3].recipe_config.hq_ingredients, [1, 0, 1, 0, 0, 0]);
+}
+
+#[test]
+fn test_shared_intermediate() {
+    // ALC_INTERMEDIATE is needed once directly and once by SHARED_INTERMEDIATE_PART,
+    // and a single craft yields enough for both
+    let tree = CraftingTree::new(&BuiltinGameData, SHARED_INTERMEDIATE_ITEM, 1);
+    let jobs = tree.solve_jobs(&HashSet::new());
+    let intermediate_jobs: Vec<_> = jobs
+        .iter()
+        .filter(|job| job.recipe_config.recipe.item_id == ALC_INTERMEDIATE)
+        .map(|job| job.crafts)
+        .collect();
+    assert_eq!(intermediate_jobs, [1]);
+    assert!(tree.raw_materials().contains(&(ALC_MATERIAL, 2)));
+    assert_eq!(
+        jobs.last().unwrap().recipe_config.recipe.item_id,
+        SHARED_INTERMEDIATE_ITEM
+    );
+    assert!(jobs
+        .iter()
+        .any(|job| job.recipe_config.recipe.item_id == SHARED_INTERMEDIATE_PART));
+}
+
+#[test]
+fn test_hq_ingredients_use_recipe_slots() {
+    // the recipe has an empty ingredient slot before the Bronze Ingot
+    let mut recipe = *BuiltinGameData
+        .recipes()
+        .iter()
+        .find(|recipe| recipe.item_id == BRONZE_RIVETS)
+        .unwrap();
+    recipe.ingredients = [
+        Ingredient {
+            item_id: 0,
+            amount: 0,
+        },
+        Ingredient {
+            item_id: BRONZE_INGOT,
+            amount: 2,
+        },
+        Ingredient {
+            item_id: 0,
+            amount: 0,
+        },
+        Ingredient {
+            item_id: 0,
+            amount: 0,
+        },
+        Ingredient {
+            item_id: 0,
+            amount: 0,
+        },
+        Ingredient {
+            item_id: 0,
+            amount: 0,
+        },
+    ];
+    let game_data = GameData {
+        recipes: vec![recipe],
+        ..Default::default()
+    };
+    let tree = CraftingTree::new(&game_data, BRONZE_RIVETS, 3);
+    assert_eq!(tree.raw_materials(), [(BRONZE_INGOT, 6)]);
+    let jobs = tree.solve_jobs(&HashSet::from([BRONZE_INGOT]));
+    assert_eq!(jobs.len(), 1);
+    assert_eq!(jobs[0].crafts, 3);
+    assert_eq!(jobs[0].recipe_config.hq_ingredients, [0, 2, 0, 0, 0, 0]);
+}
@@ -0,0 +1,146 @@
+use game_data::{
+    feasibility_csv, feasibility_report, recipe_feasibility, Consumable, CrafterStats, Locale,
+    Recipe, RECIPES,
+};
+use simulator::GameVersion;
+
+const JOB_ID: u8 = 0;
+
+fn crafter_stats(craftsmanship: u16, control: u16, cp: u16) -> CrafterStats {
+    CrafterStats {
+        craftsmanship,
+        control,
+        cp,
+        level: 10,
+        manipulation: false,
+    }
+}
+
+fn food() -> Consumable {
+    Consumable {
+        item_id: 0,
+        item_level: 0,
+        hq: true,
+        craft_rel: 0,
+        craft_max: 0,
+        control_rel: 0,
+        control_max: 0,
+        cp_rel: 50,
+        cp_max: 40,
+    }
+}
+
+#[test]
+fn test_low_level_report() {
+    let report = feasibility_report(
+        JOB_ID,
+        crafter_stats(100, 100, 200),
+        None,
+        None,
+        GameVersion::Dawntrail,
+        false,
+    );
+    assert!(!report.is_empty());
+    for entry in report.iter() {
+        assert_eq!(entry.recipe.job_id, JOB_ID);
+        assert!(entry.recipe.level <= 10);
+        assert!(entry.feasibility.can_complete);
+        assert!(!entry.feasibility.exact);
+        assert!(entry.feasibility.quality <= entry.recipe.quality);
+        assert!(!entry.needs_consumables);
+    }
+
+    let csv = feasibility_csv(&report, Locale::EN);
+    assert_eq!(csv.lines().count(), report.len() + 1);
+    assert!(csv.starts_with("Item,Level,"));
+}
+
+#[test]
+fn test_full_solve() {
+    let stats = crafter_stats(20, 20, 0);
+    let bounds = feasibility_report(JOB_ID, stats, None, None, GameVersion::Dawntrail, false);
+    let solved = feasibility_report(JOB_ID, stats, None, None, GameVersion::Dawntrail, true);
+    for (bound, solved) in bounds.iter().zip(solved.iter()) {
+        assert_eq!(
+            bound.feasibility.can_complete,
+            solved.feasibility.can_complete
+        );
+        if solved.feasibility.can_complete {
+            assert!(solved.feasibility.exact);
+            assert!(solved.feasibility.quality <= bound.feasibility.quality);
+        }
+    }
+    // without CP for Master's Mend, a weak crafter cannot complete every recipe
+    assert!(solved.iter().any(|entry| !entry.feasibility.can_complete));
+    assert!(solved.iter().any(|entry| entry.feasibility.can_complete));
+}
+
+#[test]
+fn test_needs_consumables() {
+    // the food adds enough CP for one Master's Mend
+    let stats = crafter_stats(20, 20, 60);
+    let report = feasibility_report(
+        JOB_ID,
+        stats,
+        Some(food()),
+        None,
+        GameVersion::Dawntrail,
+        false,
+    );
+    let entry = |item_id: u32| {
+        report
+            .iter()
+            .find(|entry| entry.recipe.item_id == item_id)
+            .unwrap()
+    };
+    // max Quality is reached without the food
+    assert_eq!(entry(5361).feasibility.quality, 80);
+    assert!(!entry(5361).needs_consumables);
+    // max Quality is only reached with the food
+    assert_eq!(entry(3742).feasibility.quality, 100);
+    assert!(entry(3742).needs_consumables);
+    // can only be completed with the food
+    assert_eq!(entry(1892).feasibility.quality, 41);
+    assert!(entry(1892).needs_consumables);
+    // the food increases Quality, but max Quality isn't reached either way
+    assert_eq!(entry(2219).feasibility.quality, 82);
+    assert!(!entry(2219).needs_consumables);
+    // cannot be completed even with the food
+    assert!(!entry(1893).feasibility.can_complete);
+    assert!(!entry(1893).needs_consumables);
+}
+
+#[test]
+fn test_required_quality() {
+    let recipe = *RECIPES
+        .iter()
+        .find(|recipe| recipe.job_id == JOB_ID && recipe.item_id == 2219)
+        .unwrap();
+    assert_eq!(recipe.required_quality, 0);
+    let stats = crafter_stats(20, 20, 60);
+    let entry = recipe_feasibility(recipe, stats, None, None, GameVersion::Dawntrail, false);
+    assert_eq!(entry.feasibility.quality, 41);
+
+    // the Quality is capped at the required Quality
+    let recipe = Recipe {
+        required_quality: 30,
+        ..recipe
+    };
+    let entry = recipe_feasibility(recipe, stats, None, None, GameVersion::Dawntrail, false);
+    assert_eq!(entry.feasibility.quality, 30);
+    // the required Quality is only reached with the food, max Quality isn't reached either way
+    let recipe = Recipe {
+        required_quality: 50,
+        ..recipe
+    };
+    let entry = recipe_feasibility(
+        recipe,
+        stats,
+        Some(food()),
+        None,
+        GameVersion::Dawntrail,
+        false,
+    );
+    assert_eq!(entry.feasibility.quality, 50);
+    assert!(entry.needs_consumables);
+}
@@ -0,0 +1,137 @@
+use game_data::{
+    get_game_settings, get_game_settings_for_version, get_item_name, BuiltinGameData, CrafterStats,
+    GameData, GameDataSource, Locale, RecipeConfiguration, GAME_DATA_VERSION, MEALS, POTIONS,
+    RECIPES,
+};
+use simulator::{Action, GameVersion};
+
+#[test]
+fn test_version() {
+    assert_eq!(BuiltinGameData.version(), GAME_DATA_VERSION);
+    assert!(GAME_DATA_VERSION
+        .split('.')
+        .all(|part| part.parse::<u32>().is_ok()));
+}
+
+fn assert_same_data(game_data: &impl GameDataSource) {
+    assert_eq!(game_data.version(), GAME_DATA_VERSION);
+    assert_eq!(game_data.recipes().len(), RECIPES.len());
+    assert_eq!(game_data.meals().len(), MEALS.len());
+    assert_eq!(game_data.potions().len(), POTIONS.len());
+    for (a, b) in game_data.meals().iter().zip(MEALS.iter()) {
+        assert_eq!(format!("{:?}", a), format!("{:?}", b));
+    }
+    for (a, b) in game_data.potions().iter().zip(POTIONS.iter()) {
+        assert_eq!(format!("{:?}", a), format!("{:?}", b));
+    }
+
+    let crafter_stats = CrafterStats::default();
+    for (recipe, builtin_recipe) in game_data.recipes().iter().zip(RECIPES.iter()) {
+        assert_eq!(format!("{:?}", recipe), format!("{:?}", builtin_recipe));
+        let recipe_config = RecipeConfiguration {
+            recipe: *recipe,
+            hq_ingredients: [1; 6],
+        };
+        let settings = game_data.get_game_settings(
+            recipe_config,
+            crafter_stats,
+            MEALS.first().copied(),
+            POTIONS.first().copied(),
+            false,
+        );
+        let builtin_settings = get_game_settings(
+            recipe_config,
+            crafter_stats,
+            MEALS.first().copied(),
+            POTIONS.first().copied(),
+            false,
+        );
+        assert_eq!(settings, builtin_settings);
+        for locale in [
+            Locale::EN,
+            Locale::DE,
+            Locale::FR,
+            Locale::JP,
+            Locale::KO,
+            Locale::CN,
+        ] {
+            assert_eq!(
+                game_data.get_item_name(recipe.item_id, true, locale),
+                get_item_name(recipe.item_id, true, locale)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_builtin_copy() {
+    assert_same_data(&GameData::builtin());
+}
+
+#[test]
+fn test_bundle_round_trip() {
+    let bundle = GameData::builtin().to_bundle().unwrap();
+    let game_data = GameData::from_bundle(&bundle).unwrap();
+    assert_same_data(&game_data);
+}
+
+#[test]
+fn test_from_csv() {
+    let game_data = GameData::from_csv(concat!(env!("CARGO_MANIFEST_DIR"), "/data")).unwrap();
+    assert_same_data(&game_data);
+}
+
+#[test]
+fn test_missing_csv() {
+    assert!(GameData::from_csv("does/not/exist").is_err());
+}
+
+#[test]
+fn test_game_version() {
+    let mut game_data = GameData::builtin();
+    game_data.version = "6.58".to_string();
+    assert_eq!(game_data.game_version(), GameVersion::Endwalker);
+    let recipe_config = RecipeConfiguration {
+        recipe: RECIPES[0],
+        hq_ingredients: [0; 6],
+    };
+    let settings = game_data
+        .get_game_settings(recipe_config, CrafterStats::default(), None, None, false)
+        .unwrap();
+    assert_eq!(settings.game_version, GameVersion::Endwalker);
+    assert!(!settings.allowed_actions.has(Action::ComboRefinedTouch));
+    assert!(settings.allowed_actions.has(Action::ComboAdvancedTouch));
+
+    // the rules can be picked independently of the data
+    let crafter_stats = CrafterStats {
+        level: 100,
+        ..CrafterStats::default()
+    };
+    let settings = game_data
+        .get_game_settings_for_version(
+            recipe_config,
+            crafter_stats,
+            None,
+            None,
+            false,
+            GameVersion::Dawntrail,
+        )
+        .unwrap();
+    assert_eq!(settings.game_version, GameVersion::Dawntrail);
+    assert!(settings.allowed_actions.has(Action::ComboRefinedTouch));
+    let settings = get_game_settings_for_version(
+        recipe_config,
+        crafter_stats,
+        None,
+        None,
+        false,
+        GameVersion::Endwalker,
+    )
+    .unwrap();
+    assert_eq!(settings.game_version, GameVersion::Endwalker);
+    assert!(!settings.allowed_actions.has(Action::TrainedPerfection));
+
+    // unsupported patches fall back to the latest version
+    game_data.version = "5.55".to_string();
+    assert_eq!(game_data.game_version(), GameVersion::default());
+}
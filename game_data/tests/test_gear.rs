@@ -0,0 +1,134 @@
+use game_data::{GearItem, GearPiece, GearStats, Gearset, Materia, BASE_CP, GEAR, MATERIA};
+
+const fn stats(craftsmanship: u16, control: u16, cp: u16) -> GearStats {
+    GearStats {
+        craftsmanship,
+        control,
+        cp,
+    }
+}
+
+const GEAR_ITEM: GearItem = GearItem {
+    item_id: 1,
+    equip_slot: 3,
+    item_level: 710,
+    equip_level: 100,
+    jobs: 0b1111_1111,
+    stats: stats(200, 300, 0),
+    hq_stats: stats(220, 330, 0),
+    meld_caps: stats(300, 350, 20),
+    materia_slots: 2,
+    advanced_melding: true,
+};
+
+const CRAFTSMANSHIP_MATERIA: Materia = Materia {
+    item_id: 2,
+    grade: 12,
+    stats: stats(54, 0, 0),
+};
+
+const CONTROL_MATERIA: Materia = Materia {
+    item_id: 3,
+    grade: 12,
+    stats: stats(0, 54, 0),
+};
+
+const CP_MATERIA: Materia = Materia {
+    item_id: 4,
+    grade: 11,
+    stats: stats(0, 0, 9),
+};
+
+#[test]
+fn test_no_melds() {
+    assert_eq!(GEAR_ITEM.stats(false, &[]), Ok(stats(200, 300, 0)));
+    assert_eq!(GEAR_ITEM.stats(true, &[]), Ok(stats(220, 330, 0)));
+}
+
+#[test]
+fn test_meld_caps() {
+    let melds = [
+        CRAFTSMANSHIP_MATERIA,
+        CONTROL_MATERIA,
+        CP_MATERIA,
+        CP_MATERIA,
+    ];
+    assert_eq!(GEAR_ITEM.stats(false, &melds), Ok(stats(254, 350, 18)));
+    assert_eq!(GEAR_ITEM.stats(true, &melds), Ok(stats(274, 350, 18)));
+    let melds = [CP_MATERIA; 3];
+    assert_eq!(GEAR_ITEM.stats(false, &melds), Ok(stats(200, 300, 20)));
+}
+
+#[test]
+fn test_max_melds() {
+    assert_eq!(GEAR_ITEM.max_melds(), 5);
+    assert!(GEAR_ITEM.stats(false, &[CP_MATERIA; 6]).is_err());
+    let item = GearItem {
+        advanced_melding: false,
+        ..GEAR_ITEM
+    };
+    assert_eq!(item.max_melds(), 2);
+    assert!(item.stats(false, &[CP_MATERIA; 2]).is_ok());
+    assert!(item.stats(false, &[CP_MATERIA; 3]).is_err());
+}
+
+#[test]
+fn test_empty_gearset() {
+    let gearset = Gearset::default();
+    let crafter_stats = gearset.crafter_stats(0, 100, true).unwrap();
+    assert_eq!(crafter_stats.craftsmanship, 0);
+    assert_eq!(crafter_stats.control, 0);
+    assert_eq!(crafter_stats.cp, BASE_CP);
+    assert_eq!(crafter_stats.level, 100);
+    assert!(crafter_stats.manipulation);
+}
+
+#[test]
+fn test_unknown_items() {
+    let gearset = Gearset {
+        pieces: vec![GearPiece {
+            item_id: 0,
+            hq: false,
+            melds: Vec::new(),
+        }],
+    };
+    assert!(gearset.crafter_stats(0, 100, true).is_err());
+}
+
+#[test]
+fn test_gear_tables() {
+    for item in GEAR.iter() {
+        assert_ne!(item.jobs, 0);
+        assert!(item.hq_stats.craftsmanship >= item.stats.craftsmanship);
+        assert!(item.hq_stats.control >= item.stats.control);
+        assert!(item.hq_stats.cp >= item.stats.cp);
+    }
+    for materia in MATERIA.iter() {
+        assert_ne!(materia.stats, GearStats::default());
+    }
+}
+
+#[test]
+fn test_overmeld_grades() {
+    // grade XII materia can only be melded into the two regular slots
+    let melds = [CRAFTSMANSHIP_MATERIA, CONTROL_MATERIA, CP_MATERIA];
+    assert!(GEAR_ITEM.stats(false, &melds).is_ok());
+    let melds = [CRAFTSMANSHIP_MATERIA, CP_MATERIA, CONTROL_MATERIA];
+    assert!(GEAR_ITEM.stats(false, &melds).is_err());
+    assert!(!CRAFTSMANSHIP_MATERIA.can_be_overmelded());
+    assert!(CP_MATERIA.can_be_overmelded());
+    for grade in [1, 5, 7, 9, 11] {
+        assert!(Materia {
+            grade,
+            ..CP_MATERIA
+        }
+        .can_be_overmelded());
+    }
+    for grade in [6, 8, 10, 12] {
+        assert!(!Materia {
+            grade,
+            ..CP_MATERIA
+        }
+        .can_be_overmelded());
+    }
+}
@@ -0,0 +1,71 @@
+//! Runs the gear importer of the build script on a small fixture with the layout of the game's data files.
+
+#[allow(dead_code)]
+#[path = "../build/utils.rs"]
+mod utils;
+
+#[allow(dead_code)]
+#[path = "../build/gear.rs"]
+mod gear;
+
+use std::path::Path;
+
+fn fixture_dir() -> &'static Path {
+    Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/gear"))
+}
+
+#[test]
+fn test_import_gear() {
+    let gear = gear::import_gear(fixture_dir()).unwrap();
+    // the sword can't be equipped by crafters and the hat doesn't have any crafting stats
+    assert_eq!(
+        gear.iter().map(|item| item.item_id).collect::<Vec<_>>(),
+        [1001, 1002]
+    );
+
+    let saw = &gear[0];
+    assert_eq!(saw.equip_slot, 1);
+    assert_eq!(saw.item_level, 690);
+    assert_eq!(saw.equip_level, 100);
+    assert_eq!(saw.jobs, 0b0000_0001);
+    assert_eq!(saw.stats, [500, 400, 0]);
+    assert_eq!(saw.hq_stats, [550, 440, 0]);
+    assert_eq!(saw.meld_caps, [700, 560, 0]);
+    assert_eq!(saw.materia_slots, 2);
+    assert!(saw.advanced_melding);
+
+    let ring = &gear[1];
+    assert_eq!(ring.equip_slot, 12);
+    assert_eq!(ring.equip_level, 90);
+    assert_eq!(ring.jobs, 0b1111_1111);
+    assert_eq!(ring.stats, [100, 0, 8]);
+    assert_eq!(ring.hq_stats, [110, 0, 9]);
+    // meld caps are rounded to the nearest integer
+    assert_eq!(ring.meld_caps, [250, 200, 5]);
+    assert_eq!(ring.materia_slots, 1);
+    assert!(!ring.advanced_melding);
+}
+
+#[test]
+fn test_import_materia() {
+    let materia = gear::import_materia(fixture_dir()).unwrap();
+    // materia of other stats are skipped
+    assert_eq!(
+        materia
+            .iter()
+            .map(|materia| (materia.item_id, materia.grade, materia.stats))
+            .collect::<Vec<_>>(),
+        [
+            (2011, 1, [0, 0, 1]),
+            (2001, 1, [3, 0, 0]),
+            (2002, 2, [4, 0, 0]),
+            (2003, 1, [0, 5, 0]),
+        ]
+    );
+}
+
+#[test]
+fn test_missing_data() {
+    assert!(gear::import_gear(Path::new("does/not/exist")).is_err());
+    assert!(gear::import_materia(Path::new("does/not/exist")).is_err());
+}
@@ -1,7 +1,7 @@
 use game_data::{
     get_game_settings, get_item_name, CrafterStats, Locale, Recipe, RecipeConfiguration, RECIPES,
 };
-use simulator::{ActionMask, Settings};
+use simulator::{ActionMask, GameVersion, Settings};
 
 fn find_recipe(item_name: &'static str) -> Option<Recipe> {
     for recipe in RECIPES.iter() {
@@ -47,7 +47,7 @@ fn test_turali_pineapple_ponzecake() {
         level: 94,
         manipulation: true,
     };
-    let settings = get_game_settings(recipe_config, crafter_stats, None, None, false);
+    let settings = get_game_settings(recipe_config, crafter_stats, None, None, false).unwrap();
     assert_eq!(
         settings,
         Settings {
@@ -61,6 +61,7 @@ fn test_turali_pineapple_ponzecake() {
             job_level: 94,
             allowed_actions: ActionMask::from_level(94, true, false),
             adversarial: false,
+            game_version: GameVersion::Dawntrail,
         }
     )
 }
@@ -87,7 +88,7 @@ fn test_smaller_water_otter_hardware() {
         level: 100,
         manipulation: true,
     };
-    let settings = get_game_settings(recipe_config, crafter_stats, None, None, false);
+    let settings = get_game_settings(recipe_config, crafter_stats, None, None, false).unwrap();
     assert_eq!(
         settings,
         Settings {
@@ -102,8 +103,20 @@ fn test_smaller_water_otter_hardware() {
             // Trained Eye is not available for expert recipes
             allowed_actions: ActionMask::from_level(100, true, false),
             adversarial: false,
+            game_version: GameVersion::Dawntrail,
         }
-    )
+    );
+    assert_eq!(recipe.required_craftsmanship, 3800);
+    assert_eq!(recipe.required_control, 0);
+    assert_eq!(recipe.required_quality, 17000);
+    let crafter_stats = CrafterStats {
+        craftsmanship: 3799,
+        ..crafter_stats
+    };
+    assert_eq!(
+        get_game_settings(recipe_config, crafter_stats, None, None, false),
+        Err("Craftsmanship requirement not met")
+    );
 }
 
 #[test]
@@ -128,7 +141,7 @@ fn test_grade_8_tincture() {
         level: 100,
         manipulation: true,
     };
-    let settings = get_game_settings(recipe_config, crafter_stats, None, None, false);
+    let settings = get_game_settings(recipe_config, crafter_stats, None, None, false).unwrap();
     assert_eq!(
         settings,
         Settings {
@@ -143,6 +156,7 @@ fn test_grade_8_tincture() {
             // Trained Eye is available
             allowed_actions: ActionMask::from_level(100, true, true),
             adversarial: false,
+            game_version: GameVersion::Dawntrail,
         }
     )
 }
@@ -165,7 +179,7 @@ fn test_claro_walnut_spinning_wheel() {
         level: 99,
         manipulation: true,
     };
-    let settings = get_game_settings(recipe_config, crafter_stats, None, None, false);
+    let settings = get_game_settings(recipe_config, crafter_stats, None, None, false).unwrap();
     assert_eq!(
         settings,
         Settings {
@@ -179,6 +193,7 @@ fn test_claro_walnut_spinning_wheel() {
             job_level: 99,
             allowed_actions: ActionMask::from_level(99, true, false),
             adversarial: false,
+            game_version: GameVersion::Dawntrail,
         }
     )
 }
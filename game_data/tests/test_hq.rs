@@ -0,0 +1,105 @@
+use game_data::{hq_breakpoints, hq_percentage, next_hq_breakpoint, QualityTarget, RECIPES};
+use simulator::{ActionMask, GameVersion, Settings};
+
+#[test]
+fn test_hq_breakpoints() {
+    for max_quality in [1, 99, 100, 1234, 5500, 12000, 40000] {
+        let breakpoints = hq_breakpoints(max_quality);
+        assert_eq!(breakpoints.first(), Some(&(0, 1)));
+        assert_eq!(breakpoints.last(), Some(&(max_quality, 100)));
+        for window in breakpoints.windows(2) {
+            let ((quality, hq), (next_quality, next_hq)) = (window[0], window[1]);
+            assert!(quality < next_quality && hq < next_hq);
+            assert_eq!(hq_percentage(quality, max_quality), hq);
+            // the breakpoint is the lowest quality with that HQ%
+            assert_eq!(hq_percentage(next_quality - 1, max_quality), hq);
+            assert_eq!(hq_percentage(next_quality, max_quality), next_hq);
+        }
+    }
+    assert!(hq_breakpoints(0).is_empty());
+}
+
+#[test]
+fn test_next_hq_breakpoint() {
+    assert_eq!(next_hq_breakpoint(0, 10000), Some((500, 2)));
+    assert_eq!(next_hq_breakpoint(499, 10000), Some((1, 2)));
+    assert_eq!(next_hq_breakpoint(9900, 10000), Some((100, 100)));
+    assert_eq!(next_hq_breakpoint(10000, 10000), None);
+    assert_eq!(next_hq_breakpoint(0, 0), None);
+}
+
+#[test]
+fn test_hq_quality_targets() {
+    assert_eq!(QualityTarget::MaximizeHq.get_target(0, 10000), 10000);
+    assert_eq!(QualityTarget::Hq(2).get_target(0, 10000), 500);
+    // HQ% that don't exist round up to the next one
+    assert_eq!(QualityTarget::Hq(93).get_target(0, 10000), 9700);
+    assert_eq!(QualityTarget::Hq(100).get_target(0, 10000), 10000);
+    assert_eq!(QualityTarget::Hq(0).get_target(0, 10000), 0);
+}
+
+#[test]
+fn test_solver_targets() {
+    let targets = QualityTarget::MaximizeHq.solver_targets(10000).unwrap();
+    assert_eq!(targets.first(), Some(&0));
+    assert_eq!(targets.last(), Some(&10000));
+    assert!(QualityTarget::Full.solver_targets(10000).is_none());
+    assert!(QualityTarget::Hq(50).solver_targets(10000).is_none());
+}
+
+#[test]
+fn test_required_quality_targets() {
+    let recipe = game_data::Recipe {
+        quality: 10000,
+        required_quality: 4000,
+        ..RECIPES[0]
+    };
+    for quality_target in [
+        QualityTarget::Zero,
+        QualityTarget::Full,
+        QualityTarget::MaximizeHq,
+        QualityTarget::Hq(50),
+    ] {
+        assert_eq!(quality_target.recipe_target(&recipe, 10000), 4000);
+        assert!(quality_target
+            .recipe_solver_targets(&recipe, 10000)
+            .is_none());
+    }
+    let recipe = game_data::Recipe {
+        required_quality: 0,
+        ..recipe
+    };
+    assert_eq!(QualityTarget::Hq(2).recipe_target(&recipe, 10000), 500);
+    assert!(QualityTarget::MaximizeHq
+        .recipe_solver_targets(&recipe, 10000)
+        .is_some());
+}
+
+#[test]
+fn test_apply_quality_target() {
+    let recipe = game_data::Recipe {
+        quality: 10000,
+        required_quality: 4000,
+        ..RECIPES[0]
+    };
+    let mut settings = Settings {
+        max_cp: 500,
+        max_durability: 80,
+        max_progress: 2000,
+        max_quality: 10000,
+        base_progress: 100,
+        base_quality: 100,
+        initial_quality: 0,
+        job_level: 100,
+        allowed_actions: ActionMask::all(),
+        adversarial: false,
+        game_version: GameVersion::Dawntrail,
+    };
+    QualityTarget::Full.apply(&recipe, &mut settings);
+    assert_eq!(settings.max_quality, 4000);
+    // HQ ingredients can already exceed the target
+    settings.max_quality = 10000;
+    settings.initial_quality = 5000;
+    QualityTarget::Full.apply(&recipe, &mut settings);
+    assert_eq!(settings.max_quality, 5000);
+}
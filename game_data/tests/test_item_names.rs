@@ -1,4 +1,5 @@
-use game_data::{get_item_name, Locale};
+use game_data::{action_name, get_item_name, get_job_name, Locale};
+use simulator::Action;
 
 #[test]
 fn test_44232() {
@@ -20,3 +21,40 @@ fn test_44232() {
         "収集用のタコス・カルネ・アサーダ (HQ)"
     );
 }
+
+#[test]
+fn test_extra_locale_item_names() {
+    let item_id = 2; // Fire Shard
+    assert_eq!(get_item_name(item_id, false, Locale::EN), "Fire Shard");
+    assert_eq!(get_item_name(item_id, false, Locale::KO), "불의 샤드");
+    assert_eq!(get_item_name(item_id, true, Locale::CN), "火之碎晶 (HQ)");
+    assert_eq!(get_item_name(0, false, Locale::KO), "Unknown item");
+    assert_eq!(get_item_name(0, false, Locale::CN), "Unknown item");
+}
+
+#[test]
+fn test_extra_locale_job_names() {
+    assert_eq!(get_job_name(0, Locale::KO), "목수");
+    assert_eq!(get_job_name(7, Locale::KO), "요리");
+    assert_eq!(get_job_name(0, Locale::CN), "刻木");
+    assert_eq!(get_job_name(7, Locale::CN), "烹调");
+}
+
+#[test]
+fn test_extra_locale_action_names() {
+    assert_eq!(action_name(Action::Observe, Locale::KO), "경계");
+    assert_eq!(
+        action_name(Action::ByregotsBlessing, Locale::KO),
+        "비레고의 축복"
+    );
+    assert_eq!(
+        action_name(Action::ComboAdvancedTouch, Locale::KO),
+        "상급 가공"
+    );
+    assert_eq!(action_name(Action::Observe, Locale::CN), "观察");
+    assert_eq!(
+        action_name(Action::ByregotsBlessing, Locale::CN),
+        "比尔格的祝福"
+    );
+    assert_eq!(action_name(Action::Manipulation, Locale::CN), "掌握");
+}
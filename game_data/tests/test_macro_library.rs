@@ -0,0 +1,121 @@
+use game_data::{MacroLibrary, MacroMatch, SavedMacro};
+use simulator::{Action, ActionMask, GameVersion, Settings};
+
+const SETTINGS: Settings = Settings {
+    max_cp: 200,
+    max_durability: 40,
+    max_progress: 300,
+    max_quality: 1000,
+    base_progress: 100,
+    base_quality: 100,
+    initial_quality: 0,
+    job_level: 100,
+    allowed_actions: ActionMask::all(),
+    adversarial: false,
+    game_version: GameVersion::Dawntrail,
+};
+
+fn saved_macro(name: &str, actions: &[Action]) -> SavedMacro {
+    SavedMacro {
+        name: name.to_string(),
+        tags: vec!["Test".to_string()],
+        actions: actions.to_vec(),
+    }
+}
+
+fn library() -> MacroLibrary {
+    let mut library = MacroLibrary::default();
+    library.save(saved_macro(
+        "progress only",
+        &[Action::BasicSynthesis, Action::Groundwork],
+    ));
+    library.save(saved_macro(
+        "too short",
+        &[Action::BasicSynthesis, Action::BasicSynthesis],
+    ));
+    library.save(saved_macro(
+        "touch",
+        &[
+            Action::BasicTouch,
+            Action::BasicSynthesis,
+            Action::Groundwork,
+        ],
+    ));
+    library.save(saved_macro(
+        "slow touch",
+        &[
+            Action::BasicTouch,
+            Action::Observe,
+            Action::BasicSynthesis,
+            Action::Groundwork,
+        ],
+    ));
+    library.save(saved_macro(
+        "fails",
+        &[
+            Action::ByregotsBlessing,
+            Action::Groundwork,
+            Action::Groundwork,
+        ],
+    ));
+    library
+}
+
+#[test]
+fn test_find_working() {
+    let matches = library().find_working(&SETTINGS);
+    assert_eq!(
+        matches,
+        [
+            MacroMatch {
+                index: 2,
+                quality: 100,
+                hq: 3,
+                duration: 9,
+            },
+            MacroMatch {
+                index: 3,
+                quality: 100,
+                hq: 3,
+                duration: 12,
+            },
+            MacroMatch {
+                index: 0,
+                quality: 0,
+                hq: 1,
+                duration: 6,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_find_working_no_quality() {
+    let settings = Settings {
+        max_quality: 0,
+        ..SETTINGS
+    };
+    let library = library();
+    let matches = library.find_working(&settings);
+    // every working macro reaches the same (zero) Quality, so they are ordered by duration
+    let names: Vec<&str> = matches
+        .iter()
+        .map(|m| library.macros[m.index].name.as_str())
+        .collect();
+    assert_eq!(names, ["progress only", "touch", "slow touch"]);
+    assert!(matches.iter().all(|m| m.quality == 0 && m.hq == 0));
+}
+
+#[test]
+fn test_save_and_remove() {
+    let mut library = library();
+    library.save(saved_macro("touch", &[Action::BasicTouch]));
+    assert_eq!(library.macros.len(), 5);
+    assert_eq!(library.macros[2].actions, [Action::BasicTouch]);
+    assert!(library.macros[2].has_tag("test"));
+
+    let removed = library.remove("progress only").unwrap();
+    assert_eq!(removed.name, "progress only");
+    assert_eq!(library.macros.len(), 4);
+    assert!(library.remove("progress only").is_none());
+}
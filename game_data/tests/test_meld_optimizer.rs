@@ -0,0 +1,216 @@
+use game_data::{
+    get_game_settings_for_version, GearData, GearItem, GearPiece, GearStats, Gearset, Materia,
+    MeldObjective, MeldOptimizer, RecipeConfiguration, BASE_CP, RECIPES,
+};
+use simulator::{GameVersion, SimulationState};
+
+const fn stats(craftsmanship: u16, control: u16, cp: u16) -> GearStats {
+    GearStats {
+        craftsmanship,
+        control,
+        cp,
+    }
+}
+
+const GEAR: [GearItem; 1] = [GearItem {
+    item_id: 1,
+    equip_slot: 3,
+    item_level: 710,
+    equip_level: 100,
+    jobs: 0b1111_1111,
+    stats: stats(200, 300, 0),
+    hq_stats: stats(220, 330, 0),
+    meld_caps: stats(300, 400, 20),
+    materia_slots: 2,
+    advanced_melding: true,
+}];
+
+const MATERIA: [Materia; 3] = [
+    Materia {
+        item_id: 2,
+        grade: 12,
+        stats: stats(54, 0, 0),
+    },
+    Materia {
+        item_id: 3,
+        grade: 11,
+        stats: stats(0, 20, 0),
+    },
+    Materia {
+        item_id: 4,
+        grade: 11,
+        stats: stats(0, 0, 9),
+    },
+];
+
+const GEAR_DATA: GearData<'static> = GearData {
+    gear: &GEAR,
+    materia: &MATERIA,
+};
+
+fn optimizer(objective: MeldObjective) -> MeldOptimizer<'static> {
+    MeldOptimizer {
+        gear_data: GEAR_DATA,
+        recipe_config: RecipeConfiguration {
+            recipe: RECIPES[0],
+            hq_ingredients: [0; 6],
+        },
+        job_id: RECIPES[0].job_id,
+        level: 100,
+        manipulation: true,
+        food: None,
+        potion: None,
+        objective,
+        game_version: GameVersion::Dawntrail,
+    }
+}
+
+fn gearset(melds: Vec<u32>) -> Gearset {
+    Gearset {
+        pieces: vec![GearPiece {
+            item_id: 1,
+            hq: false,
+            melds,
+        }],
+    }
+}
+
+#[test]
+fn test_empty_gearset() {
+    let optimizer = optimizer(MeldObjective::MaximizeQuality);
+    let candidates = optimizer.candidates(&Gearset::default(), &[]).unwrap();
+    assert_eq!(candidates.len(), 1);
+    assert_eq!(candidates[0].crafter_stats.cp, BASE_CP);
+    assert_eq!(candidates[0].overmelds, 0);
+    // Trained Eye maxes out quality of low-level recipes, even without any gear
+    let solution = optimizer
+        .optimize(&Gearset::default(), &[])
+        .unwrap()
+        .unwrap();
+    assert_eq!(solution.quality, RECIPES[0].quality);
+}
+
+#[test]
+fn test_game_version() {
+    let optimizer = MeldOptimizer {
+        level: GameVersion::Endwalker.max_level(),
+        game_version: GameVersion::Endwalker,
+        ..optimizer(MeldObjective::MaximizeQuality)
+    };
+    let solution = optimizer
+        .optimize(&Gearset::default(), &[])
+        .unwrap()
+        .unwrap();
+    // the rotation is valid with the rules of the game version
+    let settings = get_game_settings_for_version(
+        optimizer.recipe_config,
+        solution.candidate.crafter_stats,
+        None,
+        None,
+        false,
+        GameVersion::Endwalker,
+    )
+    .unwrap();
+    let state = SimulationState::from_macro(&settings, &solution.actions).unwrap();
+    assert_eq!(state.missing_progress, 0);
+}
+
+#[test]
+fn test_unknown_items() {
+    let optimizer = optimizer(MeldObjective::ReachQuality(0));
+    assert!(optimizer
+        .candidates(&Gearset::default(), &[(0, 1)])
+        .is_err());
+    let gearset = Gearset {
+        pieces: vec![GearPiece {
+            item_id: 0,
+            hq: false,
+            melds: Vec::new(),
+        }],
+    };
+    assert!(optimizer.candidates(&gearset, &[]).is_err());
+}
+
+#[test]
+fn test_candidates_respect_inventory() {
+    let inventory = [(2, 1), (3, 1), (4, 1)];
+    let optimizer = optimizer(MeldObjective::MaximizeQuality);
+    let candidates = optimizer
+        .candidates(&gearset(Vec::new()), &inventory)
+        .unwrap();
+    assert!(!candidates.is_empty());
+    for candidate in candidates.iter() {
+        let melds = &candidate.gearset.pieces[0].melds;
+        assert!(melds.len() <= GEAR[0].max_melds() as usize);
+        for (index, meld) in melds.iter().enumerate() {
+            // every materia is only available once
+            assert!(!melds[..index].contains(meld));
+        }
+    }
+    // no candidate is strictly worse than another one
+    for a in candidates.iter() {
+        for b in candidates.iter() {
+            let (a_stats, b_stats) = (a.crafter_stats, b.crafter_stats);
+            assert!(
+                !(a_stats.craftsmanship > b_stats.craftsmanship
+                    && a_stats.control > b_stats.control
+                    && a_stats.cp > b_stats.cp
+                    && a.overmelds < b.overmelds)
+            );
+        }
+    }
+    // all materia melded, with the grade XII materia in a regular slot
+    let full = candidates
+        .iter()
+        .find(|candidate| candidate.gearset.pieces[0].melds.len() == 3)
+        .unwrap();
+    assert_eq!(full.gearset.pieces[0].melds[0], 2);
+    assert_eq!(full.overmelds, 1);
+    assert_eq!(full.crafter_stats.craftsmanship, 254);
+    assert_eq!(full.crafter_stats.control, 320);
+    assert_eq!(full.crafter_stats.cp, BASE_CP + 9);
+}
+
+#[test]
+fn test_overmeld_grades() {
+    // both regular slots are taken, so the grade XII materia can't be used anymore
+    let optimizer = optimizer(MeldObjective::MaximizeQuality);
+    let candidates = optimizer
+        .candidates(&gearset(vec![3, 4]), &[(2, 5)])
+        .unwrap();
+    assert_eq!(candidates.len(), 1);
+    assert_eq!(candidates[0].gearset.pieces[0].melds, [3, 4]);
+    assert_eq!(candidates[0].overmelds, 0);
+}
+
+#[test]
+fn test_large_frontier() {
+    let gearset = Gearset {
+        pieces: vec![
+            GearPiece {
+                item_id: 1,
+                hq: false,
+                melds: Vec::new(),
+            };
+            6
+        ],
+    };
+    let optimizer = optimizer(MeldObjective::MaximizeQuality);
+    let candidates = optimizer
+        .candidates(&gearset, &[(2, 30), (3, 30), (4, 30)])
+        .unwrap();
+    // the frontier is larger than any fixed cap, and candidates with low stat sums are kept as well
+    assert!(candidates.len() > 1024);
+    let max_control = candidates
+        .iter()
+        .map(|candidate| candidate.crafter_stats.control)
+        .max();
+    assert_eq!(max_control, Some(6 * 400));
+    // three CP materia reach the CP cap of each piece, leaving two slots for Control
+    let control_at_max_cp = candidates
+        .iter()
+        .filter(|candidate| candidate.crafter_stats.cp == BASE_CP + 6 * 20)
+        .map(|candidate| candidate.crafter_stats.control)
+        .max();
+    assert_eq!(control_at_max_cp, Some(6 * (300 + 2 * 20)));
+}
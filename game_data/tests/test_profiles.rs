@@ -0,0 +1,138 @@
+use game_data::{
+    Consumable, CrafterConfig, CrafterProfile, CrafterStats, GearPiece, Gearset,
+    LegacyCrafterConfig,
+};
+
+const FOOD: Consumable = Consumable {
+    item_id: 1,
+    item_level: 710,
+    hq: true,
+    craft_rel: 0,
+    craft_max: 0,
+    control_rel: 10,
+    control_max: 100,
+    cp_rel: 26,
+    cp_max: 80,
+};
+
+const POTION: Consumable = Consumable {
+    item_id: 2,
+    item_level: 700,
+    hq: true,
+    craft_rel: 0,
+    craft_max: 0,
+    control_rel: 0,
+    control_max: 0,
+    cp_rel: 6,
+    cp_max: 28,
+};
+
+fn crafter_stats(craftsmanship: u16) -> CrafterStats {
+    CrafterStats {
+        craftsmanship,
+        ..Default::default()
+    }
+}
+
+fn profile(name: &str, craftsmanship: u16) -> CrafterProfile {
+    CrafterProfile::new(name, crafter_stats(craftsmanship))
+}
+
+fn profile_names(config: &CrafterConfig, job_id: usize) -> Vec<&str> {
+    config.profiles[job_id]
+        .iter()
+        .map(|profile| profile.name.as_str())
+        .collect()
+}
+
+#[test]
+fn test_migrate() {
+    let legacy: LegacyCrafterConfig = serde_json::from_str(&format!(
+        r#"{{ "selected_job": 2, "crafter_stats": [{}] }}"#,
+        (0..8)
+            .map(|job_id| serde_json::to_string(&crafter_stats(1000 + job_id)).unwrap())
+            .collect::<Vec<_>>()
+            .join(", ")
+    ))
+    .unwrap();
+    let gearsets = std::array::from_fn(|job_id| Gearset {
+        pieces: vec![GearPiece {
+            item_id: job_id as u32,
+            hq: false,
+            melds: Vec::new(),
+        }],
+    });
+    let config = CrafterConfig::migrate(legacy, Some(FOOD), Some(POTION), gearsets);
+
+    assert_eq!(config.selected_job, 2);
+    assert_eq!(config.selected_profiles, [0; 8]);
+    for (job_id, profiles) in config.profiles.iter().enumerate() {
+        assert_eq!(profiles.len(), 1);
+        let profile = &profiles[0];
+        assert_eq!(profile.name, "Default");
+        assert_eq!(profile.crafter_stats, crafter_stats(1000 + job_id as u16));
+        assert_eq!(profile.gearset.pieces[0].item_id, job_id as u32);
+        // only the selected job had the consumables selected
+        assert_eq!(profile.food.is_some(), job_id == 2);
+        assert_eq!(profile.potion.is_some(), job_id == 2);
+    }
+    assert_eq!(config.profile().food.unwrap().item_id, FOOD.item_id);
+    assert_eq!(config.profile().potion.unwrap().item_id, POTION.item_id);
+}
+
+#[test]
+fn test_export_import_round_trip() {
+    let mut config = CrafterConfig::default();
+    config.profiles[0] = vec![profile("Default", 100), profile("Endgame", 200)];
+    config.profiles[7][0].food = Some(FOOD);
+    config.profiles[7][0].gearset.pieces.push(GearPiece {
+        item_id: 3,
+        hq: true,
+        melds: vec![4, 5],
+    });
+
+    let mut imported = CrafterConfig::default();
+    assert_eq!(imported.import_profiles(&config.export_profiles()), Ok(9));
+    assert_eq!(profile_names(&imported, 0), ["Default", "Endgame"]);
+    assert_eq!(imported.profiles[0][1].crafter_stats, crafter_stats(200));
+    assert_eq!(imported.profiles[7][0].food.unwrap().item_id, FOOD.item_id);
+    assert_eq!(
+        imported.profiles[7][0].gearset,
+        config.profiles[7][0].gearset
+    );
+}
+
+#[test]
+fn test_import_replaces_profiles_with_the_same_name() {
+    let mut exported = CrafterConfig::default();
+    exported.profiles[0] = vec![profile("Endgame", 300), profile("Leveling", 400)];
+    let json = exported.export_profiles();
+
+    let mut config = CrafterConfig::default();
+    config.profiles[0] = vec![profile("Default", 100), profile("Endgame", 200)];
+    config.import_profiles(&json).unwrap();
+    // existing profiles keep their position, new ones are appended
+    assert_eq!(
+        profile_names(&config, 0),
+        ["Default", "Endgame", "Leveling"]
+    );
+    assert_eq!(config.profiles[0][0].crafter_stats, crafter_stats(100));
+    assert_eq!(config.profiles[0][1].crafter_stats, crafter_stats(300));
+    assert_eq!(config.profiles[0][2].crafter_stats, crafter_stats(400));
+    // names are only compared within the same job
+    assert_eq!(profile_names(&config, 1), ["Default"]);
+    assert_eq!(config.profiles[1][0].crafter_stats, CrafterStats::default());
+}
+
+#[test]
+fn test_import_invalid_files() {
+    let mut config = CrafterConfig::default();
+    assert!(config.import_profiles("not json").is_err());
+    let json = CrafterConfig::default().export_profiles().replacen(
+        "\"version\": 1",
+        "\"version\": 1000",
+        1,
+    );
+    assert!(config.import_profiles(&json).is_err());
+    assert_eq!(profile_names(&config, 0), ["Default"]);
+}
@@ -0,0 +1,130 @@
+use game_data::{get_item_name, Locale, MatchRank, RecipeSearch, ITEMS, RECIPES};
+
+const TACOS_ITEM_ID: u32 = 44232;
+
+fn first_result_item_id(search: &RecipeSearch, locale: Locale) -> u32 {
+    RECIPES[*search.indices(locale).first().unwrap()].item_id
+}
+
+#[test]
+fn test_en() {
+    let search = RecipeSearch::new("Rarefied Tacos de Carne Asada");
+    assert_eq!(first_result_item_id(&search, Locale::EN), TACOS_ITEM_ID);
+    let search = RecipeSearch::new("rarefied asada");
+    assert_eq!(first_result_item_id(&search, Locale::EN), TACOS_ITEM_ID);
+}
+
+#[test]
+fn test_de() {
+    let search = RecipeSearch::new("Tacos de Carne Asada (Sammlerstück)");
+    assert_eq!(first_result_item_id(&search, Locale::DE), TACOS_ITEM_ID);
+    let search = RecipeSearch::new("tacos sammlerstück");
+    assert_eq!(first_result_item_id(&search, Locale::DE), TACOS_ITEM_ID);
+}
+
+#[test]
+fn test_fr() {
+    let search = RecipeSearch::new("Tacos de carne asada collectionnables");
+    assert_eq!(first_result_item_id(&search, Locale::FR), TACOS_ITEM_ID);
+    let search = RecipeSearch::new("tacos collectionnables");
+    assert_eq!(first_result_item_id(&search, Locale::FR), TACOS_ITEM_ID);
+}
+
+#[test]
+fn test_jp() {
+    let search = RecipeSearch::new("収集用のタコス・カルネ・アサーダ");
+    assert_eq!(first_result_item_id(&search, Locale::JP), TACOS_ITEM_ID);
+    let search = RecipeSearch::new("タコス");
+    assert!(search
+        .recipes(Locale::JP)
+        .iter()
+        .any(|recipe| recipe.item_id == TACOS_ITEM_ID));
+}
+
+#[test]
+fn test_match_rank() {
+    assert_eq!(
+        MatchRank::of("whipped cream", "whipped cream"),
+        Some(MatchRank::Exact)
+    );
+    assert_eq!(
+        MatchRank::of("whipped cream", "whipped"),
+        Some(MatchRank::Prefix)
+    );
+    assert_eq!(
+        MatchRank::of("whipped cream", "cream"),
+        Some(MatchRank::Substring)
+    );
+    assert_eq!(
+        MatchRank::of("whipped cream", "whi cre"),
+        Some(MatchRank::Words)
+    );
+    assert_eq!(MatchRank::of("whipped cream", "cre whi"), None);
+    assert!(MatchRank::Exact > MatchRank::Prefix);
+    assert!(MatchRank::Prefix > MatchRank::Substring);
+    assert!(MatchRank::Substring > MatchRank::Words);
+}
+
+#[test]
+fn test_empty_search_returns_everything() {
+    let search = RecipeSearch::default();
+    assert_eq!(
+        search.indices(Locale::EN),
+        (0..RECIPES.len()).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_filters() {
+    let search = RecipeSearch {
+        job_id: Some(7),
+        level: Some(90..=100),
+        is_expert: Some(false),
+        is_collectable: Some(true),
+        ..Default::default()
+    };
+    let recipes = search.recipes(Locale::EN);
+    assert!(recipes.iter().any(|recipe| recipe.item_id == TACOS_ITEM_ID));
+    assert!(recipes.iter().all(|recipe| recipe.job_id == 7
+        && (90..=100).contains(&recipe.level)
+        && !recipe.is_expert
+        && ITEMS.get(&recipe.item_id).unwrap().is_collectable));
+
+    let search = RecipeSearch {
+        text: String::from("rarefied tacos"),
+        job_id: Some(0),
+        ..Default::default()
+    };
+    assert!(search.indices(Locale::EN).is_empty());
+
+    let search = RecipeSearch {
+        text: String::from("rarefied tacos"),
+        recipe_level: Some(1..=100),
+        ..Default::default()
+    };
+    assert!(search.indices(Locale::EN).is_empty());
+}
+
+#[test]
+fn test_ingredient_filter() {
+    let search = RecipeSearch {
+        ingredient_text: String::from("turali pineapple"),
+        ..Default::default()
+    };
+    let recipes = search.recipes(Locale::EN);
+    assert!(recipes
+        .iter()
+        .any(|recipe| get_item_name(recipe.item_id, false, Locale::EN)
+            == "Turali Pineapple Ponzecake"));
+    assert!(recipes
+        .iter()
+        .all(
+            |recipe| recipe.ingredients.iter().any(|ingredient| get_item_name(
+                ingredient.item_id,
+                false,
+                Locale::EN
+            )
+            .to_lowercase()
+            .contains("turali pineapple"))
+        ));
+}
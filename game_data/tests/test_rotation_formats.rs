@@ -0,0 +1,202 @@
+use game_data::{action_id, export_rotation, import_rotation, RotationFormat, UnknownAction};
+use simulator::{Action, GameVersion};
+
+const FORMATS: [RotationFormat; 2] = [RotationFormat::Teamcraft, RotationFormat::CraftOpt];
+
+const ROTATION: [Action; 10] = [
+    Action::MuscleMemory,
+    Action::Manipulation,
+    Action::Veneration,
+    Action::WasteNot2,
+    Action::Groundwork,
+    Action::Innovation,
+    Action::BasicTouch,
+    Action::ComboStandardTouch,
+    Action::ComboAdvancedTouch,
+    Action::ByregotsBlessing,
+];
+
+#[test]
+fn test_teamcraft_export() {
+    let json = export_rotation(&ROTATION[..4], RotationFormat::Teamcraft);
+    assert_eq!(
+        json,
+        r#"["MuscleMemory","Manipulation","Veneration","WasteNotII"]"#
+    );
+}
+
+#[test]
+fn test_craft_opt_export() {
+    let json = export_rotation(&ROTATION[..4], RotationFormat::CraftOpt);
+    assert_eq!(
+        json,
+        r#"["muscleMemory","manipulation","veneration","wasteNot2"]"#
+    );
+}
+
+#[test]
+fn test_round_trip() {
+    for format in FORMATS {
+        let json = export_rotation(&ROTATION, format);
+        let rotation = import_rotation(&json, format, GameVersion::Dawntrail).unwrap();
+        assert_eq!(rotation.actions, ROTATION);
+        assert!(rotation.unknown_actions.is_empty());
+    }
+}
+
+#[test]
+fn test_combo_actions() {
+    // combo versions are only used directly after the action that starts the combo
+    let json =
+        r#"["StandardTouch", "BasicTouch", "StandardTouch", "AdvancedTouch", "AdvancedTouch"]"#;
+    let rotation =
+        import_rotation(json, RotationFormat::Teamcraft, GameVersion::Dawntrail).unwrap();
+    assert_eq!(
+        rotation.actions,
+        [
+            Action::StandardTouch,
+            Action::BasicTouch,
+            Action::ComboStandardTouch,
+            Action::ComboAdvancedTouch,
+            Action::AdvancedTouch,
+        ]
+    );
+}
+
+#[test]
+fn test_observe_combo() {
+    let json = r#"["Observe", "AdvancedTouch", "BasicTouch", "Observe", "AdvancedTouch"]"#;
+    let rotation =
+        import_rotation(json, RotationFormat::Teamcraft, GameVersion::Dawntrail).unwrap();
+    assert_eq!(
+        rotation.actions,
+        [
+            Action::Observe,
+            Action::ComboAdvancedTouch,
+            Action::BasicTouch,
+            Action::Observe,
+            Action::ComboAdvancedTouch,
+        ]
+    );
+    // Observe only started the combo after Endwalker
+    let rotation =
+        import_rotation(json, RotationFormat::Teamcraft, GameVersion::Endwalker).unwrap();
+    assert_eq!(
+        rotation.actions,
+        [
+            Action::Observe,
+            Action::AdvancedTouch,
+            Action::BasicTouch,
+            Action::Observe,
+            Action::AdvancedTouch,
+        ]
+    );
+}
+
+#[test]
+fn test_unknown_action_breaks_combo() {
+    let json = r#"["BasicTouch", "HastyTouch", "StandardTouch"]"#;
+    let rotation =
+        import_rotation(json, RotationFormat::Teamcraft, GameVersion::Dawntrail).unwrap();
+    assert_eq!(
+        rotation.actions,
+        [Action::BasicTouch, Action::StandardTouch]
+    );
+    assert_eq!(rotation.unknown_actions.len(), 1);
+}
+
+#[test]
+fn test_unknown_actions() {
+    let json = r#"["MuscleMemory", "HastyTouch", "BasicSynthesis", "muscleMemory"]"#;
+    let rotation =
+        import_rotation(json, RotationFormat::Teamcraft, GameVersion::Dawntrail).unwrap();
+    assert_eq!(
+        rotation.actions,
+        [Action::MuscleMemory, Action::BasicSynthesis]
+    );
+    assert_eq!(
+        rotation.unknown_actions,
+        [
+            UnknownAction {
+                index: 1,
+                id: "HastyTouch".to_string()
+            },
+            UnknownAction {
+                index: 3,
+                id: "muscleMemory".to_string()
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_craft_opt_upgraded_actions() {
+    let json = r#"["basicSynth2", "carefulSynthesis2", "groundwork2"]"#;
+    let rotation = import_rotation(json, RotationFormat::CraftOpt, GameVersion::Dawntrail).unwrap();
+    assert_eq!(
+        rotation.actions,
+        [
+            Action::BasicSynthesis,
+            Action::CarefulSynthesis,
+            Action::Groundwork
+        ]
+    );
+}
+
+#[test]
+fn test_teamcraft_rotation_object() {
+    let json = r#"{"name": "Example", "rotation": ["Reflect", "Innovation"]}"#;
+    let rotation =
+        import_rotation(json, RotationFormat::Teamcraft, GameVersion::Dawntrail).unwrap();
+    assert_eq!(rotation.actions, [Action::Reflect, Action::Innovation]);
+}
+
+#[test]
+fn test_invalid_json() {
+    for format in FORMATS {
+        assert!(import_rotation("", format, GameVersion::Dawntrail).is_err());
+        assert!(import_rotation("{}", format, GameVersion::Dawntrail).is_err());
+        assert!(import_rotation("[1, 2]", format, GameVersion::Dawntrail).is_err());
+    }
+}
+
+#[test]
+fn test_unique_ids() {
+    let actions = [
+        Action::BasicSynthesis,
+        Action::BasicTouch,
+        Action::MasterMend,
+        Action::Observe,
+        Action::WasteNot,
+        Action::Veneration,
+        Action::StandardTouch,
+        Action::GreatStrides,
+        Action::Innovation,
+        Action::WasteNot2,
+        Action::ByregotsBlessing,
+        Action::PreciseTouch,
+        Action::MuscleMemory,
+        Action::CarefulSynthesis,
+        Action::Manipulation,
+        Action::PrudentTouch,
+        Action::AdvancedTouch,
+        Action::Reflect,
+        Action::PreparatoryTouch,
+        Action::Groundwork,
+        Action::DelicateSynthesis,
+        Action::IntensiveSynthesis,
+        Action::PrudentSynthesis,
+        Action::TrainedFinesse,
+        Action::ComboRefinedTouch,
+        Action::ImmaculateMend,
+        Action::TrainedPerfection,
+        Action::TrainedEye,
+    ];
+    for format in FORMATS {
+        for (index, a) in actions.iter().enumerate() {
+            for b in actions[..index].iter() {
+                assert_ne!(action_id(*a, format), action_id(*b, format));
+            }
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use game_data::{
+    bottleneck_stat, get_game_settings, stat_sensitivity, CrafterStat, CrafterStats,
+    RecipeConfiguration, RECIPES,
+};
+use simulator::GameVersion;
+
+fn recipe_config() -> RecipeConfiguration {
+    RecipeConfiguration {
+        recipe: RECIPES[0],
+        hq_ingredients: [0; 6],
+    }
+}
+
+fn crafter_stats(craftsmanship: u16, control: u16, cp: u16) -> CrafterStats {
+    CrafterStats {
+        craftsmanship,
+        control,
+        cp,
+        level: 10,
+        manipulation: false,
+    }
+}
+
+#[test]
+fn test_breakpoints() {
+    let stats = crafter_stats(30, 30, 180);
+    let sensitivities = stat_sensitivity(
+        recipe_config(),
+        stats,
+        None,
+        None,
+        GameVersion::Dawntrail,
+        50,
+    )
+    .unwrap();
+    assert_eq!(sensitivities.len(), 3);
+    for sensitivity in sensitivities.iter() {
+        let mut previous = get_game_settings(recipe_config(), stats, None, None, false).unwrap();
+        let mut previous_points = 0;
+        for breakpoint in sensitivity.breakpoints.iter() {
+            assert!(breakpoint.points > previous_points && breakpoint.points <= 50);
+            assert!(
+                breakpoint.base_progress != previous.base_progress
+                    || breakpoint.base_quality != previous.base_quality
+            );
+            let mut stats = stats;
+            match sensitivity.stat {
+                CrafterStat::Craftsmanship => stats.craftsmanship += breakpoint.points,
+                CrafterStat::Control => stats.control += breakpoint.points,
+                CrafterStat::Cp => panic!("CP has no breakpoints"),
+            }
+            previous = get_game_settings(recipe_config(), stats, None, None, false).unwrap();
+            previous_points = breakpoint.points;
+        }
+    }
+    let craftsmanship = &sensitivities[0];
+    assert!(!craftsmanship.breakpoints.is_empty());
+    assert!(craftsmanship
+        .breakpoints
+        .windows(2)
+        .all(|pair| pair[0].base_progress < pair[1].base_progress));
+}
+
+#[test]
+fn test_max_quality_cannot_improve() {
+    let sensitivities = stat_sensitivity(
+        recipe_config(),
+        crafter_stats(200, 200, 400),
+        None,
+        None,
+        GameVersion::Dawntrail,
+        20,
+    )
+    .unwrap();
+    assert!(sensitivities
+        .iter()
+        .all(|sensitivity| sensitivity.improvement.is_none()));
+    assert_eq!(bottleneck_stat(&sensitivities), None);
+}
+
+#[test]
+fn test_improvement() {
+    let sensitivities = stat_sensitivity(
+        recipe_config(),
+        crafter_stats(30, 10, 60),
+        None,
+        None,
+        GameVersion::Dawntrail,
+        30,
+    )
+    .unwrap();
+    let control = &sensitivities[1];
+    assert_eq!(control.stat, CrafterStat::Control);
+    let (points, _) = control.improvement.unwrap();
+    // the first Control breakpoint already improves Quality
+    assert_eq!(points, control.breakpoints[0].points);
+    assert!(bottleneck_stat(&sensitivities).is_some());
+}
@@ -0,0 +1,173 @@
+use game_data::{
+    decode_share_fragment, encode_share_fragment, CrafterStats, Ingredient, QualityTarget, Recipe,
+    RecipeConfiguration, SharedSetup, SolverConfig, MEALS, POTIONS, RECIPES,
+};
+use simulator::{Action, GameVersion};
+
+const CUSTOM_RECIPE: Recipe = Recipe {
+    job_id: 3,
+    item_id: 0,
+    level: 100,
+    recipe_level: 690,
+    progress: 6600,
+    quality: 12000,
+    durability: 80,
+    material_quality_factor: 0,
+    ingredients: [Ingredient {
+        item_id: 0,
+        amount: 0,
+    }; 6],
+    is_expert: false,
+    required_craftsmanship: 0,
+    required_control: 0,
+    required_quality: 0,
+    result_amount: 1,
+};
+
+fn custom_setup() -> SharedSetup {
+    SharedSetup {
+        recipe_config: RecipeConfiguration {
+            recipe: CUSTOM_RECIPE,
+            hq_ingredients: [0; 6],
+        },
+        crafter_stats: CrafterStats {
+            craftsmanship: 4900,
+            control: 4800,
+            cp: 620,
+            level: 100,
+            manipulation: true,
+        },
+        food: None,
+        potion: None,
+        solver_config: SolverConfig {
+            quality_target: QualityTarget::Custom(10000),
+            backload_progress: true,
+            adversarial: false,
+            game_version: GameVersion::Dawntrail,
+        },
+        actions: Some(vec![
+            Action::MuscleMemory,
+            Action::Manipulation,
+            Action::Veneration,
+            Action::WasteNot,
+            Action::Groundwork,
+            Action::Innovation,
+            Action::PreparatoryTouch,
+            Action::ByregotsBlessing,
+            Action::BasicSynthesis,
+        ]),
+    }
+}
+
+fn assert_same_setup(a: &SharedSetup, b: &SharedSetup) {
+    assert_eq!(format!("{:?}", a), format!("{:?}", b));
+}
+
+#[test]
+fn test_round_trip() {
+    let setup = custom_setup();
+    let fragment = encode_share_fragment(&setup);
+    assert_same_setup(&decode_share_fragment(&fragment).unwrap(), &setup);
+    // the fragment is usually read from the URL including the '#'
+    assert_same_setup(
+        &decode_share_fragment(&format!("#{fragment}")).unwrap(),
+        &setup,
+    );
+}
+
+#[test]
+fn test_round_trip_without_actions() {
+    let setup = SharedSetup {
+        actions: None,
+        ..custom_setup()
+    };
+    let decoded = decode_share_fragment(&encode_share_fragment(&setup)).unwrap();
+    assert!(decoded.actions.is_none());
+    assert_same_setup(&decoded, &setup);
+}
+
+#[test]
+fn test_round_trip_builtin_data() {
+    for recipe in RECIPES.iter().take(10) {
+        let setup = SharedSetup {
+            recipe_config: RecipeConfiguration {
+                recipe: *recipe,
+                hq_ingredients: [1, 0, 0, 0, 0, 0],
+            },
+            food: MEALS.last().copied(),
+            potion: POTIONS.first().copied(),
+            solver_config: SolverConfig::default(),
+            ..custom_setup()
+        };
+        let decoded = decode_share_fragment(&encode_share_fragment(&setup)).unwrap();
+        assert_same_setup(&decoded, &setup);
+    }
+}
+
+#[test]
+fn test_fragment_is_url_safe() {
+    let fragment = encode_share_fragment(&custom_setup());
+    assert!(fragment
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+}
+
+#[test]
+fn test_version_1_links() {
+    // links created by older versions must keep working
+    let fragment = "AQMAZPuyAvvIGfvgLlAAAAAAAAAAAAAAAAAAAAAAAAEAAAAAAAD7JBP7wBL7bAJkAQAABfsQJwEAAQkNDwUEFQkUCwA";
+    let decoded = decode_share_fragment(fragment).unwrap();
+    assert_eq!(decoded.solver_config.game_version, GameVersion::default());
+    assert_same_setup(&decoded, &custom_setup());
+}
+
+#[test]
+fn test_version_2_links() {
+    // version 2 links don't record the game version and are decoded with the default one
+    let fragment = "AgMAZPuyAvvIGfvgLlAAAAAAAAAAAAAAAAAAAAAAAAEAAAAAAAD7JBP7wBL7bAJkAQAABfsQJwEAAQkNDwUEFQkUCwA";
+    let decoded = decode_share_fragment(fragment).unwrap();
+    assert_eq!(decoded.solver_config.game_version, GameVersion::default());
+    assert_same_setup(&decoded, &custom_setup());
+}
+
+#[test]
+fn test_round_trip_game_version() {
+    for game_version in [GameVersion::Endwalker, GameVersion::Dawntrail] {
+        let setup = SharedSetup {
+            solver_config: SolverConfig {
+                game_version,
+                ..custom_setup().solver_config
+            },
+            ..custom_setup()
+        };
+        let decoded = decode_share_fragment(&encode_share_fragment(&setup)).unwrap();
+        assert_eq!(decoded.solver_config.game_version, game_version);
+        assert_same_setup(&decoded, &setup);
+    }
+}
+
+#[test]
+fn test_round_trip_hq_targets() {
+    for quality_target in [QualityTarget::MaximizeHq, QualityTarget::Hq(75)] {
+        let setup = SharedSetup {
+            solver_config: SolverConfig {
+                quality_target,
+                ..custom_setup().solver_config
+            },
+            ..custom_setup()
+        };
+        let decoded = decode_share_fragment(&encode_share_fragment(&setup)).unwrap();
+        assert_same_setup(&decoded, &setup);
+    }
+}
+
+#[test]
+fn test_invalid_fragments() {
+    assert!(decode_share_fragment("").is_err());
+    assert!(decode_share_fragment("not a link").is_err());
+    // unknown version
+    assert!(decode_share_fragment("_w").is_err());
+    // trailing bytes
+    let fragment = encode_share_fragment(&custom_setup());
+    assert!(decode_share_fragment(&format!("{fragment}AA")).is_err());
+}
@@ -6,4 +6,5 @@ export RANDOM_SUFFIX=""
 # export RUSTFLAGS="--cfg=web_sys_unstable_apis"
 export BASE_URL="http://localhost:8080"
 
+./fetch-fonts.sh
 trunk serve index.html --dist distrib
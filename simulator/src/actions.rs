@@ -1,6 +1,6 @@
 use crate::{Condition, Effects, SingleUse};
 
-use super::Settings;
+use super::{GameVersion, Settings};
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum Action {
@@ -243,14 +243,17 @@ impl Action {
         (settings.base_progress as u64 * efficiency_mod * effect_mod / 10000) as u16
     }
 
-    pub const fn quality_efficiency(self, inner_quiet: u8) -> u64 {
+    pub const fn quality_efficiency(self, inner_quiet: u8, game_version: GameVersion) -> u64 {
         match self {
             Action::BasicTouch => 100,
             Action::StandardTouch => 125,
             Action::ComboStandardTouch => 125,
             Action::PreciseTouch => 150,
             Action::PrudentTouch => 100,
-            Action::Reflect => 300,
+            Action::Reflect => match game_version {
+                GameVersion::Endwalker => 100,
+                GameVersion::Dawntrail => 300,
+            },
             Action::PreparatoryTouch => 200,
             Action::DelicateSynthesis => 100,
             Action::AdvancedTouch => 150,
@@ -271,7 +274,7 @@ impl Action {
         if matches!(self, Action::TrainedEye) {
             return settings.max_quality;
         }
-        let efficieny_mod = self.quality_efficiency(effects.inner_quiet());
+        let efficieny_mod = self.quality_efficiency(effects.inner_quiet(), settings.game_version);
         let condition_mod = match condition {
             Condition::Good => 150,
             Condition::Excellent => 400,
@@ -294,6 +297,16 @@ impl Action {
             / 100000000) as u16
     }
 
+    pub const fn inner_quiet_bonus(self) -> u8 {
+        match self {
+            Action::Reflect => 2,
+            Action::PreciseTouch => 2,
+            Action::PreparatoryTouch => 2,
+            Action::ComboRefinedTouch => 2,
+            _ => 1,
+        }
+    }
+
     pub const fn combo_fulfilled(self, combo: Option<ComboAction>) -> bool {
         match self {
             Action::Reflect | Action::MuscleMemory | Action::TrainedEye => {
@@ -308,12 +321,15 @@ impl Action {
         }
     }
 
-    pub const fn to_combo(self) -> Option<ComboAction> {
+    pub const fn to_combo(self, game_version: GameVersion) -> Option<ComboAction> {
         match self {
             Action::BasicTouch => Some(ComboAction::BasicTouch),
             Action::ComboStandardTouch => Some(ComboAction::StandardTouch),
             // Observe and StandardTouch unlock the same action (ComboAdvancedTouch)
-            Action::Observe => Some(ComboAction::StandardTouch),
+            Action::Observe => match game_version {
+                GameVersion::Endwalker => None,
+                GameVersion::Dawntrail => Some(ComboAction::StandardTouch),
+            },
             _ => None,
         }
     }
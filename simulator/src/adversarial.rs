@@ -0,0 +1,197 @@
+use crate::{state::InProgress, Action, ComboAction, Condition, Settings, SimulationState};
+
+/// Highest Poor budget supported by `AdversarialModel::max_poor`.
+pub const MAX_POOR_BUDGET: u8 = 4;
+
+// Excellent is always followed by Poor, which is always followed by Normal.
+const PHASES: usize = 3;
+const NODES: usize = PHASES * (MAX_POOR_BUDGET as usize + 1);
+
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+pub enum ConditionSet {
+    /// Poor only occurs directly after Excellent, i.e. the Excellent > Poor > Normal chain of the game.
+    /// This is the model of `Settings::adversarial`.
+    #[default]
+    ExcellentPoor,
+    /// Every step except the first one can be Poor, and no step is Excellent.
+    AnyPoor,
+}
+
+/// Describes the condition sequences that a rotation is guaranteed to work for.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+pub struct AdversarialModel {
+    pub conditions: ConditionSet,
+    /// Maximum number of Poor conditions. `None` means there is no limit.
+    pub max_poor: Option<u8>,
+}
+
+impl AdversarialModel {
+    /// Returns true if the model allows the given condition sequence.
+    /// Good is never allowed because it can only ever increase Quality.
+    pub fn allows(&self, conditions: &[Condition]) -> bool {
+        let poor_count = conditions
+            .iter()
+            .filter(|condition| **condition == Condition::Poor)
+            .count();
+        if self
+            .max_poor
+            .is_some_and(|max_poor| poor_count > max_poor as usize)
+        {
+            return false;
+        }
+        if conditions
+            .first()
+            .is_some_and(|first| *first != Condition::Normal)
+        {
+            return false;
+        }
+        match self.conditions {
+            ConditionSet::ExcellentPoor => conditions.windows(2).all(|window| match window {
+                [Condition::Excellent, next] => *next == Condition::Poor,
+                [Condition::Poor, next] => *next == Condition::Normal,
+                [_, next] => *next == Condition::Normal || *next == Condition::Excellent,
+                _ => unreachable!(),
+            }),
+            ConditionSet::AnyPoor => conditions
+                .iter()
+                .all(|condition| matches!(condition, Condition::Normal | Condition::Poor)),
+        }
+    }
+
+    fn poor_budget(&self) -> usize {
+        self.max_poor.map_or(0, |max_poor| max_poor as usize)
+    }
+
+    /// Index of the budget node after spending another Poor, if the budget allows it.
+    fn spend_poor(&self, used: usize) -> Option<usize> {
+        match self.max_poor {
+            None => Some(0),
+            Some(max_poor) if used < max_poor as usize => Some(used + 1),
+            Some(_) => None,
+        }
+    }
+}
+
+/// Simulation state that tracks the Quality that is reached under every condition sequence of an `AdversarialModel`.
+/// Unlike `Settings::adversarial`, this works for any model, but the state is considerably larger.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AdversarialState {
+    /// State of the simulation when every condition is Normal.
+    pub state: SimulationState,
+    // Worst missing Quality for every combination of spent Poor budget and phase of the condition chain.
+    // Unreachable combinations are 0, which never affects the maximum.
+    missing_quality: [u16; NODES],
+}
+
+impl AdversarialState {
+    /// `settings.adversarial` is ignored, the model takes its place.
+    pub fn new(settings: &Settings, model: &AdversarialModel) -> Self {
+        assert!(
+            model
+                .max_poor
+                .is_none_or(|max_poor| max_poor <= MAX_POOR_BUDGET),
+            "max_poor must not be greater than MAX_POOR_BUDGET"
+        );
+        let settings = Self::normal_settings(settings);
+        let state = SimulationState::new(&settings);
+        let mut missing_quality = [0; NODES];
+        missing_quality[0] = state.get_missing_quality();
+        Self {
+            state,
+            missing_quality,
+        }
+    }
+
+    pub fn from_macro(
+        settings: &Settings,
+        model: &AdversarialModel,
+        actions: &[Action],
+    ) -> Result<Self, &'static str> {
+        let mut state = Self::new(settings, model);
+        for action in actions {
+            state = state.use_action(*action, settings, model)?;
+        }
+        Ok(state)
+    }
+
+    fn normal_settings(settings: &Settings) -> Settings {
+        Settings {
+            adversarial: false,
+            ..*settings
+        }
+    }
+
+    /// Uses the action assuming Normal condition for everything except Quality.
+    pub fn use_action(
+        self,
+        action: Action,
+        settings: &Settings,
+        model: &AdversarialModel,
+    ) -> Result<Self, &'static str> {
+        let settings = Self::normal_settings(settings);
+        let in_progress = InProgress::try_from(self.state)?;
+        let effects = in_progress.raw_state().effects;
+        let first_step = self.state.combo == Some(ComboAction::SynthesisBegin);
+        let state = in_progress.use_action(action, Condition::Normal, &settings)?;
+
+        let quality = |condition| action.quality_increase(&settings, &effects, condition);
+        let (normal, excellent, poor) = (
+            quality(Condition::Normal),
+            quality(Condition::Excellent),
+            quality(Condition::Poor),
+        );
+        let mut missing_quality = [0; NODES];
+        let mut update = |node: usize, missing: u16, increase: u16| {
+            missing_quality[node] =
+                std::cmp::max(missing_quality[node], missing.saturating_sub(increase));
+        };
+        for used in 0..=model.poor_budget() {
+            let node = used * PHASES;
+            let [free, after_excellent, after_poor] = [
+                self.missing_quality[node],
+                self.missing_quality[node + 1],
+                self.missing_quality[node + 2],
+            ];
+            match model.conditions {
+                ConditionSet::ExcellentPoor => {
+                    update(node, free, normal);
+                    update(node, after_poor, normal);
+                    // Excellent is only possible if the Poor that follows it is within the budget
+                    if !first_step && model.spend_poor(used).is_some() {
+                        update(node + 1, free, excellent);
+                    }
+                    if let Some(next) = model.spend_poor(used) {
+                        update(next * PHASES + 2, after_excellent, poor);
+                    }
+                }
+                ConditionSet::AnyPoor => {
+                    update(node, free, normal);
+                    if let (false, Some(next)) = (first_step, model.spend_poor(used)) {
+                        update(next * PHASES, free, poor);
+                    }
+                }
+            }
+        }
+        Ok(Self {
+            state,
+            missing_quality,
+        })
+    }
+
+    /// Missing Quality under the worst condition sequence allowed by the model.
+    pub fn get_missing_quality(&self) -> u16 {
+        *self.missing_quality.iter().max().unwrap()
+    }
+
+    /// Returns true if `self` reaches at least as much Quality as `other` under every condition sequence.
+    pub fn quality_dominates(&self, other: &Self) -> bool {
+        self.missing_quality
+            .iter()
+            .zip(other.missing_quality.iter())
+            .all(|(a, b)| a <= b)
+    }
+}
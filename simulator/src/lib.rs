@@ -11,4 +11,10 @@ pub mod state;
 pub use state::SimulationState;
 
 mod settings;
-pub use settings::{ActionMask, Settings};
+pub use settings::{ActionMask, GameVersion, Settings};
+
+mod adversarial;
+pub use adversarial::{AdversarialModel, AdversarialState, ConditionSet, MAX_POOR_BUDGET};
+
+mod lint;
+pub use lint::{lint_rotation, Lint, LintKind};
@@ -0,0 +1,203 @@
+use crate::{state::InProgress, Action, Condition, Settings, SimulationState};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LintKind {
+    /// Innovation, Veneration or Great Strides wore off without affecting any action.
+    UnusedEffect(Action),
+    /// Innovation, Veneration or Great Strides was used again before it affected any action.
+    OverwrittenEffect(Action),
+    /// Inner Quiet stacks that were lost because Inner Quiet was already (close to) 10.
+    InnerQuietOvercap { lost_stacks: u8 },
+    /// Durability that was restored above the maximum.
+    WastedRepair { durability: i8 },
+    /// CP left at the end of a rotation that doesn't max out Quality.
+    LeftoverCp { cp: i16 },
+    /// The finishing action overshoots Progress, and Basic Synthesis would have finished the craft for free.
+    ProgressOvershoot { overshoot: u16 },
+    /// Removing the step doesn't decrease the Progress or Quality of the rotation.
+    RemovableStep,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lint {
+    /// Index of the action that the finding refers to.
+    pub step: usize,
+    pub kind: LintKind,
+}
+
+// (Progress, Quality) reached by continuing from the state with the actions, or None if any of the actions fails
+fn progress_quality(
+    settings: &Settings,
+    mut state: SimulationState,
+    actions: &[Action],
+) -> Option<(u16, u16)> {
+    for action in actions {
+        let in_progress = InProgress::try_from(state).ok()?;
+        state = in_progress
+            .use_action(*action, Condition::Normal, settings)
+            .ok()?;
+    }
+    Some((
+        settings.max_progress - state.missing_progress,
+        settings.max_quality - state.get_missing_quality(),
+    ))
+}
+
+fn effect_duration(action: Action, state: &SimulationState) -> u8 {
+    match action {
+        Action::Innovation => state.effects.innovation(),
+        Action::Veneration => state.effects.veneration(),
+        Action::GreatStrides => state.effects.great_strides(),
+        _ => unreachable!(),
+    }
+}
+
+/// Walks the rotation through the simulator (assuming Normal conditions) and reports wasted resources and redundant steps.
+/// Only the actions before the first failing action are analyzed.
+pub fn lint_rotation(settings: &Settings, actions: &[Action]) -> Vec<Lint> {
+    let max_quality = settings.max_quality;
+    // Quality is tracked past max_quality so that wasted buffs are still detected
+    let settings = Settings {
+        max_quality: u16::MAX,
+        ..*settings
+    };
+
+    let mut lints = Vec::new();
+    let mut state = SimulationState::new(&settings);
+    // State before each step, so that removing a step only needs to simulate the steps after it
+    let mut prefix_states = Vec::with_capacity(actions.len());
+    // Step of each effect that is active but hasn't affected any action yet
+    let mut pending_effects: [(Action, Option<usize>); 3] = [
+        (Action::Innovation, None),
+        (Action::Veneration, None),
+        (Action::GreatStrides, None),
+    ];
+
+    for (step, action) in actions.iter().copied().enumerate() {
+        let Ok(in_progress) = InProgress::try_from(state) else {
+            break;
+        };
+        let Ok(new_state) = in_progress.use_action(action, Condition::Normal, &settings) else {
+            break;
+        };
+        let effects = state.effects;
+        let affects_quality = action.quality_increase(&settings, &effects, Condition::Normal) != 0;
+        let affects_progress = action.progress_increase(&settings, &effects) != 0;
+
+        for (effect, pending) in pending_effects.iter_mut() {
+            let affected = match *effect {
+                Action::Veneration => affects_progress,
+                _ => affects_quality,
+            };
+            if affected && effect_duration(*effect, &state) != 0 {
+                *pending = None;
+            }
+            if action == *effect {
+                if let Some(applied) = pending.replace(step) {
+                    lints.push(Lint {
+                        step: applied,
+                        kind: LintKind::OverwrittenEffect(*effect),
+                    });
+                }
+            } else if effect_duration(*effect, &new_state) == 0 {
+                if let Some(applied) = pending.take() {
+                    lints.push(Lint {
+                        step: applied,
+                        kind: LintKind::UnusedEffect(*effect),
+                    });
+                }
+            }
+        }
+
+        if affects_quality && action != Action::ByregotsBlessing && settings.job_level >= 11 {
+            let lost_stacks =
+                (effects.inner_quiet() + action.inner_quiet_bonus()).saturating_sub(10);
+            // every action past 10 stacks loses its single stack, only the bonus stacks are worth pointing out
+            if action.inner_quiet_bonus() > 1 && lost_stacks != 0 {
+                lints.push(Lint {
+                    step,
+                    kind: LintKind::InnerQuietOvercap { lost_stacks },
+                });
+            }
+        }
+
+        if new_state.missing_progress != 0 && new_state.durability > 0 {
+            let mut repair = 0;
+            if action == Action::MasterMend {
+                repair += 30;
+            }
+            if effects.manipulation() != 0 && action != Action::Manipulation {
+                repair += 5;
+            }
+            // the durability cost is 0 if the action consumes Trained Perfection
+            let wasted =
+                state.durability - action.durability_cost(&effects) + repair - new_state.durability;
+            if repair != 0 && wasted > 0 {
+                lints.push(Lint {
+                    step,
+                    kind: LintKind::WastedRepair { durability: wasted },
+                });
+            }
+        }
+
+        if new_state.missing_progress == 0
+            && action != Action::BasicSynthesis
+            && action.cp_cost() != 0
+            && action.quality_efficiency(effects.inner_quiet(), settings.game_version) == 0
+            && settings.allowed_actions.has(Action::BasicSynthesis)
+            && Action::BasicSynthesis.progress_increase(&settings, &effects)
+                >= state.missing_progress
+        {
+            lints.push(Lint {
+                step,
+                kind: LintKind::ProgressOvershoot {
+                    overshoot: action.progress_increase(&settings, &effects)
+                        - state.missing_progress,
+                },
+            });
+        }
+
+        prefix_states.push(state);
+        state = new_state;
+    }
+    let steps = prefix_states.len();
+
+    let quality = settings.max_quality - state.get_missing_quality();
+    if state.missing_progress == 0 {
+        for (effect, pending) in pending_effects {
+            if let Some(applied) = pending {
+                lints.push(Lint {
+                    step: applied,
+                    kind: LintKind::UnusedEffect(effect),
+                });
+            }
+        }
+        if quality < max_quality && state.cp >= Action::BasicTouch.cp_cost() {
+            lints.push(Lint {
+                step: steps - 1,
+                kind: LintKind::LeftoverCp { cp: state.cp },
+            });
+        }
+    }
+
+    let actions = &actions[..steps];
+    let reached = (
+        settings.max_progress - state.missing_progress,
+        std::cmp::min(max_quality, quality),
+    );
+    for (step, prefix_state) in prefix_states.into_iter().enumerate() {
+        if let Some((progress, quality)) =
+            progress_quality(&settings, prefix_state, &actions[step + 1..])
+        {
+            if progress >= reached.0 && std::cmp::min(max_quality, quality) >= reached.1 {
+                lints.push(Lint {
+                    step,
+                    kind: LintKind::RemovableStep,
+                });
+            }
+        }
+    }
+
+    lints.sort_by_key(|lint| lint.step);
+    lints
+}
@@ -10,10 +10,84 @@ pub struct Settings {
     pub job_level: u8,
     pub allowed_actions: ActionMask,
     pub adversarial: bool,
+    #[serde(default)]
+    pub game_version: GameVersion,
 }
 
 use crate::Action;
 
+/// Rule set of a game version, as action formulas and availability changed between expansions.
+/// Versions before Endwalker are not supported, because Inner Quiet used to increase Control instead of Quality.
+///
+/// Endwalker differs from Dawntrail in the efficiency of Reflect, in Observe not starting the Advanced Touch combo
+/// and in the actions that are available (see `has_action`).
+/// Byregot's Blessing, Groundwork and the durability costs use the same formulas in both versions,
+/// and the level 94 trait of Delicate Synthesis can't be reached in Endwalker, so they don't depend on the version.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Default, serde::Serialize, serde::Deserialize,
+)]
+pub enum GameVersion {
+    /// Patch 6.x, level cap 90.
+    Endwalker,
+    /// Patch 7.x, level cap 100.
+    #[default]
+    Dawntrail,
+}
+
+impl std::fmt::Display for GameVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Endwalker => write!(f, "Endwalker (6.x)"),
+            Self::Dawntrail => write!(f, "Dawntrail (7.x)"),
+        }
+    }
+}
+
+impl GameVersion {
+    /// Returns the version of a patch string such as "6.58" or "7.0", or `None` if the expansion is not supported.
+    pub fn from_patch(patch: &str) -> Option<Self> {
+        match patch.split('.').next()? {
+            "6" => Some(Self::Endwalker),
+            "7" => Some(Self::Dawntrail),
+            _ => None,
+        }
+    }
+
+    pub const fn max_level(self) -> u8 {
+        match self {
+            Self::Endwalker => 90,
+            Self::Dawntrail => 100,
+        }
+    }
+
+    /// Returns false if the action doesn't exist in the game version or is unlocked at a higher level than in the latest version.
+    /// The level requirements of the latest version are checked separately through `ActionMask::from_level`.
+    pub const fn has_action(self, action: Action, job_level: u8) -> bool {
+        match self {
+            Self::Endwalker => match action {
+                // Advanced Touch replaced Focused Touch at level 68 in 7.0
+                Action::AdvancedTouch | Action::ComboAdvancedTouch => job_level >= 84,
+                Action::ComboRefinedTouch | Action::ImmaculateMend | Action::TrainedPerfection => {
+                    false
+                }
+                _ => true,
+            },
+            Self::Dawntrail => true,
+        }
+    }
+
+    /// Returns the actions that are available in the game version at the given level.
+    pub fn action_mask(self, job_level: u8) -> ActionMask {
+        let mut result = ActionMask::none();
+        for action in ALL_ACTIONS {
+            if self.has_action(*action, job_level) {
+                result = result.add(*action);
+            }
+        }
+        result
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub struct ActionMask {
     mask: u64,
@@ -110,6 +110,9 @@ impl InProgress {
         if !settings.allowed_actions.has(action) {
             return Err("Action not enabled");
         }
+        if !settings.game_version.has_action(action, settings.job_level) {
+            return Err("Action not available in this game version");
+        }
         if action.cp_cost() > self.state.cp {
             return Err("Not enough CP");
         }
@@ -197,16 +200,9 @@ impl InProgress {
                 state.unreliable_quality[1].saturating_sub(quality_increase);
             state.effects.set_great_strides(0);
             if settings.job_level >= 11 {
-                let inner_quiet_bonus = match action {
-                    Action::Reflect => 2,
-                    Action::PreciseTouch => 2,
-                    Action::PreparatoryTouch => 2,
-                    Action::ComboRefinedTouch => 2,
-                    _ => 1,
-                };
                 state.effects.set_inner_quiet(std::cmp::min(
                     10,
-                    state.effects.inner_quiet() + inner_quiet_bonus,
+                    state.effects.inner_quiet() + action.inner_quiet_bonus(),
                 ));
             }
         }
@@ -235,7 +231,7 @@ impl InProgress {
             return Ok(state);
         }
 
-        state.combo = action.to_combo();
+        state.combo = action.to_combo(settings.game_version);
 
         // remove manipulation before it is triggered
         if action == Action::Manipulation {
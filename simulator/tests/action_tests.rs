@@ -1,4 +1,6 @@
-use simulator::{state::InProgress, Action, ActionMask, Condition, Settings, SimulationState};
+use simulator::{
+    state::InProgress, Action, ActionMask, Condition, GameVersion, Settings, SimulationState,
+};
 
 const SETTINGS: Settings = Settings {
     max_cp: 250,
@@ -11,6 +13,7 @@ const SETTINGS: Settings = Settings {
     job_level: 100,
     allowed_actions: ActionMask::all(),
     adversarial: false,
+    game_version: GameVersion::Dawntrail,
 };
 
 #[test]
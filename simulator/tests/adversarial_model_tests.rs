@@ -0,0 +1,225 @@
+use simulator::{
+    state::InProgress, Action, ActionMask, AdversarialModel, AdversarialState, Condition,
+    ConditionSet, GameVersion, Settings, SimulationState, MAX_POOR_BUDGET,
+};
+
+const SETTINGS: Settings = Settings {
+    max_cp: 1000,
+    max_durability: 80,
+    max_progress: 2000,
+    max_quality: 40000,
+    base_progress: 100,
+    base_quality: 100,
+    initial_quality: 0,
+    job_level: 100,
+    allowed_actions: ActionMask::all(),
+    adversarial: false,
+    game_version: GameVersion::Dawntrail,
+};
+
+fn models() -> Vec<AdversarialModel> {
+    let mut models = Vec::new();
+    for conditions in [ConditionSet::ExcellentPoor, ConditionSet::AnyPoor] {
+        models.push(AdversarialModel {
+            conditions,
+            max_poor: None,
+        });
+        for max_poor in 0..=MAX_POOR_BUDGET {
+            models.push(AdversarialModel {
+                conditions,
+                max_poor: Some(max_poor),
+            });
+        }
+    }
+    models
+}
+
+/// Calculate the minimum achievable Quality across all condition sequences allowed by the model
+fn guaranteed_quality(model: &AdversarialModel, actions: &[Action]) -> u16 {
+    fn search(
+        model: &AdversarialModel,
+        actions: &[Action],
+        conditions: &mut Vec<Condition>,
+        state: SimulationState,
+    ) -> u16 {
+        let Some(action) = actions.get(conditions.len()) else {
+            return SETTINGS.max_quality - state.get_missing_quality();
+        };
+        let mut min_quality = u16::MAX;
+        for condition in [Condition::Normal, Condition::Excellent, Condition::Poor] {
+            conditions.push(condition);
+            if model.allows(conditions) {
+                let state = InProgress::try_from(state)
+                    .unwrap()
+                    .use_action(*action, condition, &SETTINGS)
+                    .unwrap();
+                min_quality = std::cmp::min(min_quality, search(model, actions, conditions, state));
+            }
+            conditions.pop();
+        }
+        min_quality
+    }
+    search(
+        model,
+        actions,
+        &mut Vec::new(),
+        SimulationState::new(&SETTINGS),
+    )
+}
+
+fn check_all_models(actions: &[Action]) {
+    for model in models() {
+        let state = AdversarialState::from_macro(&SETTINGS, &model, actions).unwrap();
+        assert_eq!(
+            SETTINGS.max_quality - state.get_missing_quality(),
+            guaranteed_quality(&model, actions),
+            "{model:?} {actions:?}"
+        );
+    }
+}
+
+#[test]
+fn test_allowed_sequences() {
+    use Condition::*;
+    let model = AdversarialModel::default();
+    assert!(model.allows(&[Normal, Excellent, Poor, Normal, Excellent]));
+    assert!(!model.allows(&[Excellent, Poor]));
+    assert!(!model.allows(&[Normal, Poor]));
+    assert!(!model.allows(&[Normal, Excellent, Normal]));
+    assert!(!model.allows(&[Normal, Excellent, Poor, Excellent]));
+    assert!(!model.allows(&[Normal, Good]));
+    let model = AdversarialModel {
+        conditions: ConditionSet::AnyPoor,
+        max_poor: Some(2),
+    };
+    assert!(model.allows(&[Normal, Poor, Poor, Normal]));
+    assert!(!model.allows(&[Poor, Normal]));
+    assert!(!model.allows(&[Normal, Poor, Poor, Poor]));
+    assert!(!model.allows(&[Normal, Excellent]));
+}
+
+#[test]
+fn test_zero_budget_is_normal() {
+    let actions = [
+        Action::Reflect,
+        Action::Innovation,
+        Action::BasicTouch,
+        Action::ComboStandardTouch,
+        Action::ByregotsBlessing,
+    ];
+    let normal = SimulationState::from_macro(&SETTINGS, &actions).unwrap();
+    for conditions in [ConditionSet::ExcellentPoor, ConditionSet::AnyPoor] {
+        let model = AdversarialModel {
+            conditions,
+            max_poor: Some(0),
+        };
+        let state = AdversarialState::from_macro(&SETTINGS, &model, &actions).unwrap();
+        assert_eq!(state.get_missing_quality(), normal.get_missing_quality());
+        assert_eq!(state.state, normal);
+    }
+}
+
+#[test]
+fn test_budget_is_monotonic() {
+    let actions = [
+        Action::MuscleMemory,
+        Action::GreatStrides,
+        Action::PreparatoryTouch,
+        Action::Innovation,
+        Action::BasicTouch,
+        Action::Observe,
+        Action::AdvancedTouch,
+        Action::GreatStrides,
+        Action::PreparatoryTouch,
+    ];
+    for conditions in [ConditionSet::ExcellentPoor, ConditionSet::AnyPoor] {
+        let mut previous_quality = u16::MAX;
+        for max_poor in (0..=MAX_POOR_BUDGET).map(Some).chain([None]) {
+            let model = AdversarialModel {
+                conditions,
+                max_poor,
+            };
+            let state = AdversarialState::from_macro(&SETTINGS, &model, &actions).unwrap();
+            let quality = SETTINGS.max_quality - state.get_missing_quality();
+            assert!(quality <= previous_quality);
+            previous_quality = quality;
+        }
+    }
+}
+
+#[test]
+fn test_matches_settings_adversarial() {
+    // the unlimited Excellent > Poor model is the one of Settings::adversarial
+    let settings = Settings {
+        adversarial: true,
+        ..SETTINGS
+    };
+    let actions = [
+        Action::Reflect,
+        Action::Manipulation,
+        Action::Innovation,
+        Action::WasteNot2,
+        Action::BasicTouch,
+        Action::ComboStandardTouch,
+        Action::PreparatoryTouch,
+        Action::Veneration,
+        Action::DelicateSynthesis,
+        Action::Groundwork,
+        Action::Innovation,
+        Action::BasicTouch,
+        Action::ComboStandardTouch,
+        Action::ComboAdvancedTouch,
+        Action::ByregotsBlessing,
+        Action::CarefulSynthesis,
+    ];
+    for length in 1..=actions.len() {
+        let expected = SimulationState::from_macro(&settings, &actions[..length]).unwrap();
+        let state = AdversarialState::from_macro(
+            &SETTINGS,
+            &AdversarialModel::default(),
+            &actions[..length],
+        )
+        .unwrap();
+        assert_eq!(state.get_missing_quality(), expected.get_missing_quality());
+    }
+}
+
+#[test]
+/// Test all possible sequences of Observe and PrudentTouch of a certain length
+fn test_exhaustive() {
+    const STEPS: usize = 8;
+    for mask in 0..(1 << STEPS) {
+        let actions: Vec<Action> = (0..STEPS)
+            .map(|index| match (mask >> index) & 1 {
+                0 => Action::Observe,
+                _ => Action::PrudentTouch,
+            })
+            .collect();
+        check_all_models(&actions);
+    }
+}
+
+#[test]
+/// Test random quality action sequences
+fn test_fuzz() {
+    const STEPS: usize = 10;
+    const ACTIONS: [Action; 8] = [
+        Action::BasicTouch,
+        Action::StandardTouch,
+        Action::AdvancedTouch,
+        Action::ByregotsBlessing,
+        Action::Observe,
+        Action::Innovation,
+        Action::GreatStrides,
+        Action::ImmaculateMend,
+    ];
+    for _ in 0..1000 {
+        let actions: Vec<Action> =
+            std::iter::repeat_with(|| ACTIONS[rand::random::<usize>() % ACTIONS.len()])
+                .take(STEPS)
+                .collect();
+        if SimulationState::from_macro(&SETTINGS, &actions).is_ok() {
+            check_all_models(&actions);
+        }
+    }
+}
@@ -1,4 +1,6 @@
-use simulator::{state::InProgress, Action, ActionMask, Condition, Settings, SimulationState};
+use simulator::{
+    state::InProgress, Action, ActionMask, Condition, GameVersion, Settings, SimulationState,
+};
 
 const SETTINGS: Settings = Settings {
     max_cp: 1000,
@@ -11,6 +13,7 @@ const SETTINGS: Settings = Settings {
     job_level: 100,
     allowed_actions: ActionMask::all(),
     adversarial: true,
+    game_version: GameVersion::Dawntrail,
 };
 
 /// Calculate the minimum achievable Quality across all possible Condition rolls
@@ -0,0 +1,175 @@
+use simulator::{Action, ActionMask, GameVersion, Settings, SimulationState};
+
+fn settings(game_version: GameVersion) -> Settings {
+    Settings {
+        max_cp: 500,
+        max_durability: 80,
+        max_progress: 2000,
+        max_quality: 40000,
+        base_progress: 100,
+        base_quality: 100,
+        initial_quality: 0,
+        job_level: game_version.max_level(),
+        allowed_actions: ActionMask::all(),
+        adversarial: false,
+        game_version,
+    }
+}
+
+fn progress_and_quality(settings: &Settings, actions: &[Action]) -> (u16, u16) {
+    let state = SimulationState::from_macro(settings, actions).unwrap();
+    (
+        settings.max_progress - state.missing_progress,
+        settings.max_quality - state.get_missing_quality(),
+    )
+}
+
+#[test]
+fn test_from_patch() {
+    assert_eq!(
+        GameVersion::from_patch("6.58"),
+        Some(GameVersion::Endwalker)
+    );
+    assert_eq!(GameVersion::from_patch("7.0"), Some(GameVersion::Dawntrail));
+    assert_eq!(
+        GameVersion::from_patch("7.05"),
+        Some(GameVersion::Dawntrail)
+    );
+    assert_eq!(GameVersion::from_patch("5.5"), None);
+    assert_eq!(GameVersion::default(), GameVersion::Dawntrail);
+}
+
+#[test]
+fn test_endwalker_actions() {
+    let settings = settings(GameVersion::Endwalker);
+    for action in [
+        Action::TrainedPerfection,
+        Action::ImmaculateMend,
+        Action::ComboRefinedTouch,
+    ] {
+        assert!(!settings.game_version.has_action(action, 90));
+        let state = SimulationState::from_macro(&settings, &[Action::BasicTouch, action]);
+        assert_eq!(state, Err("Action not available in this game version"));
+    }
+    // Advanced Touch is only unlocked at level 84
+    let state = SimulationState::from_macro(
+        &Settings {
+            job_level: 83,
+            ..settings
+        },
+        &[Action::AdvancedTouch],
+    );
+    assert_eq!(state, Err("Action not available in this game version"));
+    assert!(SimulationState::from_macro(&settings, &[Action::AdvancedTouch]).is_ok());
+
+    let action_mask = settings.game_version.action_mask(90);
+    assert!(!action_mask.has(Action::TrainedPerfection));
+    assert!(action_mask.has(Action::ComboAdvancedTouch));
+    assert!(!settings
+        .game_version
+        .action_mask(80)
+        .has(Action::AdvancedTouch));
+}
+
+#[test]
+fn test_endwalker_combos() {
+    let settings = settings(GameVersion::Endwalker);
+    let state = SimulationState::from_macro(
+        &settings,
+        &[
+            Action::BasicTouch,
+            Action::ComboStandardTouch,
+            Action::ComboAdvancedTouch,
+        ],
+    );
+    assert!(state.is_ok());
+    // Observe doesn't combo into Advanced Touch before 7.0
+    let state =
+        SimulationState::from_macro(&settings, &[Action::Observe, Action::ComboAdvancedTouch]);
+    assert_eq!(state, Err("Combo requirement not fulfilled"));
+}
+
+#[test]
+fn test_endwalker_formulas() {
+    let settings = settings(GameVersion::Endwalker);
+    assert_eq!(
+        progress_and_quality(&settings, &[Action::Reflect]),
+        (0, 100)
+    );
+    // Delicate Synthesis Mastery is a level 94 trait, which is above the level cap of 6.x
+    assert_eq!(
+        progress_and_quality(&settings, &[Action::DelicateSynthesis]),
+        (100, 100)
+    );
+    // Byregot's Blessing and Groundwork have the same formulas in every supported version
+    assert_eq!(
+        progress_and_quality(&settings, &[Action::Groundwork]),
+        (360, 0)
+    );
+    assert_eq!(
+        progress_and_quality(
+            &settings,
+            &[
+                Action::BasicTouch,
+                Action::BasicTouch,
+                Action::ByregotsBlessing
+            ]
+        ),
+        (0, 100 + 110 + 168)
+    );
+}
+
+#[test]
+fn test_dawntrail_actions() {
+    let settings = settings(GameVersion::Dawntrail);
+    for action in [
+        Action::TrainedPerfection,
+        Action::ImmaculateMend,
+        Action::AdvancedTouch,
+    ] {
+        assert!(settings.game_version.has_action(action, 1));
+        assert!(SimulationState::from_macro(&settings, &[action]).is_ok());
+    }
+    assert!(SimulationState::from_macro(
+        &settings,
+        &[Action::BasicTouch, Action::ComboRefinedTouch]
+    )
+    .is_ok());
+    assert!(
+        SimulationState::from_macro(&settings, &[Action::Observe, Action::ComboAdvancedTouch])
+            .is_ok()
+    );
+    // every action exists at every level, level requirements are part of `ActionMask::from_level`
+    assert_eq!(
+        settings.game_version.action_mask(1),
+        settings.game_version.action_mask(100)
+    );
+}
+
+#[test]
+fn test_dawntrail_formulas() {
+    let settings = settings(GameVersion::Dawntrail);
+    assert_eq!(
+        progress_and_quality(&settings, &[Action::Reflect]),
+        (0, 300)
+    );
+    assert_eq!(
+        progress_and_quality(&settings, &[Action::DelicateSynthesis]),
+        (150, 100)
+    );
+    assert_eq!(
+        progress_and_quality(&settings, &[Action::Groundwork]),
+        (360, 0)
+    );
+    assert_eq!(
+        progress_and_quality(
+            &settings,
+            &[
+                Action::BasicTouch,
+                Action::BasicTouch,
+                Action::ByregotsBlessing
+            ]
+        ),
+        (0, 100 + 110 + 168)
+    );
+}
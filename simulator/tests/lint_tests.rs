@@ -0,0 +1,167 @@
+use simulator::{lint_rotation, Action, ActionMask, GameVersion, Lint, LintKind, Settings};
+
+const SETTINGS: Settings = Settings {
+    max_cp: 500,
+    max_durability: 70,
+    max_progress: 1000,
+    max_quality: 20000,
+    base_progress: 100,
+    base_quality: 100,
+    initial_quality: 0,
+    job_level: 100,
+    allowed_actions: ActionMask::all(),
+    adversarial: false,
+    game_version: GameVersion::Dawntrail,
+};
+
+fn lint(step: usize, kind: LintKind) -> Lint {
+    Lint { step, kind }
+}
+
+#[test]
+fn test_unused_effect() {
+    let actions = [
+        Action::Innovation,
+        Action::BasicSynthesis,
+        Action::BasicSynthesis,
+        Action::BasicSynthesis,
+        Action::BasicSynthesis,
+    ];
+    let lints = lint_rotation(&SETTINGS, &actions);
+    assert_eq!(
+        lints,
+        [
+            lint(0, LintKind::UnusedEffect(Action::Innovation)),
+            lint(0, LintKind::RemovableStep),
+        ]
+    );
+}
+
+#[test]
+fn test_overwritten_great_strides() {
+    let actions = [
+        Action::GreatStrides,
+        Action::GreatStrides,
+        Action::BasicTouch,
+    ];
+    let lints = lint_rotation(&SETTINGS, &actions);
+    assert_eq!(
+        lints,
+        [
+            lint(0, LintKind::OverwrittenEffect(Action::GreatStrides)),
+            lint(0, LintKind::RemovableStep),
+            lint(1, LintKind::RemovableStep),
+        ]
+    );
+}
+
+#[test]
+fn test_inner_quiet_overcap() {
+    let settings = Settings {
+        max_durability: 120,
+        ..SETTINGS
+    };
+    let actions = [
+        Action::Reflect,
+        Action::PreparatoryTouch,
+        Action::PreparatoryTouch,
+        Action::PreparatoryTouch,
+        Action::PreparatoryTouch,
+        Action::PreparatoryTouch,
+    ];
+    let lints = lint_rotation(&settings, &actions);
+    assert_eq!(
+        lints,
+        [lint(5, LintKind::InnerQuietOvercap { lost_stacks: 2 })]
+    );
+}
+
+#[test]
+fn test_wasted_repair() {
+    let actions = [Action::BasicTouch, Action::MasterMend];
+    let lints = lint_rotation(&SETTINGS, &actions);
+    assert_eq!(
+        lints,
+        [
+            lint(1, LintKind::WastedRepair { durability: 20 }),
+            lint(1, LintKind::RemovableStep),
+        ]
+    );
+
+    let actions = [
+        Action::Manipulation,
+        Action::Observe,
+        Action::BasicSynthesis,
+    ];
+    let lints = lint_rotation(&SETTINGS, &actions);
+    assert_eq!(
+        lints,
+        [
+            lint(0, LintKind::RemovableStep),
+            lint(1, LintKind::WastedRepair { durability: 5 }),
+            lint(1, LintKind::RemovableStep),
+        ]
+    );
+}
+
+#[test]
+fn test_wasted_repair_with_trained_perfection() {
+    let settings = Settings {
+        max_progress: 240,
+        ..SETTINGS
+    };
+    // Basic Synthesis doesn't cost any durability with Trained Perfection,
+    // so the repair of Manipulation is wasted at full durability
+    let actions = [
+        Action::Manipulation,
+        Action::TrainedPerfection,
+        Action::BasicSynthesis,
+        Action::BasicSynthesis,
+    ];
+    let lints = lint_rotation(&settings, &actions);
+    assert_eq!(
+        lints,
+        [
+            lint(0, LintKind::RemovableStep),
+            lint(1, LintKind::WastedRepair { durability: 5 }),
+            lint(1, LintKind::RemovableStep),
+            lint(2, LintKind::WastedRepair { durability: 5 }),
+            lint(3, LintKind::LeftoverCp { cp: 404 }),
+        ]
+    );
+}
+
+#[test]
+fn test_progress_overshoot_and_leftover_cp() {
+    let settings = Settings {
+        max_progress: 100,
+        ..SETTINGS
+    };
+    let actions = [Action::CarefulSynthesis];
+    let lints = lint_rotation(&settings, &actions);
+    assert_eq!(
+        lints,
+        [
+            lint(0, LintKind::ProgressOvershoot { overshoot: 80 }),
+            lint(0, LintKind::LeftoverCp { cp: 493 }),
+        ]
+    );
+}
+
+#[test]
+fn test_removable_step() {
+    let actions = [Action::BasicSynthesis, Action::Observe, Action::BasicTouch];
+    let lints = lint_rotation(&SETTINGS, &actions);
+    assert_eq!(lints, [lint(1, LintKind::RemovableStep)]);
+}
+
+#[test]
+fn test_stops_at_first_error() {
+    let actions = [
+        Action::BasicSynthesis,
+        Action::ByregotsBlessing,
+        Action::MasterMend,
+    ];
+    let lints = lint_rotation(&SETTINGS, &actions);
+    assert!(lints.is_empty());
+}
@@ -1,4 +1,6 @@
-use simulator::{state::InProgress, Action, ActionMask, Condition, Settings, SimulationState};
+use simulator::{
+    state::InProgress, Action, ActionMask, Condition, GameVersion, Settings, SimulationState,
+};
 
 fn simulate(
     settings: &Settings,
@@ -36,6 +38,7 @@ fn test_random_926ae85b() {
         job_level: 10,
         allowed_actions: ActionMask::all(),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = [
         Action::BasicSynthesis,
@@ -71,6 +74,7 @@ fn test_random_3c721e47() {
         job_level: 85,
         allowed_actions: ActionMask::all(),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = [
         Action::MuscleMemory,
@@ -110,6 +114,7 @@ fn test_random_3ba90d3a() {
         job_level: 81,
         allowed_actions: ActionMask::all(),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = [
         Action::Veneration,
@@ -152,6 +157,7 @@ fn test_random_bce2650c() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, false, false),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = [
         Action::MuscleMemory,
@@ -209,6 +215,7 @@ fn test_ingame_be9fc5c2() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
     let states: Vec<(u16, u16)> = simulate(
         &settings,
@@ -279,6 +286,7 @@ fn test_ingame_d11d9c68() {
         job_level: 94,
         allowed_actions: ActionMask::all(),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = [
         Action::Reflect,
@@ -338,6 +346,7 @@ fn test_ingame_f9f0dac7() {
         job_level: 100,
         allowed_actions: ActionMask::all(),
         adversarial: true,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = [
         Action::Reflect,
@@ -0,0 +1,39 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use simulator::{state::InProgress, ActionMask, GameVersion, Settings};
+use solvers::MacroSolver;
+
+fn settings() -> Settings {
+    Settings {
+        max_cp: 370,
+        max_durability: 60,
+        max_progress: 2000,
+        max_quality: 40000,
+        base_progress: 100,
+        base_quality: 100,
+        initial_quality: 0,
+        job_level: 90,
+        allowed_actions: ActionMask::from_level(90, true, false),
+        adversarial: false,
+        game_version: GameVersion::Dawntrail,
+    }
+}
+
+fn solve(symmetry_reduction: bool) {
+    let settings = settings();
+    let mut solver = MacroSolver::new(settings, Box::new(|_| {}));
+    if !symmetry_reduction {
+        solver = solver.without_symmetry_reduction();
+    }
+    solver.solve(InProgress::new(&settings), false).unwrap();
+}
+
+fn bench_symmetry_reduction(c: &mut Criterion) {
+    let mut group = c.benchmark_group("macro_solver");
+    group.sample_size(10);
+    group.bench_function("symmetry_reduction", |b| b.iter(|| solve(true)));
+    group.bench_function("no_symmetry_reduction", |b| b.iter(|| solve(false)));
+    group.finish();
+}
+
+criterion_group!(benches, bench_symmetry_reduction);
+criterion_main!(benches);
@@ -1,4 +1,4 @@
-use simulator::{state::InProgress, ActionMask, Settings, SimulationState};
+use simulator::{state::InProgress, ActionMask, GameVersion, Settings, SimulationState};
 use solvers::MacroSolver;
 
 fn main() {
@@ -16,6 +16,7 @@ fn main() {
         job_level: 100,
         allowed_actions: ActionMask::from_level(100, true, false),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
 
     let state = InProgress::new(&settings);
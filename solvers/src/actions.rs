@@ -39,3 +39,13 @@ pub const DURABILITY_ACTIONS: ActionMask = action_mask!(
     Action::ImmaculateMend,
     Action::TrainedPerfection
 );
+
+/// Actions that are searched by the solvers that build a full rotation.
+pub const FULL_SEARCH_ACTIONS: ActionMask = PROGRESS_ACTIONS
+    .union(QUALITY_ACTIONS)
+    .union(DURABILITY_ACTIONS);
+
+/// Actions that are searched when Progress is backloaded and the first Progress action has been used.
+pub const PROGRESS_SEARCH_ACTIONS: ActionMask = PROGRESS_ACTIONS
+    .union(DURABILITY_ACTIONS)
+    .remove(Action::DelicateSynthesis);
@@ -0,0 +1,150 @@
+use rustc_hash::FxHashSet as HashSet;
+use simulator::{state::InProgress, Action, Condition, Settings, SimulationState};
+
+use crate::actions::{FULL_SEARCH_ACTIONS, PROGRESS_SEARCH_ACTIONS};
+use crate::utils::{Backtracking, NamedTimer};
+use crate::{FinishSolver, UpperBoundSolver};
+
+#[derive(Debug, Clone)]
+pub struct ApproximateSolution {
+    pub actions: Vec<Action>,
+    pub quality: u16,
+    /// Upper bound on the Quality of the optimal solution.
+    pub quality_upper_bound: u16,
+}
+
+impl ApproximateSolution {
+    /// Maximum amount of Quality that the optimal solution reaches in addition to this one.
+    pub fn quality_gap(&self) -> u16 {
+        self.quality_upper_bound - self.quality
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Candidate {
+    state: InProgress,
+    quality_upper_bound: u16,
+    quality: u16,
+    duration: u16,
+    action: Action,
+    parent_index: u32,
+}
+
+/// Approximate solver that only keeps the most promising states of each step.
+/// Much faster than `MacroSolver`, but the solution is not guaranteed to be optimal.
+pub struct BeamSearchSolver {
+    settings: Settings,
+    beam_width: usize,
+    finish_solver: FinishSolver,
+    bound_solver: UpperBoundSolver,
+}
+
+impl BeamSearchSolver {
+    /// Solves most recipes in well under a second.
+    pub const DEFAULT_BEAM_WIDTH: usize = 256;
+
+    pub fn new(settings: Settings, beam_width: usize) -> Self {
+        Self {
+            settings,
+            beam_width,
+            finish_solver: FinishSolver::new(settings),
+            bound_solver: UpperBoundSolver::new(settings),
+        }
+    }
+
+    /// Returns the best solution found together with an upper bound on the optimal Quality.
+    /// Returns `None` if the state cannot be completed (i.e. cannot max out Progress).
+    pub fn solve(
+        &mut self,
+        state: InProgress,
+        backload_progress: bool,
+    ) -> Option<ApproximateSolution> {
+        let _timer = NamedTimer::new("Beam search");
+        if !self.finish_solver.can_finish(&state) {
+            return None;
+        }
+        let quality_upper_bound = self.bound_solver.quality_upper_bound(state);
+
+        let mut backtracking: Backtracking<Action> = Backtracking::new();
+        let mut beam = vec![(state, 0, Backtracking::<Action>::SENTINEL)];
+        let mut solution: Option<(u16, u16, u32)> = None; // (quality, duration, trace_index)
+
+        while !beam.is_empty() {
+            let mut visited_states: HashSet<SimulationState> = HashSet::default();
+            let mut candidates: Vec<Candidate> = Vec::new();
+            for (state, duration, backtrack_index) in beam {
+                let search_actions = match backload_progress
+                    && state.raw_state().missing_progress != self.settings.max_progress
+                {
+                    true => PROGRESS_SEARCH_ACTIONS.intersection(self.settings.allowed_actions),
+                    false => FULL_SEARCH_ACTIONS.intersection(self.settings.allowed_actions),
+                };
+                for action in search_actions.actions_iter() {
+                    let Ok(new_state) = state.use_action(action, Condition::Normal, &self.settings)
+                    else {
+                        continue;
+                    };
+                    let quality = self.settings.max_quality - new_state.get_missing_quality();
+                    let duration = duration + action.time_cost() as u16;
+                    if let Ok(in_progress) = InProgress::try_from(new_state) {
+                        if !self.finish_solver.can_finish(&in_progress)
+                            || !visited_states.insert(new_state)
+                        {
+                            continue;
+                        }
+                        let state_upper_bound = self.bound_solver.quality_upper_bound(in_progress);
+                        if solution
+                            .is_some_and(|(best_quality, _, _)| state_upper_bound <= best_quality)
+                        {
+                            continue;
+                        }
+                        candidates.push(Candidate {
+                            state: in_progress,
+                            quality_upper_bound: state_upper_bound,
+                            quality,
+                            duration,
+                            action,
+                            parent_index: backtrack_index,
+                        });
+                    } else if new_state.missing_progress == 0 {
+                        let is_better = match solution {
+                            Some((best_quality, best_duration, _)) => {
+                                (quality, best_duration) > (best_quality, duration)
+                            }
+                            None => true,
+                        };
+                        if is_better {
+                            let trace_index = backtracking.push(action, backtrack_index);
+                            solution = Some((quality, duration, trace_index));
+                        }
+                    }
+                }
+            }
+            if solution.is_some_and(|(quality, _, _)| quality == quality_upper_bound) {
+                break;
+            }
+            candidates.sort_by(|a, b| {
+                b.quality_upper_bound
+                    .cmp(&a.quality_upper_bound)
+                    .then(b.quality.cmp(&a.quality))
+                    .then(a.duration.cmp(&b.duration))
+            });
+            candidates.truncate(self.beam_width);
+            beam = candidates
+                .into_iter()
+                .map(|candidate| {
+                    let backtrack_index =
+                        backtracking.push(candidate.action, candidate.parent_index);
+                    (candidate.state, candidate.duration, backtrack_index)
+                })
+                .collect();
+        }
+
+        let (quality, _, trace_index) = solution?;
+        Some(ApproximateSolution {
+            actions: backtracking.get(trace_index).collect(),
+            quality,
+            quality_upper_bound,
+        })
+    }
+}
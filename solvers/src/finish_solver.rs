@@ -6,6 +6,7 @@ use simulator::{
 use rustc_hash::FxHashMap as HashMap;
 
 use super::actions::{DURABILITY_ACTIONS, PROGRESS_ACTIONS};
+use super::utils::hash_map_memory_usage;
 
 const SEARCH_ACTIONS: ActionMask = PROGRESS_ACTIONS
     .union(DURABILITY_ACTIONS)
@@ -94,6 +95,15 @@ impl FinishSolver {
         }
     }
 
+    pub fn memory_usage(&self) -> usize {
+        hash_map_memory_usage(&self.max_progress)
+    }
+
+    /// Frees the memory of all solved states. They are solved again when needed.
+    pub fn clear_cache(&mut self) {
+        self.max_progress = HashMap::default();
+    }
+
     pub fn can_finish(&mut self, state: &InProgress) -> bool {
         let max_progress = self.solve_max_progress(ReducedState::from_state(state));
         max_progress >= state.raw_state().missing_progress
@@ -2,10 +2,29 @@ mod actions;
 mod utils;
 
 mod finish_solver;
-use finish_solver::FinishSolver;
+pub use finish_solver::FinishSolver;
 
 mod upper_bound_solver;
-use upper_bound_solver::UpperBoundSolver;
+pub use upper_bound_solver::UpperBoundSolver;
 
 mod macro_solver;
-pub use macro_solver::MacroSolver;
+pub use macro_solver::{MacroSolver, SearchStats};
+
+mod beam_search;
+pub use beam_search::{ApproximateSolution, BeamSearchSolver};
+
+mod quality_target_solver;
+pub use quality_target_solver::QualityTargetSolver;
+
+mod robust_solver;
+pub use robust_solver::RobustSolver;
+
+mod multi_settings_solver;
+pub use multi_settings_solver::{
+    MultiSettingsObjective, MultiSettingsSolution, MultiSettingsSolver,
+};
+
+#[cfg(feature = "reference")]
+mod reference_solver;
+#[cfg(feature = "reference")]
+pub use reference_solver::ReferenceSolver;
@@ -2,5 +2,7 @@ mod fast_lower_bound;
 mod pareto_set;
 mod quick_search;
 mod solver;
+mod symmetry;
 
-pub use solver::MacroSolver;
+pub(crate) use solver::Score;
+pub use solver::{MacroSolver, SearchStats};
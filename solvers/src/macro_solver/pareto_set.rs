@@ -2,6 +2,8 @@ use pareto_front::{Dominate, ParetoFront};
 use rustc_hash::FxHashMap;
 use simulator::{ComboAction, Effects, SimulationState};
 
+use crate::utils::hash_map_memory_usage;
+
 #[derive(Clone, Copy)]
 struct Value {
     cp: i16,
@@ -61,14 +63,21 @@ impl Key {
 #[derive(Default)]
 pub struct ParetoSet {
     buckets: FxHashMap<Key, ParetoFront<Value>>,
+    // total number of values in all buckets
+    values: usize,
 }
 
 impl ParetoSet {
     pub fn insert(&mut self, state: SimulationState) -> bool {
-        self.buckets
-            .entry(Key::new(state))
-            .or_default()
-            .push(Value::new(state))
+        let bucket = self.buckets.entry(Key::new(state)).or_default();
+        let previous_len = bucket.len();
+        let inserted = bucket.push(Value::new(state));
+        self.values = self.values + bucket.len() - previous_len;
+        inserted
+    }
+
+    pub fn memory_usage(&self) -> usize {
+        hash_map_memory_usage(&self.buckets) + self.values * std::mem::size_of::<Value>()
     }
 }
 
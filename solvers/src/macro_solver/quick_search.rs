@@ -4,17 +4,14 @@ use simulator::{
 };
 
 use crate::{
-    actions::{DURABILITY_ACTIONS, PROGRESS_ACTIONS, QUALITY_ACTIONS},
+    actions::{DURABILITY_ACTIONS, PROGRESS_SEARCH_ACTIONS, QUALITY_ACTIONS},
     finish_solver::FinishSolver,
     upper_bound_solver::UpperBoundSolver,
     utils::{Backtracking, NamedTimer},
 };
 
 use super::pareto_set::ParetoSet;
-
-const PROGRESS_SEARCH_ACTIONS: ActionMask = PROGRESS_ACTIONS
-    .union(DURABILITY_ACTIONS)
-    .remove(Action::DelicateSynthesis);
+use super::symmetry::TranspositionTable;
 
 const QUALITY_SEARCH_ACTIONS: ActionMask = QUALITY_ACTIONS
     .union(DURABILITY_ACTIONS)
@@ -43,6 +40,7 @@ pub fn quick_search(
     let mut search_queue: RadixHeapMap<Score, SearchNode> = RadixHeapMap::default();
     let mut backtracking: Backtracking<Action> = Backtracking::new();
     let mut pareto_set = ParetoSet::default();
+    let mut transpositions = TranspositionTable::default();
 
     search_queue.push(
         Score::new(0, 0),
@@ -66,6 +64,9 @@ pub fn quick_search(
                     if action == Action::ByregotsBlessing && state.get_missing_quality() != 0 {
                         continue;
                     }
+                    if !transpositions.insert(&state) {
+                        continue;
+                    }
                     if !finish_solver.can_finish(&in_progress) {
                         continue;
                     }
@@ -1,36 +1,56 @@
 use radix_heap::RadixHeapMap;
 use simulator::state::InProgress;
-use simulator::{Action, ActionMask, Condition, Settings};
+use simulator::{Action, Condition, Settings};
 
 use super::pareto_set::ParetoSet;
 use super::quick_search::quick_search;
-use crate::actions::{DURABILITY_ACTIONS, PROGRESS_ACTIONS, QUALITY_ACTIONS};
+use super::symmetry::{is_redundant, TranspositionTable};
+use crate::actions::{FULL_SEARCH_ACTIONS, PROGRESS_SEARCH_ACTIONS};
 use crate::macro_solver::fast_lower_bound::fast_lower_bound;
 use crate::utils::{Backtracking, NamedTimer};
 use crate::{FinishSolver, UpperBoundSolver};
 
 use std::vec::Vec;
 
-const FULL_SEARCH_ACTIONS: ActionMask = PROGRESS_ACTIONS
-    .union(QUALITY_ACTIONS)
-    .union(DURABILITY_ACTIONS);
-
-const PROGRESS_SEARCH_ACTIONS: ActionMask = PROGRESS_ACTIONS
-    .union(DURABILITY_ACTIONS)
-    .remove(Action::DelicateSynthesis);
-
 #[derive(Debug, Clone, Copy)]
 struct SearchNode {
     state: InProgress,
     backtrack_index: u32,
 }
 
+// Number of expanded nodes in between two checks of the memory usage
+const MEMORY_CHECK_INTERVAL: usize = 1 << 12;
+
+/// Statistics of the last full search.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SearchStats {
+    pub expanded_nodes: usize,
+    pub finish_solver_rejected_nodes: usize,
+    pub upper_bound_solver_rejected_nodes: usize,
+    pub pareto_dominated_nodes: usize,
+    /// Nodes that were skipped because the exact same state was reached before through a different action order.
+    pub transposition_nodes: usize,
+    /// Nodes that were skipped because their last action is redundant, see `symmetry::is_redundant`.
+    pub redundant_action_nodes: usize,
+    /// Highest estimated memory usage in bytes.
+    pub peak_memory: usize,
+    /// Number of times that solver caches were dropped to stay within the memory limit.
+    pub cache_evictions: usize,
+    /// The search was stopped because it couldn't stay within the memory limit.
+    /// The returned rotation is the best one found until then and is not necessarily optimal.
+    /// `None` is returned if no rotation was found until then, even if the state can be completed.
+    pub memory_limit_reached: bool,
+}
+
 type ProgressCallback<'a> = dyn Fn(&[Action]) + 'a;
 pub struct MacroSolver<'a> {
     settings: Settings,
     finish_solver: FinishSolver,
     bound_solver: UpperBoundSolver,
     progress_callback: Box<ProgressCallback<'a>>,
+    memory_limit: Option<usize>,
+    symmetry_reduction: bool,
+    stats: SearchStats,
 }
 
 impl<'a> MacroSolver<'a> {
@@ -42,13 +62,38 @@ impl<'a> MacroSolver<'a> {
             finish_solver: FinishSolver::new(settings),
             bound_solver: UpperBoundSolver::new(settings),
             progress_callback: callback,
+            memory_limit: None,
+            symmetry_reduction: true,
+            stats: SearchStats::default(),
         }
     }
 
+    /// Limits the estimated memory usage of the full search to `bytes`.
+    /// When the limit is reached, the solver caches and the Pareto set are dropped. This costs time, but doesn't change the result.
+    /// If the search queue on its own still exceeds the limit, the search stops early and the result is NOT optimal,
+    /// or `None` if no rotation was found yet. Check `SearchStats::memory_limit_reached` after solving.
+    pub fn with_memory_limit(mut self, bytes: usize) -> Self {
+        self.memory_limit = Some(bytes);
+        self
+    }
+
+    /// Disables the transposition table and the redundant action rules of the full search.
+    /// Only exposed for the tests and benchmarks that measure how many nodes they save.
+    #[doc(hidden)]
+    pub fn without_symmetry_reduction(mut self) -> Self {
+        self.symmetry_reduction = false;
+        self
+    }
+
+    pub fn search_stats(&self) -> SearchStats {
+        self.stats
+    }
+
     /// Returns a list of Actions that maximizes Quality of the completed state.
-    /// Returns `None` if the state cannot be completed (i.e. cannot max out Progress).
+    /// Returns `None` if the state cannot be completed (i.e. cannot max out Progress) or if the memory limit was reached first.
     /// The solver makes an effort to produce a short solution, but it is not (yet) guaranteed to be the shortest solution.
     pub fn solve(&mut self, state: InProgress, backload_progress: bool) -> Option<Vec<Action>> {
+        self.stats = SearchStats::default();
         let timer = NamedTimer::new("Finish solver");
         if !self.finish_solver.can_finish(&state) {
             return None;
@@ -68,12 +113,21 @@ impl<'a> MacroSolver<'a> {
         self.do_solve(state, backload_progress)
     }
 
-    fn do_solve(&mut self, state: InProgress, backload_progress: bool) -> Option<Vec<Action>> {
-        let mut pareto_dominated_nodes: usize = 0;
-        let mut finish_solver_rejected_nodes: usize = 0;
-        let mut upper_bound_solver_rejected_nodes: usize = 0;
+    /// Runs only the quick search, which returns `None` if it can't find a rotation that maxes out Quality.
+    /// Exposed to compare it against `ReferenceSolver`.
+    #[cfg(feature = "reference")]
+    pub fn quick_search(&mut self, state: InProgress) -> Option<Vec<Action>> {
+        quick_search(
+            state,
+            &self.settings,
+            &mut self.finish_solver,
+            &mut self.bound_solver,
+        )
+    }
 
+    fn do_solve(&mut self, state: InProgress, backload_progress: bool) -> Option<Vec<Action>> {
         let mut pareto_set = ParetoSet::default();
+        let mut transpositions = TranspositionTable::default();
 
         let mut search_queue: RadixHeapMap<Score, SearchNode> = RadixHeapMap::new();
         let mut backtracking: Backtracking<Action> = Backtracking::new();
@@ -101,33 +155,63 @@ impl<'a> MacroSolver<'a> {
             if solution.is_some() && score <= solution.unwrap().0 {
                 break;
             }
+            self.stats.expanded_nodes += 1;
+            if self
+                .stats
+                .expanded_nodes
+                .is_multiple_of(MEMORY_CHECK_INTERVAL)
+            {
+                let search_memory = search_queue.len() * std::mem::size_of::<(Score, SearchNode)>()
+                    + backtracking.memory_usage();
+                if !self.enforce_memory_limit(search_memory, &mut pareto_set) {
+                    self.stats.memory_limit_reached = true;
+                    break;
+                }
+            }
             let search_actions = match backload_progress
                 && node.state.raw_state().missing_progress != self.settings.max_progress
             {
                 true => PROGRESS_SEARCH_ACTIONS.intersection(self.settings.allowed_actions),
                 false => FULL_SEARCH_ACTIONS.intersection(self.settings.allowed_actions),
             };
+            let previous_action = backtracking.last(node.backtrack_index);
             for action in search_actions.actions_iter() {
+                if self.symmetry_reduction
+                    && is_redundant(
+                        action,
+                        previous_action,
+                        node.state.raw_state(),
+                        &self.settings,
+                    )
+                {
+                    self.stats.redundant_action_nodes += 1;
+                    continue;
+                }
                 if let Ok(state) = node
                     .state
                     .use_action(action, Condition::Normal, &self.settings)
                 {
                     if let Ok(in_progress) = InProgress::try_from(state) {
+                        // skip this state if it has already been evaluated
+                        if self.symmetry_reduction && !transpositions.insert(&state) {
+                            self.stats.transposition_nodes += 1;
+                            continue;
+                        }
                         // skip this state if it is impossible to max out Progress
                         if !self.finish_solver.can_finish(&in_progress) {
-                            finish_solver_rejected_nodes += 1;
+                            self.stats.finish_solver_rejected_nodes += 1;
                             continue;
                         }
                         // skip this state if its Quality upper bound is not greater than the current best Quality
                         let quality_upper_bound =
                             self.bound_solver.quality_upper_bound(in_progress);
                         if quality_upper_bound < quality_lower_bound {
-                            upper_bound_solver_rejected_nodes += 1;
+                            self.stats.upper_bound_solver_rejected_nodes += 1;
                             continue;
                         }
                         // skip this state if it is Pareto-dominated
                         if !pareto_set.insert(state) {
-                            pareto_dominated_nodes += 1;
+                            self.stats.pareto_dominated_nodes += 1;
                             continue;
                         }
 
@@ -170,26 +254,48 @@ impl<'a> MacroSolver<'a> {
             None => None,
         };
 
-        dbg!(
-            finish_solver_rejected_nodes,
-            upper_bound_solver_rejected_nodes,
-            pareto_dominated_nodes,
-        );
-
         dbg!(&actions);
         actions
     }
+
+    /// Records the memory usage and drops caches if it exceeds the limit.
+    /// Dropping the Pareto set only means that fewer nodes are pruned, so this alone doesn't change the result.
+    /// Returns false if the limit cannot be kept. The search then has to stop, and its result is not optimal.
+    fn enforce_memory_limit(&mut self, search_memory: usize, pareto_set: &mut ParetoSet) -> bool {
+        let memory_usage = |solver: &Self, pareto_set: &ParetoSet| {
+            search_memory
+                + pareto_set.memory_usage()
+                + solver.finish_solver.memory_usage()
+                + solver.bound_solver.memory_usage()
+        };
+        let mut memory = memory_usage(self, pareto_set);
+        self.stats.peak_memory = std::cmp::max(self.stats.peak_memory, memory);
+        let Some(memory_limit) = self.memory_limit else {
+            return true;
+        };
+        if memory > memory_limit {
+            self.stats.cache_evictions += 1;
+            self.bound_solver.clear_cache();
+            self.finish_solver.clear_cache();
+            memory = memory_usage(self, pareto_set);
+        }
+        if memory > memory_limit {
+            *pareto_set = ParetoSet::default();
+            memory = memory_usage(self, pareto_set);
+        }
+        memory <= memory_limit
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-struct Score {
-    quality: u16,
-    duration: u8,
-    steps: u8,
+pub(crate) struct Score {
+    pub quality: u16,
+    pub duration: u8,
+    pub steps: u8,
 }
 
 impl Score {
-    fn new(quality: u16, duration: u8, steps: u8) -> Self {
+    pub fn new(quality: u16, duration: u8, steps: u8) -> Self {
         Self {
             quality,
             duration,
@@ -0,0 +1,91 @@
+use simulator::{Action, ComboAction, Settings, SimulationState};
+
+/// Lossless packing of a `SimulationState` into 106 bits.
+fn state_key(state: &SimulationState) -> u128 {
+    let combo = match state.combo {
+        None => 0,
+        Some(ComboAction::SynthesisBegin) => 1,
+        Some(ComboAction::BasicTouch) => 2,
+        Some(ComboAction::StandardTouch) => 3,
+    };
+    (state.cp as u16 as u128)
+        | (state.durability as u8 as u128) << 16
+        | (state.missing_progress as u128) << 24
+        | (state.unreliable_quality[0] as u128) << 40
+        | (state.unreliable_quality[1] as u128) << 56
+        | (state.effects.into_bits() as u128) << 72
+        | combo << 104
+}
+
+// Number of slots of the transposition table, must be a power of two
+const TRANSPOSITION_SLOTS: usize = 1 << 16;
+const EMPTY_SLOT: u128 = u128::MAX;
+
+/// Fixed-size cache of recently reached states.
+/// Different action orders often lead to the exact same state, and only the first one needs to be evaluated.
+/// Each state has a single slot which is overwritten on collision, so memory usage stays constant (1 MiB)
+/// and states that are no longer in the table are simply evaluated again.
+pub struct TranspositionTable {
+    slots: Box<[u128]>,
+}
+
+impl Default for TranspositionTable {
+    fn default() -> Self {
+        Self {
+            slots: vec![EMPTY_SLOT; TRANSPOSITION_SLOTS].into_boxed_slice(),
+        }
+    }
+}
+
+impl TranspositionTable {
+    /// Returns false if the state is already in the table.
+    pub fn insert(&mut self, state: &SimulationState) -> bool {
+        let key = state_key(state);
+        let hash = (key as u64 ^ (key >> 64) as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+        let slot = &mut self.slots[(hash >> (64 - TRANSPOSITION_SLOTS.trailing_zeros())) as usize];
+        if *slot == key {
+            return false;
+        }
+        *slot = key;
+        true
+    }
+}
+
+/// Returns true if using `action` right after `previous_action` is dominated by a shorter sequence that is also searched.
+/// - Overwriting an effect that was just applied only costs CP and time while the other effects tick down,
+///   so it is dominated by using `action` in place of `previous_action`.
+/// - Observe only helps if it starts the Advanced Touch combo. Followed by any other action it only costs CP and time,
+///   so it is dominated by using that action in place of Observe. In adversarial mode, Observe can also be used to
+///   spend a step without Quality, so it is never redundant there.
+///
+/// None of this holds while Manipulation is still active after `previous_action`, because the extra step restores Durability.
+/// If Manipulation ran out with `previous_action`, the shorter sequence restores the same Durability with `action` instead.
+///
+/// Different buffs, e.g. Innovation and Veneration, are not put into a canonical order. The effect that is applied first
+/// has already ticked down once when the second one is applied, so the two orders reach different states and neither
+/// dominates the other. Orders that do reach the exact same state are skipped by the `TranspositionTable`.
+pub fn is_redundant(
+    action: Action,
+    previous_action: Option<Action>,
+    state: &SimulationState,
+    settings: &Settings,
+) -> bool {
+    let Some(previous_action) = previous_action else {
+        return false;
+    };
+    if state.effects.manipulation() != 0 && action != Action::Manipulation {
+        return false;
+    }
+    if previous_action == Action::Observe {
+        return !settings.adversarial && action != Action::ComboAdvancedTouch;
+    }
+    match action {
+        Action::Innovation | Action::Veneration | Action::GreatStrides | Action::Manipulation => {
+            previous_action == action
+        }
+        Action::WasteNot | Action::WasteNot2 => {
+            matches!(previous_action, Action::WasteNot | Action::WasteNot2)
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,308 @@
+use rustc_hash::FxHashSet as HashSet;
+use simulator::{state::InProgress, Action, ActionMask, Condition, Settings, SimulationState};
+
+use crate::actions::{FULL_SEARCH_ACTIONS, PROGRESS_SEARCH_ACTIONS};
+use crate::utils::{Backtracking, NamedTimer};
+use crate::{FinishSolver, UpperBoundSolver};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MultiSettingsObjective {
+    /// Maximize the lowest Quality of all Settings, relative to their max Quality.
+    MinimumQuality,
+    /// Maximize the Quality of the Settings at the given index.
+    PrimaryQuality(usize),
+}
+
+#[derive(Debug, Clone)]
+pub struct MultiSettingsSolution {
+    pub actions: Vec<Action>,
+    /// Quality reached for each of the Settings, in the order they were given.
+    pub qualities: Vec<u16>,
+}
+
+/// Value of the objective, a fraction of Quality.
+/// Compared exactly by cross-multiplying, so that Qualities that only differ by a rounding error aren't considered equal.
+#[derive(Debug, Clone, Copy)]
+struct ObjectiveValue {
+    quality: u32,
+    max_quality: u32,
+}
+
+impl ObjectiveValue {
+    fn raw(quality: u16) -> Self {
+        Self {
+            quality: quality as u32,
+            max_quality: 1,
+        }
+    }
+
+    fn relative(quality: u16, max_quality: u16) -> Self {
+        match max_quality {
+            0 => Self::raw(1),
+            max_quality => Self {
+                quality: quality as u32,
+                max_quality: max_quality as u32,
+            },
+        }
+    }
+}
+
+impl Ord for ObjectiveValue {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.quality as u64 * other.max_quality as u64)
+            .cmp(&(other.quality as u64 * self.max_quality as u64))
+    }
+}
+
+impl PartialOrd for ObjectiveValue {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for ObjectiveValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == std::cmp::Ordering::Equal
+    }
+}
+
+impl Eq for ObjectiveValue {}
+
+#[derive(Debug, Clone)]
+struct Candidate {
+    states: Vec<SimulationState>,
+    objective_upper_bound: ObjectiveValue,
+    objective: ObjectiveValue,
+    duration: u16,
+    action: Action,
+    parent_index: u32,
+}
+
+struct Member {
+    settings: Settings,
+    finish_solver: FinishSolver,
+    bound_solver: UpperBoundSolver,
+}
+
+/// Solver for a single rotation that completes the craft for every one of a set of Settings,
+/// e.g. for several recipes or for the stats of several crafters.
+/// Searches the states of all Settings in lockstep, keeping only the most promising states of each step like `BeamSearchSolver`.
+/// A craft that is completed before the end of the rotation ignores the remaining actions, like an in-game macro does.
+///
+/// The solution is approximate, i.e. not guaranteed to be optimal, unless the beam width is `MultiSettingsSolver::EXACT`.
+/// The exact search keeps every state that can still beat the best solution found so far,
+/// so it is only feasible for small recipes.
+pub struct MultiSettingsSolver {
+    members: Vec<Member>,
+    objective: MultiSettingsObjective,
+    beam_width: usize,
+}
+
+impl MultiSettingsSolver {
+    /// Beam width that doesn't discard any states, which makes the solution optimal for the objective.
+    pub const EXACT: usize = usize::MAX;
+
+    pub fn new(
+        settings: &[Settings],
+        objective: MultiSettingsObjective,
+        beam_width: usize,
+    ) -> Self {
+        assert!(!settings.is_empty(), "settings must not be empty");
+        if let MultiSettingsObjective::PrimaryQuality(index) = objective {
+            assert!(index < settings.len(), "primary index out of bounds");
+        }
+        Self {
+            members: settings
+                .iter()
+                .map(|settings| Member {
+                    settings: *settings,
+                    finish_solver: FinishSolver::new(*settings),
+                    bound_solver: UpperBoundSolver::new(*settings),
+                })
+                .collect(),
+            objective,
+            beam_width,
+        }
+    }
+
+    fn objective_value(&self, mut qualities: impl Iterator<Item = u16>) -> ObjectiveValue {
+        match self.objective {
+            // relative to max Quality, so that recipes of different difficulty are comparable
+            MultiSettingsObjective::MinimumQuality => qualities
+                .zip(self.members.iter())
+                .map(|(quality, member)| {
+                    ObjectiveValue::relative(quality, member.settings.max_quality)
+                })
+                .min()
+                .unwrap(),
+            MultiSettingsObjective::PrimaryQuality(index) => {
+                ObjectiveValue::raw(qualities.nth(index).unwrap())
+            }
+        }
+    }
+
+    fn quality(&self, index: usize, state: &SimulationState) -> u16 {
+        self.members[index].settings.max_quality - state.get_missing_quality()
+    }
+
+    /// Uses the action on every state that is still in progress.
+    /// Returns `None` if the action cannot be used on one of them or if one of them can no longer be completed.
+    fn use_action(
+        &mut self,
+        states: &[SimulationState],
+        action: Action,
+    ) -> Option<Vec<SimulationState>> {
+        let mut new_states = Vec::with_capacity(states.len());
+        for (member, state) in self.members.iter_mut().zip(states.iter()) {
+            if state.missing_progress == 0 {
+                new_states.push(*state);
+                continue;
+            }
+            let in_progress = InProgress::try_from(*state).ok()?;
+            let new_state = in_progress
+                .use_action(action, Condition::Normal, &member.settings)
+                .ok()?;
+            if new_state.missing_progress != 0 {
+                let in_progress = InProgress::try_from(new_state).ok()?;
+                if !member.finish_solver.can_finish(&in_progress) {
+                    return None;
+                }
+            }
+            new_states.push(new_state);
+        }
+        Some(new_states)
+    }
+
+    fn objective_upper_bound(&mut self, states: &[SimulationState]) -> ObjectiveValue {
+        let quality_upper_bounds: Vec<u16> = self
+            .members
+            .iter_mut()
+            .zip(states.iter())
+            .map(|(member, state)| match InProgress::try_from(*state) {
+                Ok(in_progress) => member.bound_solver.quality_upper_bound(in_progress),
+                Err(_) => member.settings.max_quality - state.get_missing_quality(),
+            })
+            .collect();
+        self.objective_value(quality_upper_bounds.into_iter())
+    }
+
+    /// Returns a rotation that completes the craft for all Settings, or `None` if there is none.
+    /// The rotation is only guaranteed to be optimal if the solver uses the `EXACT` beam width.
+    pub fn solve(&mut self, backload_progress: bool) -> Option<MultiSettingsSolution> {
+        let _timer = NamedTimer::new("Multi-settings search");
+        let initial_states: Vec<SimulationState> = self
+            .members
+            .iter()
+            .map(|member| SimulationState::new(&member.settings))
+            .collect();
+        for member in self.members.iter_mut() {
+            if !member
+                .finish_solver
+                .can_finish(&InProgress::new(&member.settings))
+            {
+                return None;
+            }
+        }
+        let allowed_actions = self.members.iter().fold(ActionMask::all(), |mask, member| {
+            mask.intersection(member.settings.allowed_actions)
+        });
+
+        let mut backtracking: Backtracking<Action> = Backtracking::new();
+        let mut beam = vec![(initial_states, 0, Backtracking::<Action>::SENTINEL)];
+        let mut solution: Option<(ObjectiveValue, u16, u32)> = None; // (objective, duration, trace_index)
+
+        while !beam.is_empty() {
+            let mut visited_states: HashSet<Vec<SimulationState>> = HashSet::default();
+            let mut candidates: Vec<Candidate> = Vec::new();
+            for (states, duration, backtrack_index) in beam {
+                let progress_started = states
+                    .iter()
+                    .zip(self.members.iter())
+                    .any(|(state, member)| state.missing_progress != member.settings.max_progress);
+                let search_actions = match backload_progress && progress_started {
+                    true => PROGRESS_SEARCH_ACTIONS.intersection(allowed_actions),
+                    false => FULL_SEARCH_ACTIONS.intersection(allowed_actions),
+                };
+                for action in search_actions.actions_iter() {
+                    let Some(new_states) = self.use_action(&states, action) else {
+                        continue;
+                    };
+                    let objective = self.objective_value(
+                        new_states
+                            .iter()
+                            .enumerate()
+                            .map(|(index, state)| self.quality(index, state)),
+                    );
+                    let duration = duration + action.time_cost() as u16;
+                    if new_states.iter().all(|state| state.missing_progress == 0) {
+                        let is_better = match solution {
+                            Some((best_objective, best_duration, _)) => {
+                                (objective, best_duration) > (best_objective, duration)
+                            }
+                            None => true,
+                        };
+                        if is_better {
+                            let trace_index = backtracking.push(action, backtrack_index);
+                            solution = Some((objective, duration, trace_index));
+                        }
+                        continue;
+                    }
+                    if visited_states.contains(&new_states) {
+                        continue;
+                    }
+                    let objective_upper_bound = self.objective_upper_bound(&new_states);
+                    if solution.is_some_and(|(best_objective, _, _)| {
+                        objective_upper_bound <= best_objective
+                    }) {
+                        continue;
+                    }
+                    visited_states.insert(new_states.clone());
+                    candidates.push(Candidate {
+                        states: new_states,
+                        objective_upper_bound,
+                        objective,
+                        duration,
+                        action,
+                        parent_index: backtrack_index,
+                    });
+                }
+            }
+            candidates.sort_by(|a, b| {
+                b.objective_upper_bound
+                    .cmp(&a.objective_upper_bound)
+                    .then(b.objective.cmp(&a.objective))
+                    .then(a.duration.cmp(&b.duration))
+            });
+            candidates.truncate(self.beam_width);
+            beam = candidates
+                .into_iter()
+                .map(|candidate| {
+                    let backtrack_index =
+                        backtracking.push(candidate.action, candidate.parent_index);
+                    (candidate.states, candidate.duration, backtrack_index)
+                })
+                .collect();
+        }
+
+        let (_, _, trace_index) = solution?;
+        let actions: Vec<Action> = backtracking.get(trace_index).collect();
+        let qualities = self
+            .members
+            .iter()
+            .map(|member| {
+                let mut state = SimulationState::new(&member.settings);
+                for action in actions.iter() {
+                    if state.missing_progress == 0 {
+                        break;
+                    }
+                    state = InProgress::try_from(state)
+                        .unwrap()
+                        .use_action(*action, Condition::Normal, &member.settings)
+                        .unwrap();
+                }
+                member.settings.max_quality - state.get_missing_quality()
+            })
+            .collect();
+        Some(MultiSettingsSolution { actions, qualities })
+    }
+}
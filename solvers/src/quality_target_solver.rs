@@ -0,0 +1,89 @@
+use simulator::{state::InProgress, Action, Settings, SimulationState};
+
+use crate::{MacroSolver, SearchStats, UpperBoundSolver};
+
+type ProgressCallback<'a> = dyn Fn(&[Action]) + 'a;
+
+/// Solves for the highest reachable of a set of Quality targets.
+/// Useful when Quality in between two targets is worthless, e.g. for the breakpoints of HQ%.
+pub struct QualityTargetSolver<'a> {
+    settings: Settings,
+    /// Sorted in ascending order. Only contains targets that are greater than the initial Quality.
+    targets: Vec<u16>,
+    progress_callback: Box<ProgressCallback<'a>>,
+    memory_limit: Option<usize>,
+    stats: Vec<SearchStats>,
+}
+
+impl<'a> QualityTargetSolver<'a> {
+    /// Targets above `settings.max_quality` are ignored.
+    pub fn new(
+        settings: Settings,
+        targets: &[u16],
+        callback: Box<ProgressCallback<'a>>,
+    ) -> QualityTargetSolver<'a> {
+        let mut targets: Vec<u16> = targets
+            .iter()
+            .copied()
+            .filter(|&target| target > settings.initial_quality && target <= settings.max_quality)
+            .collect();
+        targets.sort_unstable();
+        targets.dedup();
+        QualityTargetSolver {
+            settings,
+            targets,
+            progress_callback: callback,
+            memory_limit: None,
+            stats: Vec::new(),
+        }
+    }
+
+    /// See `MacroSolver::with_memory_limit`.
+    pub fn with_memory_limit(mut self, bytes: usize) -> Self {
+        self.memory_limit = Some(bytes);
+        self
+    }
+
+    /// Statistics of the `MacroSolver` runs of the last solve, one per target that was tried, in order.
+    pub fn search_stats(&self) -> &[SearchStats] {
+        &self.stats
+    }
+
+    /// Returns the highest reachable target together with the shortest list of Actions the solver finds for it.
+    /// The initial Quality is returned as the target if none of the targets is reachable.
+    /// Returns `None` if the state cannot be completed (i.e. cannot max out Progress).
+    pub fn solve(&mut self, backload_progress: bool) -> Option<(u16, Vec<Action>)> {
+        self.stats.clear();
+        let initial_state = InProgress::new(&self.settings);
+        let mut quality_bound =
+            UpperBoundSolver::new(self.settings).quality_upper_bound(initial_state);
+        loop {
+            let target = self
+                .targets
+                .iter()
+                .rev()
+                .copied()
+                .find(|&target| target <= quality_bound)
+                .unwrap_or(self.settings.initial_quality);
+            let settings = Settings {
+                max_quality: target,
+                ..self.settings
+            };
+            let callback = |actions: &[Action]| (self.progress_callback)(actions);
+            let mut solver = MacroSolver::new(settings, Box::new(callback));
+            if let Some(memory_limit) = self.memory_limit {
+                solver = solver.with_memory_limit(memory_limit);
+            }
+            let actions = solver.solve(InProgress::new(&settings), backload_progress);
+            self.stats.push(solver.search_stats());
+            let actions = actions?;
+            let state = SimulationState::from_macro(&settings, &actions).ok()?;
+            let quality = settings.max_quality - state.get_missing_quality();
+            if quality >= target {
+                return Some((target, actions));
+            }
+            // the target is not reachable, so try the highest target below the reached Quality
+            quality_bound = quality;
+        }
+    }
+}
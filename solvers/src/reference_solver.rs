@@ -0,0 +1,75 @@
+use rustc_hash::FxHashMap as HashMap;
+use simulator::{state::InProgress, Action, Condition, Settings, SimulationState};
+
+/// Exhaustive search over every sequence of allowed actions, memoized on the full simulation state.
+/// Only feasible for tiny recipes with low CP and durability.
+/// Meant as a reference to verify the results of the other solvers, not for actual use.
+pub struct ReferenceSolver {
+    settings: Settings,
+    // Best final Quality from each state, None if Progress cannot be maxed out.
+    solved_states: HashMap<SimulationState, Option<u16>>,
+}
+
+impl ReferenceSolver {
+    pub fn new(settings: Settings) -> Self {
+        Self {
+            settings,
+            solved_states: HashMap::default(),
+        }
+    }
+
+    /// Returns the maximum Quality of a completed craft that starts from this state.
+    /// Returns `None` if the state cannot be completed (i.e. cannot max out Progress).
+    pub fn max_quality(&mut self, state: InProgress) -> Option<u16> {
+        if let Some(result) = self.solved_states.get(state.raw_state()) {
+            return *result;
+        }
+        let mut best: Option<u16> = None;
+        for action in self.settings.allowed_actions.actions_iter() {
+            let Ok(new_state) = state.use_action(action, Condition::Normal, &self.settings) else {
+                continue;
+            };
+            let quality = match InProgress::try_from(new_state) {
+                Ok(in_progress) => self.max_quality(in_progress),
+                Err(_) if new_state.missing_progress == 0 => {
+                    Some(self.settings.max_quality - new_state.get_missing_quality())
+                }
+                Err(_) => None,
+            };
+            best = std::cmp::max(best, quality);
+        }
+        self.solved_states.insert(*state.raw_state(), best);
+        best
+    }
+
+    /// Returns a list of Actions that reaches the maximum Quality.
+    /// Unlike `MacroSolver`, the list is not necessarily short.
+    pub fn solve(&mut self, mut state: InProgress) -> Option<Vec<Action>> {
+        let target = self.max_quality(state)?;
+        let settings = self.settings;
+        let mut actions = Vec::new();
+        'steps: loop {
+            for action in settings.allowed_actions.actions_iter() {
+                let Ok(new_state) = state.use_action(action, Condition::Normal, &settings) else {
+                    continue;
+                };
+                match InProgress::try_from(new_state) {
+                    Ok(in_progress) if self.max_quality(in_progress) == Some(target) => {
+                        actions.push(action);
+                        state = in_progress;
+                        continue 'steps;
+                    }
+                    Err(_)
+                        if new_state.missing_progress == 0
+                            && settings.max_quality - new_state.get_missing_quality() == target =>
+                    {
+                        actions.push(action);
+                        return Some(actions);
+                    }
+                    _ => (),
+                }
+            }
+            unreachable!("the best Quality of a state is always reached by one of its children");
+        }
+    }
+}
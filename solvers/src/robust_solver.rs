@@ -0,0 +1,189 @@
+use pareto_front::{Dominate, ParetoFront};
+use radix_heap::RadixHeapMap;
+use rustc_hash::FxHashMap;
+use simulator::{
+    state::InProgress, Action, AdversarialModel, AdversarialState, ComboAction, Effects, Settings,
+};
+
+use crate::actions::{FULL_SEARCH_ACTIONS, PROGRESS_SEARCH_ACTIONS};
+use crate::macro_solver::Score;
+use crate::utils::Backtracking;
+use crate::{FinishSolver, UpperBoundSolver};
+
+#[derive(Clone, Copy)]
+struct Value {
+    cp: i16,
+    inner_quiet: u8,
+    state: AdversarialState,
+}
+
+impl Dominate for Value {
+    fn dominate(&self, other: &Self) -> bool {
+        self.cp >= other.cp
+            && self.inner_quiet >= other.inner_quiet
+            && self.state.quality_dominates(&other.state)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct Key {
+    durability: i8,
+    missing_progress: u16,
+    effects: Effects,
+    combo: Option<ComboAction>,
+}
+
+#[derive(Default)]
+struct ParetoSet {
+    buckets: FxHashMap<Key, ParetoFront<Value>>,
+}
+
+impl ParetoSet {
+    fn insert(&mut self, state: AdversarialState) -> bool {
+        let key = Key {
+            durability: state.state.durability,
+            missing_progress: state.state.missing_progress,
+            effects: state.state.effects.with_inner_quiet(0),
+            combo: state.state.combo,
+        };
+        self.buckets.entry(key).or_default().push(Value {
+            cp: state.state.cp,
+            inner_quiet: state.state.effects.inner_quiet(),
+            state,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SearchNode {
+    state: AdversarialState,
+    backtrack_index: u32,
+}
+
+type ProgressCallback<'a> = dyn Fn(&[Action]) + 'a;
+
+/// Maximizes the Quality that is guaranteed under every condition sequence of an `AdversarialModel`.
+/// `MacroSolver` with `Settings::adversarial` is much faster for the default model.
+pub struct RobustSolver<'a> {
+    settings: Settings,
+    model: AdversarialModel,
+    finish_solver: FinishSolver,
+    bound_solver: UpperBoundSolver,
+    progress_callback: Box<ProgressCallback<'a>>,
+}
+
+impl<'a> RobustSolver<'a> {
+    /// `settings.adversarial` is ignored, the model takes its place.
+    pub fn new(
+        settings: Settings,
+        model: AdversarialModel,
+        callback: Box<ProgressCallback<'a>>,
+    ) -> RobustSolver<'a> {
+        let settings = Settings {
+            adversarial: false,
+            ..settings
+        };
+        RobustSolver {
+            settings,
+            model,
+            finish_solver: FinishSolver::new(settings),
+            bound_solver: UpperBoundSolver::new(settings),
+            progress_callback: callback,
+        }
+    }
+
+    /// Returns a list of Actions that maximizes the guaranteed Quality of the completed state.
+    /// Returns `None` if the state cannot be completed (i.e. cannot max out Progress).
+    pub fn solve(&mut self, backload_progress: bool) -> Option<Vec<Action>> {
+        let state = AdversarialState::new(&self.settings, &self.model);
+        if !self
+            .finish_solver
+            .can_finish(&InProgress::new(&self.settings))
+        {
+            return None;
+        }
+
+        let mut pareto_set = ParetoSet::default();
+        let mut search_queue: RadixHeapMap<Score, SearchNode> = RadixHeapMap::new();
+        let mut backtracking: Backtracking<Action> = Backtracking::new();
+
+        let mut quality_lower_bound = 0;
+        let mut solution: Option<(Score, u32)> = None; // (quality, trace_index)
+
+        // Quality under Normal conditions is an upper bound because every model allows only Normal conditions
+        search_queue.push(
+            Score::new(
+                self.bound_solver
+                    .quality_upper_bound(InProgress::new(&self.settings)),
+                0,
+                0,
+            ),
+            SearchNode {
+                state,
+                backtrack_index: Backtracking::<Action>::SENTINEL,
+            },
+        );
+
+        while let Some((score, node)) = search_queue.pop() {
+            if score.quality < quality_lower_bound {
+                break;
+            }
+            if solution.is_some() && score <= solution.unwrap().0 {
+                break;
+            }
+            let search_actions = match backload_progress
+                && node.state.state.missing_progress != self.settings.max_progress
+            {
+                true => PROGRESS_SEARCH_ACTIONS.intersection(self.settings.allowed_actions),
+                false => FULL_SEARCH_ACTIONS.intersection(self.settings.allowed_actions),
+            };
+            for action in search_actions.actions_iter() {
+                let Ok(state) = node.state.use_action(action, &self.settings, &self.model) else {
+                    continue;
+                };
+                let quality = self.settings.max_quality - state.get_missing_quality();
+                if let Ok(in_progress) = InProgress::try_from(state.state) {
+                    // skip this state if it is impossible to max out Progress
+                    if !self.finish_solver.can_finish(&in_progress) {
+                        continue;
+                    }
+                    // skip this state if its Quality upper bound is not greater than the current best Quality
+                    let quality_upper_bound = self.bound_solver.quality_upper_bound(in_progress);
+                    if quality_upper_bound < quality_lower_bound {
+                        continue;
+                    }
+                    // skip this state if it is Pareto-dominated
+                    if !pareto_set.insert(state) {
+                        continue;
+                    }
+
+                    let duration = score.duration + action.time_cost() as u8;
+                    let backtrack_index = backtracking.push(action, node.backtrack_index);
+                    search_queue.push(
+                        Score::new(quality_upper_bound, duration, score.steps + 1),
+                        SearchNode {
+                            state,
+                            backtrack_index,
+                        },
+                    );
+                    // finishing the craft never decreases the guaranteed Quality
+                    quality_lower_bound = std::cmp::max(quality_lower_bound, quality);
+                } else if state.state.missing_progress == 0 {
+                    let final_score = Score::new(
+                        quality,
+                        score.duration + action.time_cost() as u8,
+                        score.steps + 1,
+                    );
+                    if solution.is_none() || solution.unwrap().0 < final_score {
+                        let backtrack_index = backtracking.push(action, node.backtrack_index);
+                        solution = Some((final_score, backtrack_index));
+                        let actions: Vec<Action> = backtracking.get(backtrack_index).collect();
+                        (self.progress_callback)(&actions);
+                    }
+                }
+            }
+        }
+
+        solution.map(|(_, trace_index)| backtracking.get(trace_index).collect())
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
     actions::{PROGRESS_ACTIONS, QUALITY_ACTIONS},
-    utils::{ParetoFrontBuilder, ParetoValue},
+    utils::{hash_map_memory_usage, ParetoFrontBuilder, ParetoValue},
 };
 use simulator::{state::InProgress, Action, ActionMask, Condition, Settings};
 
@@ -12,11 +12,38 @@ const SEARCH_ACTIONS: ActionMask = PROGRESS_ACTIONS
     .union(QUALITY_ACTIONS)
     .add(Action::TrainedPerfection);
 
-pub struct UpperBoundSolver {
-    settings: Settings,
+/// Relaxation of the durability constraint in which durability is bought with CP at a fixed price.
+/// Any price that is not higher than the actual price of restoring durability gives a valid upper bound,
+/// so the minimum over several prices is a valid upper bound as well.
+struct DurabilityRelaxation {
+    /// CP cost of 5 durability.
     base_durability_cost: i16,
+    /// CP cost of a single step of Waste Not.
     waste_not_cost: i16,
     solved_states: HashMap<ReducedState, Box<[ParetoValue<u16, u16>]>>,
+    // total number of values in all solved Pareto fronts
+    pareto_values: usize,
+}
+
+impl DurabilityRelaxation {
+    fn new(base_durability_cost: i16, waste_not_cost: i16) -> Self {
+        Self {
+            base_durability_cost,
+            waste_not_cost,
+            solved_states: HashMap::default(),
+            pareto_values: 0,
+        }
+    }
+}
+
+pub struct UpperBoundSolver {
+    settings: Settings,
+    // The first relaxation prices durability as high as possible, which is tight when durability is scarce.
+    // The second one halves the prices, which is tighter when durability is plentiful,
+    // because the first one converts all of the remaining durability into CP at the full price.
+    relaxations: [DurabilityRelaxation; 2],
+    // number of relaxations that are used to compute the bound
+    relaxation_count: usize,
     pareto_front_builder: ParetoFrontBuilder<u16, u16>,
 }
 
@@ -34,15 +61,21 @@ impl UpperBoundSolver {
                 Action::ImmaculateMend.cp_cost() / (settings.max_durability as i16 / 5 - 1),
             );
         }
+        let waste_not_cost = if settings.allowed_actions.has(Action::WasteNot2) {
+            Action::WasteNot2.cp_cost() / 8
+        } else {
+            Action::WasteNot.cp_cost() / 4
+        };
         UpperBoundSolver {
             settings,
-            base_durability_cost: durability_cost,
-            waste_not_cost: if settings.allowed_actions.has(Action::WasteNot2) {
-                Action::WasteNot2.cp_cost() / 8
-            } else {
-                Action::WasteNot.cp_cost() / 4
-            },
-            solved_states: HashMap::default(),
+            relaxations: [
+                DurabilityRelaxation::new(durability_cost, waste_not_cost),
+                DurabilityRelaxation::new(
+                    std::cmp::max(1, durability_cost / 2),
+                    waste_not_cost / 2,
+                ),
+            ],
+            relaxation_count: 2,
             pareto_front_builder: ParetoFrontBuilder::new(
                 settings.max_progress,
                 settings.max_quality,
@@ -50,34 +83,79 @@ impl UpperBoundSolver {
         }
     }
 
+    /// Only uses the first relaxation, i.e. a single durability price.
+    /// This is not exactly the bound that was used before the second relaxation was added,
+    /// because the remaining Manipulation steps are now refunded at the durability price of the relaxation
+    /// instead of `Manipulation.cp_cost() / 8`, which is never lower and therefore gives a looser bound.
+    /// Only exposed for the tests that compare both bounds.
+    #[doc(hidden)]
+    pub fn with_single_durability_price(mut self) -> Self {
+        self.relaxation_count = 1;
+        self
+    }
+
+    /// Approximate number of bytes used by the solved states.
+    pub fn memory_usage(&self) -> usize {
+        self.relaxations
+            .iter()
+            .map(|relaxation| {
+                hash_map_memory_usage(&relaxation.solved_states)
+                    + relaxation.pareto_values * std::mem::size_of::<ParetoValue<u16, u16>>()
+            })
+            .sum()
+    }
+
+    /// Frees the memory of all solved states. They are solved again when needed.
+    pub fn clear_cache(&mut self) {
+        for relaxation in self.relaxations.iter_mut() {
+            relaxation.solved_states = HashMap::default();
+            relaxation.pareto_values = 0;
+        }
+    }
+
     /// Returns an upper-bound on the maximum Quality achievable from this state while also maxing out Progress.
     /// The returned upper-bound is clamped to settings.max_quality.
     /// There is no guarantee on the tightness of the upper-bound.
     pub fn quality_upper_bound(&mut self, state: InProgress) -> u16 {
-        let mut state = *state.raw_state();
-        let current_quality = self.settings.max_quality - state.get_missing_quality();
-
+        let current_quality = self.settings.max_quality - state.raw_state().get_missing_quality();
         if current_quality == self.settings.max_quality {
             return current_quality;
         }
+        (0..self.relaxation_count)
+            .map(|index| self.relaxed_upper_bound(index, state, current_quality))
+            .min()
+            .unwrap()
+    }
+
+    fn relaxed_upper_bound(
+        &mut self,
+        index: usize,
+        state: InProgress,
+        current_quality: u16,
+    ) -> u16 {
+        let mut state = *state.raw_state();
+        let relaxation = &self.relaxations[index];
 
         // refund effects and durability
-        state.cp += state.effects.manipulation() as i16 * (Action::Manipulation.cp_cost() / 8);
-        state.cp += state.effects.waste_not() as i16 * self.waste_not_cost;
-        state.cp += state.durability as i16 / 5 * self.base_durability_cost;
+        state.cp += state.effects.manipulation() as i16 * relaxation.base_durability_cost;
+        state.cp += state.effects.waste_not() as i16 * relaxation.waste_not_cost;
+        state.cp += state.durability as i16 / 5 * relaxation.base_durability_cost;
         state.durability = i8::MAX;
 
         let reduced_state = ReducedState::from_state(
             InProgress::try_from(state).unwrap(),
-            self.base_durability_cost,
-            self.waste_not_cost,
+            relaxation.base_durability_cost,
+            relaxation.waste_not_cost,
         );
 
-        if !self.solved_states.contains_key(&reduced_state) {
-            self.solve_state(reduced_state);
+        if !relaxation.solved_states.contains_key(&reduced_state) {
+            self.solve_state(index, reduced_state);
             self.pareto_front_builder.clear();
         }
-        let pareto_front = self.solved_states.get(&reduced_state).unwrap();
+        let pareto_front = self.relaxations[index]
+            .solved_states
+            .get(&reduced_state)
+            .unwrap();
 
         match pareto_front.first() {
             Some(first_element) => {
@@ -105,13 +183,13 @@ impl UpperBoundSolver {
         )
     }
 
-    fn solve_state(&mut self, state: ReducedState) {
+    fn solve_state(&mut self, index: usize, state: ReducedState) {
         self.pareto_front_builder.push_empty();
         for action in SEARCH_ACTIONS
             .intersection(self.settings.allowed_actions)
             .actions_iter()
         {
-            self.build_child_front(state, action);
+            self.build_child_front(index, state, action);
             if self.pareto_front_builder.is_max() {
                 // stop early if both Progress and Quality are maxed out
                 // this optimization would work even better with better action ordering
@@ -120,31 +198,34 @@ impl UpperBoundSolver {
             }
         }
         let pareto_front = self.pareto_front_builder.peek().unwrap();
-        self.solved_states.insert(state, pareto_front);
+        let relaxation = &mut self.relaxations[index];
+        relaxation.pareto_values += pareto_front.len();
+        relaxation.solved_states.insert(state, pareto_front);
     }
 
-    fn build_child_front(&mut self, state: ReducedState, action: Action) {
+    fn build_child_front(&mut self, index: usize, state: ReducedState, action: Action) {
         if let Ok(new_state) =
             InProgress::from(state).use_action(action, Condition::Normal, &self.settings)
         {
             if let Ok(in_progress) = InProgress::try_from(new_state) {
                 let action_progress = u16::MAX - new_state.missing_progress;
                 let action_quality = u16::MAX - new_state.get_missing_quality();
+                let base_durability_cost = self.relaxations[index].base_durability_cost;
                 let new_state = ReducedState::from_state(
                     in_progress,
-                    self.base_durability_cost,
-                    self.waste_not_cost,
+                    base_durability_cost,
+                    self.relaxations[index].waste_not_cost,
                 );
                 if new_state.cp > 0 {
-                    match self.solved_states.get(&new_state) {
+                    match self.relaxations[index].solved_states.get(&new_state) {
                         Some(pareto_front) => self.pareto_front_builder.push(pareto_front),
-                        None => self.solve_state(new_state),
+                        None => self.solve_state(index, new_state),
                     }
                     self.pareto_front_builder
                         .add(action_progress, action_quality);
                     self.pareto_front_builder.merge();
                 }
-                if new_state.cp + self.base_durability_cost >= 0 && action_progress != 0 {
+                if new_state.cp + base_durability_cost >= 0 && action_progress != 0 {
                     // "durability" must not go lower than -5
                     // last action must be a progress increase
                     self.pareto_front_builder.push(&[ParetoValue::new(0, 0)]);
@@ -160,7 +241,7 @@ impl UpperBoundSolver {
 #[cfg(test)]
 mod tests {
     use rand::Rng;
-    use simulator::{ComboAction, Effects, SimulationState};
+    use simulator::{ComboAction, Effects, GameVersion, SimulationState};
 
     use super::*;
 
@@ -184,6 +265,7 @@ mod tests {
             job_level: 90,
             allowed_actions: ActionMask::from_level(90, true, false),
             adversarial: false,
+            game_version: GameVersion::Dawntrail,
         };
         let result = solve(
             settings,
@@ -199,7 +281,7 @@ mod tests {
                 Action::PreparatoryTouch,
             ],
         );
-        assert_eq!(result, 3485);
+        assert_eq!(result, 3370);
     }
 
     #[test]
@@ -215,6 +297,7 @@ mod tests {
             job_level: 90,
             allowed_actions: ActionMask::from_level(90, true, false),
             adversarial: true,
+            game_version: GameVersion::Dawntrail,
         };
         let result = solve(
             settings,
@@ -230,7 +313,7 @@ mod tests {
                 Action::PreparatoryTouch,
             ],
         );
-        assert_eq!(result, 3375);
+        assert_eq!(result, 3260);
     }
 
     #[test]
@@ -246,6 +329,7 @@ mod tests {
             job_level: 90,
             allowed_actions: ActionMask::from_level(90, true, false),
             adversarial: false,
+            game_version: GameVersion::Dawntrail,
         };
         let result = solve(
             settings,
@@ -274,6 +358,7 @@ mod tests {
             job_level: 90,
             allowed_actions: ActionMask::from_level(90, true, false),
             adversarial: true,
+            game_version: GameVersion::Dawntrail,
         };
         let result = solve(
             settings,
@@ -302,6 +387,7 @@ mod tests {
             job_level: 90,
             allowed_actions: ActionMask::from_level(90, true, false),
             adversarial: false,
+            game_version: GameVersion::Dawntrail,
         };
         let result = solve(
             settings,
@@ -335,6 +421,7 @@ mod tests {
             job_level: 90,
             allowed_actions: ActionMask::from_level(90, true, false),
             adversarial: true,
+            game_version: GameVersion::Dawntrail,
         };
         let result = solve(
             settings,
@@ -368,6 +455,7 @@ mod tests {
             job_level: 90,
             allowed_actions: ActionMask::from_level(90, true, false),
             adversarial: false,
+            game_version: GameVersion::Dawntrail,
         };
         let result = solve(settings, &[Action::MuscleMemory]);
         assert_eq!(result, 2220);
@@ -386,6 +474,7 @@ mod tests {
             job_level: 90,
             allowed_actions: ActionMask::from_level(90, true, false),
             adversarial: true,
+            game_version: GameVersion::Dawntrail,
         };
         let result = solve(settings, &[Action::MuscleMemory]);
         assert_eq!(result, 2220);
@@ -404,6 +493,7 @@ mod tests {
             job_level: 90,
             allowed_actions: ActionMask::from_level(90, true, false),
             adversarial: false,
+            game_version: GameVersion::Dawntrail,
         };
         let result = solve(settings, &[Action::MuscleMemory]);
         assert_eq!(result, 2000);
@@ -422,6 +512,7 @@ mod tests {
             job_level: 90,
             allowed_actions: ActionMask::from_level(90, true, false),
             adversarial: true,
+            game_version: GameVersion::Dawntrail,
         };
         let result = solve(settings, &[Action::MuscleMemory]);
         assert_eq!(result, 2000);
@@ -440,6 +531,7 @@ mod tests {
             job_level: 90,
             allowed_actions: ActionMask::from_level(90, true, false),
             adversarial: false,
+            game_version: GameVersion::Dawntrail,
         };
         let result = solve(settings, &[Action::MuscleMemory]);
         assert_eq!(result, 4555);
@@ -458,6 +550,7 @@ mod tests {
             job_level: 90,
             allowed_actions: ActionMask::from_level(90, true, false),
             adversarial: true,
+            game_version: GameVersion::Dawntrail,
         };
         let result = solve(settings, &[Action::MuscleMemory]);
         assert_eq!(result, 4555);
@@ -476,6 +569,7 @@ mod tests {
             job_level: 90,
             allowed_actions: ActionMask::from_level(90, true, false),
             adversarial: false,
+            game_version: GameVersion::Dawntrail,
         };
         let result = solve(settings, &[Action::Reflect]);
         assert_eq!(result, 4633);
@@ -494,6 +588,7 @@ mod tests {
             job_level: 90,
             allowed_actions: ActionMask::from_level(90, true, false),
             adversarial: false,
+            game_version: GameVersion::Dawntrail,
         };
         let result = solve(settings, &[Action::PrudentTouch]);
         assert_eq!(result, 10000);
@@ -512,6 +607,7 @@ mod tests {
             job_level: 90,
             allowed_actions: ActionMask::from_level(90, false, false),
             adversarial: false,
+            game_version: GameVersion::Dawntrail,
         };
         let result = solve(settings, &[]);
         assert_eq!(result, 4823);
@@ -530,6 +626,7 @@ mod tests {
             job_level: 100,
             allowed_actions: ActionMask::from_level(100, false, false),
             adversarial: false,
+            game_version: GameVersion::Dawntrail,
         };
         let result = solve(settings, &[]);
         assert_eq!(result, 4269);
@@ -548,6 +645,7 @@ mod tests {
             job_level: 100,
             allowed_actions: ActionMask::from_level(100, false, false),
             adversarial: false,
+            game_version: GameVersion::Dawntrail,
         };
         let result = solve(settings, &[]);
         assert_eq!(result, 2986);
@@ -566,6 +664,7 @@ mod tests {
             job_level: 100,
             allowed_actions: ActionMask::from_level(100, false, true),
             adversarial: false,
+            game_version: GameVersion::Dawntrail,
         };
         let result = solve(settings, &[]);
         assert_eq!(result, 24000);
@@ -644,6 +743,7 @@ mod tests {
             job_level: 100,
             allowed_actions: ActionMask::from_level(100, true, false),
             adversarial: false,
+            game_version: GameVersion::Dawntrail,
         };
         monotonic_fuzz_check(settings);
     }
@@ -661,6 +761,7 @@ mod tests {
             job_level: 100,
             allowed_actions: ActionMask::from_level(100, true, false),
             adversarial: true,
+            game_version: GameVersion::Dawntrail,
         };
         monotonic_fuzz_check(settings);
     }
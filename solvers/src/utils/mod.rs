@@ -1,6 +1,11 @@
 mod pareto_front_builder;
 pub use pareto_front_builder::{ParetoFrontBuilder, ParetoValue};
 
+/// Approximate number of bytes allocated by a hash map, assuming hashbrown's layout of one control byte per bucket.
+pub fn hash_map_memory_usage<K, V, S>(map: &std::collections::HashMap<K, V, S>) -> usize {
+    map.capacity() * (std::mem::size_of::<(K, V)>() + 1)
+}
+
 pub struct NamedTimer {
     name: &'static str,
     #[cfg(not(target_arch = "wasm32"))]
@@ -56,6 +61,18 @@ impl<T: Copy> Backtracking<T> {
         items.into_iter().rev()
     }
 
+    /// Last item of the sequence that ends at `index`.
+    pub fn last(&self, index: u32) -> Option<T> {
+        match index {
+            Self::SENTINEL => None,
+            _ => Some(self.entries[index as usize].0),
+        }
+    }
+
+    pub fn memory_usage(&self) -> usize {
+        self.entries.capacity() * std::mem::size_of::<(T, u32)>()
+    }
+
     pub fn push(&mut self, item: T, parent: u32) -> u32 {
         self.entries.push((item, parent));
         self.entries.len() as u32 - 1
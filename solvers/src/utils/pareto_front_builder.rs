@@ -286,7 +286,7 @@ where
 mod tests {
     use rand::Rng;
 
-    use simulator::{ActionMask, Settings};
+    use simulator::{ActionMask, GameVersion, Settings};
 
     use super::*;
 
@@ -301,6 +301,7 @@ mod tests {
         job_level: 90,
         allowed_actions: ActionMask::none(),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
 
     const SAMPLE_FRONT_1: &[ParetoValue<u16, u16>] = &[
@@ -1,9 +1,12 @@
-use simulator::{state::InProgress, Action, ActionMask, Condition, Settings, SimulationState};
+use simulator::{
+    state::InProgress, Action, ActionMask, Condition, GameVersion, Settings, SimulationState,
+};
 use solvers::MacroSolver;
 
 fn solve(settings: &Settings, backload_progress: bool) -> Option<Vec<Action>> {
     assert!(settings.adversarial); // Ensure that non-adversarial tests are in a different file.
-    MacroSolver::new(settings.clone(), Box::new(|_| {})).solve(InProgress::new(settings), backload_progress)
+    MacroSolver::new(settings.clone(), Box::new(|_| {}))
+        .solve(InProgress::new(settings), backload_progress)
 }
 
 fn get_quality(settings: &Settings, actions: &[Action]) -> u16 {
@@ -31,7 +34,7 @@ fn is_progress_backloaded(actions: &[Action]) -> bool {
     !actions
         .into_iter()
         .skip(first_progress_action)
-        .any(|action| action.quality_efficiency(10) != 0)
+        .any(|action| action.quality_efficiency(10, GameVersion::Dawntrail) != 0)
 }
 
 const SETTINGS: Settings = Settings {
@@ -45,6 +48,7 @@ const SETTINGS: Settings = Settings {
     job_level: 100,
     allowed_actions: ActionMask::all().remove(Action::TrainedEye),
     adversarial: true,
+    game_version: GameVersion::Dawntrail,
 };
 
 #[test]
@@ -74,6 +78,7 @@ fn test_random_1e281667() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: true,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 2983);
@@ -94,6 +99,7 @@ fn test_random_d0bf2aef() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: true,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 3159);
@@ -114,6 +120,7 @@ fn test_unsolvable() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: true,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false);
     assert_eq!(actions, None);
@@ -132,6 +139,7 @@ fn test_max_quality() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: true,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 1000);
@@ -152,6 +160,7 @@ fn test_zero_quality() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: true,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 0);
@@ -172,6 +181,7 @@ fn test_random_e413e05d() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: true,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 1908);
@@ -192,6 +202,7 @@ fn test_random_bb38a037() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: true,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 2559);
@@ -212,6 +223,7 @@ fn test_backload_random_bb38a037() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: true,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, true).unwrap();
     assert!(is_progress_backloaded(&actions));
@@ -235,6 +247,7 @@ fn test_random_a300ca2b() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: true,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 3973);
@@ -255,6 +268,7 @@ fn test_random_0f9d7781() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: true,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 2530);
@@ -277,6 +291,7 @@ fn test_random_e451d981() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: true,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 4547);
@@ -297,6 +312,7 @@ fn test_random_6799bb1d() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: true,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 3004);
@@ -319,6 +335,7 @@ fn test_random_940b4755() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: true,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 3761);
@@ -341,6 +358,7 @@ fn test_rinascita_3700_3280() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: true,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 9254);
@@ -363,6 +381,7 @@ fn test_pactmaker_3240_3130() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: true,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 7494);
@@ -383,6 +402,7 @@ fn test_backload_pactmaker_3240_3130() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: true,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, true).unwrap();
     assert!(is_progress_backloaded(&actions));
@@ -406,6 +426,7 @@ fn test_diadochos_4021_3660() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: true,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 8489);
@@ -428,6 +449,7 @@ fn test_indagator_3858_4057() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: true,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 10675);
@@ -448,6 +470,7 @@ fn test_random_2ea6c001() {
         job_level: 100,
         allowed_actions: ActionMask::from_level(100, true, false),
         adversarial: true,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 10600);
@@ -470,6 +493,7 @@ fn test_random_48ae7c9f() {
         job_level: 100,
         allowed_actions: ActionMask::from_level(100, true, false),
         adversarial: true,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 17236);
@@ -492,6 +516,7 @@ fn test_backload_random_48ae7c9f() {
         job_level: 100,
         allowed_actions: ActionMask::from_level(100, true, false),
         adversarial: true,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, true).unwrap();
     assert!(is_progress_backloaded(&actions));
@@ -515,6 +540,7 @@ fn test_max_quality_indagator_3858_4057() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: true,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 11377);
@@ -535,6 +561,7 @@ fn test_random_4ecd54c4() {
         job_level: 100,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: true,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 2759);
@@ -555,6 +582,7 @@ fn test_backload_random_4ecd54c4() {
         job_level: 100,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: true,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, true).unwrap();
     assert!(is_progress_backloaded(&actions));
@@ -578,6 +606,7 @@ fn test_trained_eye() {
         job_level: 100,
         allowed_actions: ActionMask::from_level(100, true, true),
         adversarial: true,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, true).unwrap();
     assert!(is_progress_backloaded(&actions));
@@ -601,6 +630,7 @@ fn test_rare_tacos() {
         job_level: 100,
         allowed_actions: ActionMask::from_level(100, true, false),
         adversarial: true,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, true).unwrap();
     assert!(is_progress_backloaded(&actions));
@@ -625,6 +655,7 @@ fn test_mountain_chromite_ingot_no_manipulation() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, false, false),
         adversarial: true,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, true).unwrap();
     assert!(is_progress_backloaded(&actions));
@@ -0,0 +1,103 @@
+use simulator::{state::InProgress, Action, ActionMask, GameVersion, Settings, SimulationState};
+use solvers::{ApproximateSolution, BeamSearchSolver, MacroSolver};
+
+fn solve(settings: &Settings, backload_progress: bool) -> Option<ApproximateSolution> {
+    BeamSearchSolver::new(*settings, BeamSearchSolver::DEFAULT_BEAM_WIDTH)
+        .solve(InProgress::new(settings), backload_progress)
+}
+
+fn get_quality(settings: &Settings, actions: &[Action]) -> u16 {
+    let state = SimulationState::from_macro(settings, actions).unwrap();
+    assert_eq!(state.missing_progress, 0);
+    settings.max_quality - state.get_missing_quality()
+}
+
+fn check_solution(settings: &Settings, backload_progress: bool) {
+    let solution = solve(settings, backload_progress).unwrap();
+    assert_eq!(get_quality(settings, &solution.actions), solution.quality);
+    let optimal_actions = MacroSolver::new(*settings, Box::new(|_| {}))
+        .solve(InProgress::new(settings), backload_progress)
+        .unwrap();
+    let optimal_quality = get_quality(settings, &optimal_actions);
+    assert!(solution.quality <= optimal_quality);
+    assert!(solution.quality_upper_bound >= optimal_quality);
+    assert_eq!(
+        solution.quality_gap(),
+        solution.quality_upper_bound - solution.quality
+    );
+}
+
+#[test]
+fn test_random_0f93c79f() {
+    let settings = Settings {
+        max_cp: 370,
+        max_durability: 60,
+        max_progress: 2000,
+        max_quality: 40000,
+        base_progress: 100,
+        base_quality: 100,
+        initial_quality: 0,
+        job_level: 90,
+        allowed_actions: ActionMask::from_level(90, true, false),
+        adversarial: false,
+        game_version: GameVersion::Dawntrail,
+    };
+    check_solution(&settings, false);
+    check_solution(&settings, true);
+}
+
+#[test]
+fn test_random_1e281667() {
+    let settings = Settings {
+        max_cp: 553,
+        max_durability: 70,
+        max_progress: 2400,
+        max_quality: 20000,
+        base_progress: 100,
+        base_quality: 100,
+        initial_quality: 0,
+        job_level: 90,
+        allowed_actions: ActionMask::from_level(90, true, false),
+        adversarial: false,
+        game_version: GameVersion::Dawntrail,
+    };
+    check_solution(&settings, false);
+}
+
+#[test]
+fn test_max_quality() {
+    let settings = Settings {
+        max_cp: 400,
+        max_durability: 60,
+        max_progress: 2000,
+        max_quality: 1000,
+        base_progress: 100,
+        base_quality: 100,
+        initial_quality: 0,
+        job_level: 90,
+        allowed_actions: ActionMask::from_level(90, true, false),
+        adversarial: false,
+        game_version: GameVersion::Dawntrail,
+    };
+    let solution = solve(&settings, false).unwrap();
+    assert_eq!(solution.quality, 1000);
+    assert_eq!(solution.quality_gap(), 0);
+}
+
+#[test]
+fn test_unsolvable() {
+    let settings = Settings {
+        max_cp: 100,
+        max_durability: 60,
+        max_progress: 4000,
+        max_quality: 1000,
+        base_progress: 100,
+        base_quality: 100,
+        initial_quality: 0,
+        job_level: 90,
+        allowed_actions: ActionMask::from_level(90, true, false),
+        adversarial: false,
+        game_version: GameVersion::Dawntrail,
+    };
+    assert!(solve(&settings, false).is_none());
+}
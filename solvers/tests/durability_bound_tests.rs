@@ -0,0 +1,101 @@
+use simulator::{
+    state::InProgress, Action, ActionMask, Condition, GameVersion, Settings, SimulationState,
+};
+use solvers::{MacroSolver, UpperBoundSolver};
+
+fn solve(settings: &Settings) -> (Vec<Action>, u16) {
+    let mut solver = MacroSolver::new(*settings, Box::new(|_| {}));
+    let actions = solver.solve(InProgress::new(settings), false).unwrap();
+    let state = SimulationState::from_macro(settings, &actions).unwrap();
+    assert_eq!(state.missing_progress, 0);
+    let quality = settings.max_quality - state.get_missing_quality();
+    (actions, quality)
+}
+
+/// Compares the bound against the bound that only uses a single durability price.
+/// The bound must be at least the optimal Quality in all states along the optimal rotation
+/// and must never be looser than the single price bound in these states and their children.
+fn check_bound(settings: &Settings, expected_quality: u16) {
+    let (actions, quality) = solve(settings);
+    assert_eq!(quality, expected_quality);
+
+    let mut bound_solver = UpperBoundSolver::new(*settings);
+    let mut single_price_solver = UpperBoundSolver::new(*settings).with_single_durability_price();
+    let mut tighter_states = 0;
+    for steps in 0..actions.len() {
+        let state =
+            InProgress::try_from(SimulationState::from_macro(settings, &actions[..steps]).unwrap())
+                .unwrap();
+        assert!(bound_solver.quality_upper_bound(state) >= quality);
+        let children = settings
+            .allowed_actions
+            .actions_iter()
+            .filter_map(|action| {
+                let child = state.use_action(action, Condition::Normal, settings).ok()?;
+                InProgress::try_from(child).ok()
+            });
+        for state in std::iter::once(state).chain(children) {
+            let bound = bound_solver.quality_upper_bound(state);
+            let single_price_bound = single_price_solver.quality_upper_bound(state);
+            assert!(bound <= single_price_bound);
+            if bound < single_price_bound {
+                tighter_states += 1;
+            }
+        }
+    }
+    assert!(tighter_states > 0);
+}
+
+#[test]
+fn test_durability_35() {
+    let settings = Settings {
+        max_cp: 500,
+        max_durability: 35,
+        max_progress: 2000,
+        max_quality: 40000,
+        base_progress: 100,
+        base_quality: 100,
+        initial_quality: 0,
+        job_level: 90,
+        allowed_actions: ActionMask::from_level(90, true, false),
+        adversarial: false,
+        game_version: GameVersion::Dawntrail,
+    };
+    check_bound(&settings, 2255);
+}
+
+#[test]
+fn test_durability_40() {
+    let settings = Settings {
+        max_cp: 600,
+        max_durability: 40,
+        max_progress: 3000,
+        max_quality: 40000,
+        base_progress: 200,
+        base_quality: 200,
+        initial_quality: 0,
+        job_level: 100,
+        allowed_actions: ActionMask::from_level(100, true, false),
+        adversarial: false,
+        game_version: GameVersion::Dawntrail,
+    };
+    check_bound(&settings, 9142);
+}
+
+#[test]
+fn test_durability_80() {
+    let settings = Settings {
+        max_cp: 370,
+        max_durability: 80,
+        max_progress: 2000,
+        max_quality: 40000,
+        base_progress: 100,
+        base_quality: 100,
+        initial_quality: 0,
+        job_level: 90,
+        allowed_actions: ActionMask::from_level(90, true, false),
+        adversarial: false,
+        game_version: GameVersion::Dawntrail,
+    };
+    check_bound(&settings, 2143);
+}
@@ -1,9 +1,12 @@
-use simulator::{state::InProgress, Action, ActionMask, Condition, Settings, SimulationState};
+use simulator::{
+    state::InProgress, Action, ActionMask, Condition, GameVersion, Settings, SimulationState,
+};
 use solvers::MacroSolver;
 
 fn solve(settings: &Settings, backload_progress: bool) -> Option<Vec<Action>> {
     assert!(!settings.adversarial); // Ensure that adversarial tests are in a different file.
-    MacroSolver::new(settings.clone(), Box::new(|_| {})).solve(InProgress::new(settings), backload_progress)
+    MacroSolver::new(settings.clone(), Box::new(|_| {}))
+        .solve(InProgress::new(settings), backload_progress)
 }
 
 fn get_quality(settings: &Settings, actions: &[Action]) -> u16 {
@@ -31,7 +34,7 @@ fn is_progress_backloaded(actions: &[Action]) -> bool {
     !actions
         .into_iter()
         .skip(first_progress_action)
-        .any(|action| action.quality_efficiency(10) != 0)
+        .any(|action| action.quality_efficiency(10, GameVersion::Dawntrail) != 0)
 }
 
 #[test]
@@ -47,6 +50,7 @@ fn test_random_0f93c79f() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 1802);
@@ -67,6 +71,7 @@ fn test_random_1e281667() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 3366);
@@ -87,6 +92,7 @@ fn test_random_d0bf2aef() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 3434);
@@ -107,6 +113,7 @@ fn test_unsolvable() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false);
     assert_eq!(actions, None);
@@ -125,6 +132,7 @@ fn test_max_quality() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 1000);
@@ -145,6 +153,7 @@ fn test_zero_quality() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 0);
@@ -165,6 +174,7 @@ fn test_random_e413e05d() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 2018);
@@ -185,6 +195,7 @@ fn test_random_bb38a037() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 2942);
@@ -205,6 +216,7 @@ fn test_backload_random_bb38a037() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, true).unwrap();
     assert!(is_progress_backloaded(&actions));
@@ -226,6 +238,7 @@ fn test_random_a300ca2b() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 4683);
@@ -246,6 +259,7 @@ fn test_random_0f9d7781() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 2939);
@@ -266,6 +280,7 @@ fn test_random_e451d981() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 5364);
@@ -286,6 +301,7 @@ fn test_random_6799bb1d() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 3321);
@@ -306,6 +322,7 @@ fn test_random_940b4755() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 4483);
@@ -326,6 +343,7 @@ fn test_rinascita_3700_3280() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 10623);
@@ -346,6 +364,7 @@ fn test_pactmaker_3240_3130() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 8912);
@@ -366,6 +385,7 @@ fn test_backload_pactmaker_3240_3130() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, true).unwrap();
     assert!(is_progress_backloaded(&actions));
@@ -387,6 +407,7 @@ fn test_diadochos_4021_3660() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 9688);
@@ -407,6 +428,7 @@ fn test_indagator_3858_4057() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 12793);
@@ -427,6 +449,7 @@ fn test_random_2ea6c001() {
         job_level: 100,
         allowed_actions: ActionMask::from_level(100, true, false),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 10600);
@@ -447,6 +470,7 @@ fn test_random_48ae7c9f() {
         job_level: 100,
         allowed_actions: ActionMask::from_level(100, true, false),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 19621);
@@ -467,6 +491,7 @@ fn test_backload_random_48ae7c9f() {
         job_level: 100,
         allowed_actions: ActionMask::from_level(100, true, false),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, true).unwrap();
     assert!(is_progress_backloaded(&actions));
@@ -488,6 +513,7 @@ fn test_max_quality_indagator_3858_4057() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 12900);
@@ -532,6 +558,7 @@ fn test_random_4ecd54c4() {
         job_level: 100,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     assert_eq!(get_quality(&settings, &actions), 3080);
@@ -552,6 +579,7 @@ fn test_backload_random_4ecd54c4() {
         job_level: 100,
         allowed_actions: ActionMask::from_level(90, true, false),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, true).unwrap();
     assert!(is_progress_backloaded(&actions));
@@ -575,6 +603,7 @@ fn test_trained_eye() {
         job_level: 100,
         allowed_actions: ActionMask::from_level(100, true, true),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, true).unwrap();
     assert!(is_progress_backloaded(&actions));
@@ -598,6 +627,7 @@ fn test_rare_tacos() {
         job_level: 100,
         allowed_actions: ActionMask::from_level(100, true, false),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, true).unwrap();
     assert!(is_progress_backloaded(&actions));
@@ -622,6 +652,7 @@ fn test_mountain_chromite_ingot_no_manipulation() {
         job_level: 90,
         allowed_actions: ActionMask::from_level(90, false, false),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, true).unwrap();
     assert!(is_progress_backloaded(&actions));
@@ -645,6 +676,7 @@ fn test_rare_tacos_2() {
         job_level: 100,
         allowed_actions: ActionMask::from_level(100, true, false),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     dbg!(actions.clone());
@@ -655,8 +687,8 @@ fn test_rare_tacos_2() {
 
 #[test]
 fn test_stuffed_peppers_2() {
-  // lv99 Rarefied Stuffed Peppers
-  // 4785 CMS, 4758 Ctrl, 646 CP
+    // lv99 Rarefied Stuffed Peppers
+    // 4785 CMS, 4758 Ctrl, 646 CP
     let settings = Settings {
         max_cp: 646,
         max_durability: 80,
@@ -668,10 +700,11 @@ fn test_stuffed_peppers_2() {
         job_level: 100,
         allowed_actions: ActionMask::from_level(100, true, false),
         adversarial: false,
+        game_version: GameVersion::Dawntrail,
     };
     let actions = solve(&settings, false).unwrap();
     dbg!(actions.clone());
     assert_eq!(get_quality(&settings, &actions), 20177);
     assert_eq!(get_duration(&actions), 85);
     assert_eq!(actions.len(), 31);
-}
\ No newline at end of file
+}
@@ -0,0 +1,63 @@
+use simulator::{state::InProgress, Action, ActionMask, GameVersion, Settings, SimulationState};
+use solvers::{MacroSolver, SearchStats};
+
+fn settings() -> Settings {
+    Settings {
+        max_cp: 370,
+        max_durability: 60,
+        max_progress: 2000,
+        max_quality: 40000,
+        base_progress: 100,
+        base_quality: 100,
+        initial_quality: 0,
+        job_level: 90,
+        allowed_actions: ActionMask::from_level(90, true, false),
+        adversarial: false,
+        game_version: GameVersion::Dawntrail,
+    }
+}
+
+fn solve(settings: &Settings, memory_limit: Option<usize>) -> (Option<Vec<Action>>, SearchStats) {
+    let mut solver = MacroSolver::new(*settings, Box::new(|_| {}));
+    if let Some(memory_limit) = memory_limit {
+        solver = solver.with_memory_limit(memory_limit);
+    }
+    let actions = solver.solve(InProgress::new(settings), false);
+    (actions, solver.search_stats())
+}
+
+fn get_quality(settings: &Settings, actions: &[Action]) -> u16 {
+    let state = SimulationState::from_macro(settings, actions).unwrap();
+    assert_eq!(state.missing_progress, 0);
+    settings.max_quality - state.get_missing_quality()
+}
+
+#[test]
+fn test_stats_without_limit() {
+    let (actions, stats) = solve(&settings(), None);
+    assert_eq!(get_quality(&settings(), &actions.unwrap()), 1802);
+    assert!(stats.expanded_nodes > 0);
+    assert!(stats.peak_memory > 0);
+    assert_eq!(stats.cache_evictions, 0);
+    assert!(!stats.memory_limit_reached);
+}
+
+#[test]
+fn test_result_stays_optimal() {
+    let (_, unlimited_stats) = solve(&settings(), None);
+    let (actions, stats) = solve(&settings(), Some(unlimited_stats.peak_memory / 2));
+    assert_eq!(get_quality(&settings(), &actions.unwrap()), 1802);
+    assert!(stats.cache_evictions > 0);
+    assert!(!stats.memory_limit_reached);
+    assert!(stats.peak_memory > 0);
+}
+
+#[test]
+fn test_limit_too_low() {
+    let (actions, stats) = solve(&settings(), Some(1));
+    assert!(stats.memory_limit_reached);
+    // the best rotation found before running out of memory is still valid
+    if let Some(actions) = actions {
+        assert!(get_quality(&settings(), &actions) <= 1802);
+    }
+}
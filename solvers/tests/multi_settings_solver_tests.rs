@@ -0,0 +1,224 @@
+use simulator::{
+    state::InProgress, Action, ActionMask, Condition, GameVersion, Settings, SimulationState,
+};
+use solvers::{
+    BeamSearchSolver, MacroSolver, MultiSettingsObjective, MultiSettingsSolution,
+    MultiSettingsSolver, UpperBoundSolver,
+};
+
+fn base_settings() -> Settings {
+    Settings {
+        max_cp: 400,
+        max_durability: 60,
+        max_progress: 2000,
+        max_quality: 8000,
+        base_progress: 100,
+        base_quality: 100,
+        initial_quality: 0,
+        job_level: 90,
+        allowed_actions: ActionMask::from_level(90, true, false),
+        adversarial: false,
+        game_version: GameVersion::Dawntrail,
+    }
+}
+
+/// Simulates the rotation like an in-game macro, which stops once the craft is completed.
+fn simulate(settings: &Settings, actions: &[Action]) -> SimulationState {
+    let mut state = SimulationState::new(settings);
+    for action in actions {
+        if state.missing_progress == 0 {
+            break;
+        }
+        state = InProgress::try_from(state)
+            .unwrap()
+            .use_action(*action, Condition::Normal, settings)
+            .unwrap();
+    }
+    state
+}
+
+fn check_solution(settings: &[Settings], solution: &MultiSettingsSolution) {
+    assert_eq!(solution.qualities.len(), settings.len());
+    for (settings, quality) in settings.iter().zip(solution.qualities.iter()) {
+        let state = simulate(settings, &solution.actions);
+        assert_eq!(state.missing_progress, 0);
+        assert_eq!(settings.max_quality - state.get_missing_quality(), *quality);
+        let upper_bound =
+            UpperBoundSolver::new(*settings).quality_upper_bound(InProgress::new(settings));
+        assert!(*quality <= upper_bound);
+    }
+}
+
+#[test]
+fn test_single_settings() {
+    let solution = MultiSettingsSolver::new(
+        &[base_settings()],
+        MultiSettingsObjective::MinimumQuality,
+        BeamSearchSolver::DEFAULT_BEAM_WIDTH,
+    )
+    .solve(false)
+    .unwrap();
+    check_solution(&[base_settings()], &solution);
+    let single = BeamSearchSolver::new(base_settings(), BeamSearchSolver::DEFAULT_BEAM_WIDTH)
+        .solve(InProgress::new(&base_settings()), false)
+        .unwrap();
+    assert_eq!(solution.qualities[0], single.quality);
+}
+
+#[test]
+fn test_stat_range() {
+    let settings = [
+        base_settings(),
+        Settings {
+            max_cp: 360,
+            base_progress: 90,
+            base_quality: 95,
+            ..base_settings()
+        },
+        Settings {
+            max_cp: 450,
+            base_quality: 110,
+            ..base_settings()
+        },
+    ];
+    let solution = MultiSettingsSolver::new(
+        &settings,
+        MultiSettingsObjective::MinimumQuality,
+        BeamSearchSolver::DEFAULT_BEAM_WIDTH,
+    )
+    .solve(false)
+    .unwrap();
+    check_solution(&settings, &solution);
+    // the crafter with the least CP and Control is the bottleneck
+    assert_eq!(
+        solution.qualities.iter().min(),
+        Some(&solution.qualities[1])
+    );
+}
+
+#[test]
+fn test_multiple_recipes() {
+    let settings = [
+        base_settings(),
+        Settings {
+            max_durability: 35,
+            max_progress: 1200,
+            max_quality: 4000,
+            ..base_settings()
+        },
+    ];
+    for objective in [
+        MultiSettingsObjective::MinimumQuality,
+        MultiSettingsObjective::PrimaryQuality(0),
+        MultiSettingsObjective::PrimaryQuality(1),
+    ] {
+        let solution =
+            MultiSettingsSolver::new(&settings, objective, BeamSearchSolver::DEFAULT_BEAM_WIDTH)
+                .solve(true)
+                .unwrap();
+        check_solution(&settings, &solution);
+    }
+}
+
+#[test]
+fn test_infeasible_member() {
+    let settings = [
+        base_settings(),
+        Settings {
+            max_progress: 10000,
+            ..base_settings()
+        },
+    ];
+    let solution = MultiSettingsSolver::new(
+        &settings,
+        MultiSettingsObjective::MinimumQuality,
+        BeamSearchSolver::DEFAULT_BEAM_WIDTH,
+    )
+    .solve(false);
+    assert!(solution.is_none());
+}
+
+#[test]
+fn test_exact() {
+    let settings = Settings {
+        max_cp: 150,
+        max_durability: 30,
+        max_progress: 600,
+        max_quality: 3000,
+        ..base_settings()
+    };
+    let solution = MultiSettingsSolver::new(
+        &[settings],
+        MultiSettingsObjective::MinimumQuality,
+        MultiSettingsSolver::EXACT,
+    )
+    .solve(false)
+    .unwrap();
+    check_solution(&[settings], &solution);
+    let actions = MacroSolver::new(settings, Box::new(|_| {}))
+        .solve(InProgress::new(&settings), false)
+        .unwrap();
+    let state = SimulationState::from_macro(&settings, &actions).unwrap();
+    assert_eq!(
+        solution.qualities[0],
+        settings.max_quality - state.get_missing_quality()
+    );
+
+    // the exact solution is at least as good as the approximate one
+    let settings = [
+        settings,
+        Settings {
+            max_cp: 130,
+            base_quality: 110,
+            ..settings
+        },
+    ];
+    let exact = MultiSettingsSolver::new(
+        &settings,
+        MultiSettingsObjective::MinimumQuality,
+        MultiSettingsSolver::EXACT,
+    )
+    .solve(false)
+    .unwrap();
+    check_solution(&settings, &exact);
+    let approximate =
+        MultiSettingsSolver::new(&settings, MultiSettingsObjective::MinimumQuality, 1)
+            .solve(false)
+            .unwrap();
+    let relative_quality = |solution: &MultiSettingsSolution| {
+        (0..settings.len())
+            .map(|index| {
+                solution.qualities[index] as u32 * 10000 / settings[index].max_quality as u32
+            })
+            .min()
+    };
+    assert!(relative_quality(&exact) >= relative_quality(&approximate));
+}
+
+#[test]
+fn test_exact_objective_comparison() {
+    // with a max Quality above 10000, Qualities that are only a few points apart are less than
+    // a hundredth of a percent apart, which must not make them equally good
+    let settings = Settings {
+        max_cp: 150,
+        max_durability: 30,
+        max_progress: 600,
+        max_quality: 30000,
+        ..base_settings()
+    };
+    let actions = MacroSolver::new(settings, Box::new(|_| {}))
+        .solve(InProgress::new(&settings), false)
+        .unwrap();
+    let state = SimulationState::from_macro(&settings, &actions).unwrap();
+    let expected_quality = settings.max_quality - state.get_missing_quality();
+    for objective in [
+        MultiSettingsObjective::MinimumQuality,
+        MultiSettingsObjective::PrimaryQuality(0),
+    ] {
+        let solution = MultiSettingsSolver::new(&[settings], objective, MultiSettingsSolver::EXACT)
+            .solve(false)
+            .unwrap();
+        check_solution(&[settings], &solution);
+        assert_eq!(solution.qualities[0], expected_quality);
+    }
+}
@@ -0,0 +1,102 @@
+use simulator::{state::InProgress, Action, ActionMask, GameVersion, Settings, SimulationState};
+use solvers::{MacroSolver, QualityTargetSolver};
+
+fn settings() -> Settings {
+    Settings {
+        max_cp: 370,
+        max_durability: 60,
+        max_progress: 2000,
+        max_quality: 40000,
+        base_progress: 100,
+        base_quality: 100,
+        initial_quality: 0,
+        job_level: 90,
+        allowed_actions: ActionMask::from_level(90, true, false),
+        adversarial: false,
+        game_version: GameVersion::Dawntrail,
+    }
+}
+
+fn solve(settings: &Settings, targets: &[u16]) -> Option<(u16, Vec<Action>)> {
+    QualityTargetSolver::new(*settings, targets, Box::new(|_| {})).solve(false)
+}
+
+fn get_quality(settings: &Settings, actions: &[Action]) -> u16 {
+    let state = SimulationState::from_macro(settings, actions).unwrap();
+    assert_eq!(state.missing_progress, 0);
+    settings.max_quality - state.get_missing_quality()
+}
+
+fn get_duration(actions: &[Action]) -> i16 {
+    actions.iter().map(|action| action.time_cost()).sum()
+}
+
+#[test]
+fn test_highest_reachable_target() {
+    // the highest reachable Quality is 1802
+    let (target, actions) = solve(&settings(), &[500, 1000, 1500, 2000, 3000]).unwrap();
+    assert_eq!(target, 1500);
+    assert!(get_quality(&settings(), &actions) >= 1500);
+    // not going for the maximum Quality saves time
+    let max_quality_actions = MacroSolver::new(settings(), Box::new(|_| {}))
+        .solve(InProgress::new(&settings()), false)
+        .unwrap();
+    assert!(get_duration(&actions) <= get_duration(&max_quality_actions));
+}
+
+#[test]
+fn test_exact_target() {
+    let (target, actions) = solve(&settings(), &[1802]).unwrap();
+    assert_eq!(target, 1802);
+    assert!(get_quality(&settings(), &actions) >= 1802);
+    let (target, _) = solve(&settings(), &[1803]).unwrap();
+    assert_eq!(target, 0);
+}
+
+#[test]
+fn test_no_targets() {
+    let (target, actions) = solve(&settings(), &[]).unwrap();
+    assert_eq!(target, 0);
+    assert_eq!(
+        SimulationState::from_macro(&settings(), &actions)
+            .unwrap()
+            .missing_progress,
+        0
+    );
+}
+
+#[test]
+fn test_initial_quality() {
+    let settings = Settings {
+        initial_quality: 1000,
+        ..settings()
+    };
+    // targets below the initial Quality are always reached
+    let (target, _) = solve(&settings, &[500, 50000]).unwrap();
+    assert_eq!(target, 1000);
+    let (target, actions) = solve(&settings, &[500, 2000, 50000]).unwrap();
+    assert_eq!(target, 2000);
+    assert!(get_quality(&settings, &actions) >= 2000);
+}
+
+#[test]
+fn test_unfinishable() {
+    let settings = Settings {
+        max_progress: 20000,
+        ..settings()
+    };
+    assert!(solve(&settings, &[1000]).is_none());
+}
+
+#[test]
+fn test_search_stats() {
+    let mut solver = QualityTargetSolver::new(settings(), &[1000, 1803], Box::new(|_| {}));
+    assert!(solver.search_stats().is_empty());
+    solver.solve(false).unwrap();
+    // 1803 is not reachable, so the full search runs for it before 1000 is tried
+    let stats = solver.search_stats();
+    assert_eq!(stats.len(), 2);
+    assert!(stats[0].expanded_nodes > 0);
+    assert!(stats[0].peak_memory > 0);
+    assert!(stats.iter().all(|stats| !stats.memory_limit_reached));
+}
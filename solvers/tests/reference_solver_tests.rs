@@ -0,0 +1,94 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use simulator::{state::InProgress, Action, ActionMask, GameVersion, Settings, SimulationState};
+use solvers::{MacroSolver, ReferenceSolver};
+
+fn random_settings(rng: &mut StdRng, adversarial: bool) -> Settings {
+    let job_level = [50, 70, 90, 100][rng.gen_range(0..4)];
+    Settings {
+        max_cp: rng.gen_range(20..=150),
+        max_durability: 5 * rng.gen_range(2..=5),
+        max_progress: rng.gen_range(100..=500),
+        max_quality: rng.gen_range(100..=2500),
+        base_progress: 100,
+        base_quality: 100,
+        initial_quality: 0,
+        job_level,
+        allowed_actions: ActionMask::from_level(job_level as u32, true, false),
+        adversarial,
+        game_version: GameVersion::Dawntrail,
+    }
+}
+
+fn get_quality(settings: &Settings, actions: &[Action]) -> u16 {
+    let state = SimulationState::from_macro(settings, actions).unwrap();
+    assert_eq!(state.missing_progress, 0);
+    settings.max_quality - state.get_missing_quality()
+}
+
+fn check_solvers(settings: &Settings) {
+    let expected = ReferenceSolver::new(*settings).max_quality(InProgress::new(settings));
+    if let Some(actions) = ReferenceSolver::new(*settings).solve(InProgress::new(settings)) {
+        assert_eq!(
+            Some(get_quality(settings, &actions)),
+            expected,
+            "{settings:?}"
+        );
+    }
+
+    let actions = MacroSolver::new(*settings, Box::new(|_| {}))
+        .solve(InProgress::new(settings), false)
+        .map(|actions| get_quality(settings, &actions));
+    assert_eq!(actions, expected, "{settings:?}");
+
+    // quick search only finds rotations that max out Quality
+    if let Some(actions) =
+        MacroSolver::new(*settings, Box::new(|_| {})).quick_search(InProgress::new(settings))
+    {
+        assert_eq!(get_quality(settings, &actions), settings.max_quality);
+        assert_eq!(expected, Some(settings.max_quality), "{settings:?}");
+    }
+}
+
+#[test]
+fn test_reference_solver() {
+    let settings = Settings {
+        max_cp: 50,
+        max_durability: 20,
+        max_progress: 240,
+        max_quality: 1000,
+        base_progress: 100,
+        base_quality: 100,
+        initial_quality: 0,
+        job_level: 100,
+        allowed_actions: ActionMask::from_level(100, true, false),
+        adversarial: false,
+        game_version: GameVersion::Dawntrail,
+    };
+    let mut solver = ReferenceSolver::new(settings);
+    // Reflect, Innovation, Prudent Touch, Trained Perfection, Basic Synthesis, Basic Synthesis
+    assert_eq!(solver.max_quality(InProgress::new(&settings)), Some(480));
+    let impossible = Settings {
+        max_progress: 5000,
+        ..settings
+    };
+    assert_eq!(
+        ReferenceSolver::new(impossible).max_quality(InProgress::new(&impossible)),
+        None
+    );
+}
+
+#[test]
+fn test_differential() {
+    let mut rng = StdRng::seed_from_u64(0x5eed);
+    for _ in 0..100 {
+        check_solvers(&random_settings(&mut rng, false));
+    }
+}
+
+#[test]
+fn test_differential_adversarial() {
+    let mut rng = StdRng::seed_from_u64(0xbad);
+    for _ in 0..100 {
+        check_solvers(&random_settings(&mut rng, true));
+    }
+}
@@ -0,0 +1,136 @@
+use simulator::{
+    state::InProgress, Action, ActionMask, AdversarialModel, AdversarialState, Condition,
+    ConditionSet, GameVersion, Settings, SimulationState,
+};
+use solvers::{MacroSolver, RobustSolver};
+
+fn settings() -> Settings {
+    Settings {
+        max_cp: 250,
+        max_durability: 60,
+        max_progress: 1200,
+        max_quality: 40000,
+        base_progress: 100,
+        base_quality: 100,
+        initial_quality: 0,
+        job_level: 90,
+        allowed_actions: ActionMask::from_level(90, true, false),
+        adversarial: false,
+        game_version: GameVersion::Dawntrail,
+    }
+}
+
+fn solve(settings: &Settings, model: AdversarialModel) -> Vec<Action> {
+    RobustSolver::new(*settings, model, Box::new(|_| {}))
+        .solve(false)
+        .unwrap()
+}
+
+fn guaranteed_quality(settings: &Settings, model: &AdversarialModel, actions: &[Action]) -> u16 {
+    let state = AdversarialState::from_macro(settings, model, actions).unwrap();
+    assert_eq!(state.state.missing_progress, 0);
+    settings.max_quality - state.get_missing_quality()
+}
+
+fn get_quality(settings: &Settings, actions: &[Action]) -> u16 {
+    let state = SimulationState::from_macro(settings, actions).unwrap();
+    assert_eq!(state.missing_progress, 0);
+    settings.max_quality - state.get_missing_quality()
+}
+
+/// Minimum Quality of all condition sequences allowed by the model, found by trying every one of them
+fn brute_force_quality(settings: &Settings, model: &AdversarialModel, actions: &[Action]) -> u16 {
+    fn search(
+        settings: &Settings,
+        model: &AdversarialModel,
+        actions: &[Action],
+        conditions: &mut Vec<Condition>,
+        state: SimulationState,
+    ) -> u16 {
+        let Some(action) = actions.get(conditions.len()) else {
+            return settings.max_quality - state.get_missing_quality();
+        };
+        let mut min_quality = u16::MAX;
+        for condition in [Condition::Normal, Condition::Excellent, Condition::Poor] {
+            conditions.push(condition);
+            if model.allows(conditions) {
+                let state = InProgress::try_from(state)
+                    .unwrap()
+                    .use_action(*action, condition, settings)
+                    .unwrap();
+                min_quality = std::cmp::min(
+                    min_quality,
+                    search(settings, model, actions, conditions, state),
+                );
+            }
+            conditions.pop();
+        }
+        min_quality
+    }
+    let state = SimulationState::new(settings);
+    search(settings, model, actions, &mut Vec::new(), state)
+}
+
+#[test]
+fn test_zero_budget() {
+    let model = AdversarialModel {
+        conditions: ConditionSet::AnyPoor,
+        max_poor: Some(0),
+    };
+    let actions = solve(&settings(), model);
+    let expected = MacroSolver::new(settings(), Box::new(|_| {}))
+        .solve(InProgress::new(&settings()), false)
+        .unwrap();
+    assert_eq!(
+        guaranteed_quality(&settings(), &model, &actions),
+        get_quality(&settings(), &expected)
+    );
+}
+
+#[test]
+fn test_unlimited_excellent_poor() {
+    // the default model is the same as the one of Settings::adversarial
+    let model = AdversarialModel::default();
+    let actions = solve(&settings(), model);
+    let adversarial_settings = Settings {
+        adversarial: true,
+        ..settings()
+    };
+    let expected = MacroSolver::new(adversarial_settings, Box::new(|_| {}))
+        .solve(InProgress::new(&adversarial_settings), false)
+        .unwrap();
+    assert_eq!(
+        guaranteed_quality(&settings(), &model, &actions),
+        get_quality(&adversarial_settings, &expected)
+    );
+}
+
+#[test]
+fn test_poor_budgets() {
+    let normal_actions = MacroSolver::new(settings(), Box::new(|_| {}))
+        .solve(InProgress::new(&settings()), false)
+        .unwrap();
+    let mut previous_quality = get_quality(&settings(), &normal_actions);
+    for conditions in [ConditionSet::AnyPoor, ConditionSet::ExcellentPoor] {
+        for max_poor in 1..=2 {
+            let model = AdversarialModel {
+                conditions,
+                max_poor: Some(max_poor),
+            };
+            let actions = solve(&settings(), model);
+            let quality = guaranteed_quality(&settings(), &model, &actions);
+            // the guarantee holds for every allowed condition sequence
+            assert_eq!(
+                quality,
+                brute_force_quality(&settings(), &model, &actions),
+                "{model:?}"
+            );
+            // the solver does at least as well as the rotation that ignores bad conditions
+            assert!(quality >= guaranteed_quality(&settings(), &model, &normal_actions));
+            if conditions == ConditionSet::AnyPoor {
+                assert!(quality <= previous_quality);
+                previous_quality = quality;
+            }
+        }
+    }
+}
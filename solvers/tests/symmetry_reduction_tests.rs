@@ -0,0 +1,112 @@
+use simulator::{state::InProgress, Action, ActionMask, GameVersion, Settings, SimulationState};
+use solvers::{MacroSolver, SearchStats};
+
+fn solve(settings: &Settings, symmetry_reduction: bool) -> (Vec<Action>, SearchStats) {
+    let mut solver = MacroSolver::new(*settings, Box::new(|_| {}));
+    if !symmetry_reduction {
+        solver = solver.without_symmetry_reduction();
+    }
+    let actions = solver.solve(InProgress::new(settings), false).unwrap();
+    (actions, solver.search_stats())
+}
+
+fn get_quality(settings: &Settings, actions: &[Action]) -> u16 {
+    let state = SimulationState::from_macro(settings, actions).unwrap();
+    assert_eq!(state.missing_progress, 0);
+    settings.max_quality - state.get_missing_quality()
+}
+
+/// Number of nodes that were evaluated by the Finish solver
+fn evaluated_nodes(stats: &SearchStats) -> usize {
+    stats.finish_solver_rejected_nodes
+        + stats.upper_bound_solver_rejected_nodes
+        + stats.pareto_dominated_nodes
+}
+
+fn check_reduction(settings: &Settings) {
+    let (actions, stats) = solve(settings, true);
+    let (expected_actions, expected_stats) = solve(settings, false);
+    assert_eq!(
+        get_quality(settings, &actions),
+        get_quality(settings, &expected_actions)
+    );
+    assert_eq!(actions.len(), expected_actions.len());
+    assert!(stats.expanded_nodes <= expected_stats.expanded_nodes);
+    assert!(evaluated_nodes(&stats) < evaluated_nodes(&expected_stats));
+    assert!(stats.transposition_nodes > 0);
+    assert!(stats.redundant_action_nodes > 0);
+}
+
+#[test]
+fn test_random_0f93c79f() {
+    let settings = Settings {
+        max_cp: 370,
+        max_durability: 60,
+        max_progress: 2000,
+        max_quality: 40000,
+        base_progress: 100,
+        base_quality: 100,
+        initial_quality: 0,
+        job_level: 90,
+        allowed_actions: ActionMask::from_level(90, true, false),
+        adversarial: false,
+        game_version: GameVersion::Dawntrail,
+    };
+    check_reduction(&settings);
+}
+
+#[test]
+fn test_random_1e281667() {
+    let settings = Settings {
+        max_cp: 553,
+        max_durability: 70,
+        max_progress: 2400,
+        max_quality: 20000,
+        base_progress: 100,
+        base_quality: 100,
+        initial_quality: 0,
+        job_level: 90,
+        allowed_actions: ActionMask::from_level(90, true, false),
+        adversarial: false,
+        game_version: GameVersion::Dawntrail,
+    };
+    check_reduction(&settings);
+}
+
+#[test]
+fn test_endwalker() {
+    // Observe doesn't start the Advanced Touch combo before 7.0, so it is always redundant
+    let settings = Settings {
+        max_cp: 370,
+        max_durability: 60,
+        max_progress: 2000,
+        max_quality: 40000,
+        base_progress: 100,
+        base_quality: 100,
+        initial_quality: 0,
+        job_level: 90,
+        allowed_actions: ActionMask::from_level(90, true, false)
+            .intersection(GameVersion::Endwalker.action_mask(90)),
+        adversarial: false,
+        game_version: GameVersion::Endwalker,
+    };
+    check_reduction(&settings);
+}
+
+#[test]
+fn test_adversarial() {
+    let settings = Settings {
+        max_cp: 450,
+        max_durability: 60,
+        max_progress: 1800,
+        max_quality: 20000,
+        base_progress: 100,
+        base_quality: 100,
+        initial_quality: 0,
+        job_level: 90,
+        allowed_actions: ActionMask::from_level(90, true, false),
+        adversarial: true,
+        game_version: GameVersion::Dawntrail,
+    };
+    check_reduction(&settings);
+}
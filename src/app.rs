@@ -1,20 +1,24 @@
 use std::cell::Cell;
+use std::collections::HashMap;
 use std::panic;
 use std::rc::Rc;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use web_time::Instant;
 
 use egui::{Align, CursorIcon, FontData, FontDefinitions, FontFamily, Layout, TextStyle};
-use game_data::{get_item_name, get_job_name, Consumable, Locale, RecipeConfiguration, ITEMS};
-use simulator::{state::InProgress, Action, Settings};
-
-use crate::{
-    config::{CrafterConfig, QualityTarget},
-    widgets::{
-        ConsumableSelect, HelpText, MacroView, MacroViewConfig, RecipeSelect, Simulator, StatsEdit,
-    },
+use game_data::{
+    get_company_craft, get_item_name, get_job_name, hq_percentage, Consumable, CrafterConfig,
+    CrafterProfile, LegacyCrafterConfig, Locale, MacroLibrary, QualityTarget, RecipeConfiguration,
+    SharedSetup, SolverConfig, ITEMS,
+};
+use simulator::{state::InProgress, Action, GameVersion, Settings};
+
+use crate::widgets::{
+    CompanyCraftView, ConsumableSelect, FeasibilityReport, FeasibilityTable, GearsetEdit, HelpText,
+    MacroView, MacroViewConfig, RecipeSelect, Simulator, StatsEdit,
 };
 
 fn load<T: DeserializeOwned>(cc: &eframe::CreationContext<'_>, key: &'static str, default: T) -> T {
@@ -24,32 +28,103 @@ fn load<T: DeserializeOwned>(cc: &eframe::CreationContext<'_>, key: &'static str
     }
 }
 
-type MacroResult = Option<(Vec<Action>, bool)>;
+/// Loads the crafter profiles, migrating the config of older versions if there are no profiles yet.
+fn load_crafter_config(
+    cc: &eframe::CreationContext<'_>,
+    food: Option<Consumable>,
+    potion: Option<Consumable>,
+) -> CrafterConfig {
+    let Some(storage) = cc.storage else {
+        return CrafterConfig::default();
+    };
+    let mut crafter_config = match eframe::get_value(storage, "CRAFTER_PROFILES") {
+        Some(crafter_config) => crafter_config,
+        None => match eframe::get_value::<LegacyCrafterConfig>(storage, "CRAFTER_CONFIG") {
+            Some(legacy) => CrafterConfig::migrate(
+                legacy,
+                food,
+                potion,
+                load(cc, "GEARSETS", Default::default()),
+            ),
+            None => CrafterConfig::default(),
+        },
+    };
+    crafter_config.normalize();
+    crafter_config
+}
 
+/// Summary of the `solvers::SearchStats` of a solve.
 #[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
-struct SolverConfig {
-    quality_target: QualityTarget,
-    backload_progress: bool,
-    adversarial: bool,
+pub struct SolverStats {
+    expanded_nodes: usize,
+    /// Highest estimated memory usage in bytes.
+    peak_memory: usize,
+    memory_limit_reached: bool,
+}
+
+impl SolverStats {
+    /// Combines the stats of multiple searches, e.g. of the runs of a `solvers::QualityTargetSolver`.
+    fn add(&mut self, stats: &solvers::SearchStats) {
+        self.expanded_nodes += stats.expanded_nodes;
+        self.peak_memory = std::cmp::max(self.peak_memory, stats.peak_memory);
+        self.memory_limit_reached |= stats.memory_limit_reached;
+    }
 }
 
+/// Update that the solver worker sends to the app.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MacroResult {
+    actions: Vec<Action>,
+    /// True while the solver is still running.
+    pending: bool,
+    /// Maximum Quality the optimal solution has over the approximate solution in `actions`.
+    quality_gap: Option<u16>,
+    /// Only set once the solver is done.
+    stats: Option<SolverStats>,
+}
+type FetchedFont = (&'static str, Vec<u8>);
+
 pub struct MacroSolverApp {
     locale: Locale,
     recipe_config: RecipeConfiguration,
     selected_food: Option<Consumable>,
     selected_potion: Option<Consumable>,
     crafter_config: CrafterConfig,
+    /// Job and profile whose consumables were last applied.
+    active_profile: (u8, usize),
     solver_config: SolverConfig,
+    /// Memory limit of the solver in MB.
+    memory_limit: Option<u32>,
     macro_view_config: MacroViewConfig,
+    macro_library: MacroLibrary,
+    /// Item id of the selected company craft.
+    company_craft: Option<u32>,
+    /// Macros solved for the supply items of the selected company craft, keyed by job id and item id.
+    company_craft_macros: HashMap<(u8, u32), Vec<Action>>,
+    feasibility_report: FeasibilityReport,
 
     custom_recipe: bool,
     recipe_search_text: String,
     food_search_text: String,
     potion_search_text: String,
+    gear_search_text: String,
+
+    fonts: FontDefinitions,
+    requested_fonts: Vec<&'static str>,
+    font_update: Arc<Mutex<Vec<FetchedFont>>>,
+
+    share_rotation: bool,
+    share_message: Option<String>,
 
     stats_edit_window_open: bool,
+    gearset_edit_window_open: bool,
+    company_craft_window_open: bool,
+    feasibility_window_open: bool,
     actions: Vec<Action>,
     solver_pending: bool,
+    /// Maximum Quality the optimal solution has over the displayed approximate solution.
+    quality_gap: Option<u16>,
+    solver_stats: Option<SolverStats>,
     start_time: Option<Instant>,
     duration: Option<Duration>,
     bridge: gloo_worker::WorkerBridge<WebWorker>,
@@ -77,52 +152,171 @@ impl MacroSolverApp {
             style.always_scroll_the_only_direction = true;
         });
 
-        Self::load_fonts(&cc.egui_ctx);
+        let fonts = Self::load_fonts(&cc.egui_ctx);
 
         let default_recipe_config = RecipeConfiguration {
             recipe: *game_data::RECIPES.last().unwrap(),
             hq_ingredients: [0; 6],
         };
 
-        Self {
+        let selected_food = load(cc, "SELECTED_FOOD", None);
+        let selected_potion = load(cc, "SELECTED_POTION", None);
+        let crafter_config = load_crafter_config(cc, selected_food, selected_potion);
+        let active_profile = (
+            crafter_config.selected_job,
+            crafter_config.selected_profiles[crafter_config.selected_job as usize],
+        );
+
+        let mut app = Self {
             locale: load(cc, "LOCALE", Locale::EN),
             recipe_config: load(cc, "RECIPE_CONFIG", default_recipe_config),
-            selected_food: load(cc, "SELECTED_FOOD", None),
-            selected_potion: load(cc, "SELECTED_POTION", None),
-            crafter_config: load(cc, "CRAFTER_CONFIG", Default::default()),
+            selected_food,
+            selected_potion,
+            crafter_config,
+            active_profile,
             solver_config: load(cc, "SOLVER_CONFIG", Default::default()),
+            memory_limit: load(cc, "MEMORY_LIMIT", None),
             macro_view_config: load(cc, "MACRO_VIEW_CONFIG", Default::default()),
+            macro_library: load(cc, "MACRO_LIBRARY", Default::default()),
+            company_craft: load(cc, "COMPANY_CRAFT", None),
+            company_craft_macros: HashMap::new(),
+            feasibility_report: FeasibilityReport::default(),
 
             custom_recipe: load(cc, "CUSTOM_RECIPE", false),
             recipe_search_text: load(cc, "RECIPE_SEARCH_TEXT", Default::default()),
             food_search_text: load(cc, "FOOD_SEARCH_TEXT", Default::default()),
             potion_search_text: load(cc, "POTION_SEARCH_TEXT", Default::default()),
+            gear_search_text: load(cc, "GEAR_SEARCH_TEXT", Default::default()),
+
+            fonts,
+            requested_fonts: Vec::new(),
+            font_update: Default::default(),
+
+            share_rotation: true,
+            share_message: None,
 
             stats_edit_window_open: false,
+            gearset_edit_window_open: false,
+            company_craft_window_open: false,
+            feasibility_window_open: false,
             actions: Vec::new(),
             solver_pending: false,
+            quality_gap: None,
+            solver_stats: None,
             start_time: None,
             duration: None,
             data_update,
             bridge,
+        };
+        match take_shared_setup() {
+            Some(Ok(setup)) => app.apply_shared_setup(setup),
+            Some(Err(message)) => app.share_message = Some(format!("⚠ {message}")),
+            None => (),
+        }
+        app
+    }
+
+    fn apply_shared_setup(&mut self, setup: SharedSetup) {
+        let recipe = setup.recipe_config.recipe;
+        self.custom_recipe = !game_data::RECIPES.iter().any(|builtin| {
+            builtin.job_id == recipe.job_id
+                && builtin.item_id == recipe.item_id
+                && builtin.recipe_level == recipe.recipe_level
+        });
+        self.recipe_config = setup.recipe_config;
+        self.selected_food = setup.food;
+        self.selected_potion = setup.potion;
+        self.solver_config = setup.solver_config;
+        if let Some(actions) = setup.actions {
+            self.actions = actions;
+        }
+
+        // the shared stats go into a separate profile so that the user's own profiles are kept
+        let job_id = recipe.job_id;
+        let profile = CrafterProfile {
+            food: setup.food,
+            potion: setup.potion,
+            ..CrafterProfile::new(SHARED_PROFILE_NAME, setup.crafter_stats)
+        };
+        let profiles = &mut self.crafter_config.profiles[job_id as usize];
+        let index = match profiles.iter().position(|p| p.name == SHARED_PROFILE_NAME) {
+            Some(index) => {
+                profiles[index] = profile;
+                index
+            }
+            None => {
+                profiles.push(profile);
+                profiles.len() - 1
+            }
+        };
+        self.crafter_config.selected_job = job_id;
+        self.crafter_config.selected_profiles[job_id as usize] = index;
+        self.active_profile = (job_id, index);
+    }
+
+    fn copy_share_link(&mut self, ctx: &egui::Context) {
+        let setup = SharedSetup {
+            recipe_config: self.recipe_config,
+            crafter_stats: self.crafter_config.stats(),
+            food: self.selected_food,
+            potion: self.selected_potion,
+            solver_config: self.solver_config,
+            actions: match self.share_rotation && !self.actions.is_empty() {
+                true => Some(self.actions.clone()),
+                false => None,
+            },
+        };
+        let fragment = game_data::encode_share_fragment(&setup);
+        match web_sys::window().map(|window| window.location()) {
+            Some(location) => {
+                let origin = location.origin().unwrap_or_default();
+                let path = location.pathname().unwrap_or_default();
+                ctx.output_mut(|output| output.copied_text = format!("{origin}{path}#{fragment}"));
+                self.share_message = Some("Link copied to clipboard".to_string());
+            }
+            None => self.share_message = Some("⚠ Failed to create link".to_string()),
         }
     }
 }
 
+const SHARED_PROFILE_NAME: &str = "Shared link";
+
+/// Decodes the share link that the app was opened with, if any.
+/// The fragment is removed from the URL so that reloading the page doesn't overwrite later changes.
+fn take_shared_setup() -> Option<Result<SharedSetup, &'static str>> {
+    let window = web_sys::window()?;
+    let hash = window.location().hash().ok()?;
+    if hash.len() <= 1 {
+        return None;
+    }
+    let location = window.location();
+    let url = location.pathname().unwrap_or_default() + &location.search().unwrap_or_default();
+    if let Ok(history) = window.history() {
+        history
+            .replace_state_with_url(&web_sys::wasm_bindgen::JsValue::NULL, "", Some(&url))
+            .ok();
+    }
+    Some(game_data::decode_share_fragment(&hash))
+}
+
 impl eframe::App for MacroSolverApp {
     fn save(&mut self, storage: &mut dyn eframe::Storage) {
         eframe::set_value(storage, "LOCALE", &self.locale);
         eframe::set_value(storage, "RECIPE_CONFIG", &self.recipe_config);
         eframe::set_value(storage, "SELECTED_FOOD", &self.selected_food);
         eframe::set_value(storage, "SELECTED_POTION", &self.selected_potion);
-        eframe::set_value(storage, "CRAFTER_CONFIG", &self.crafter_config);
+        eframe::set_value(storage, "CRAFTER_PROFILES", &self.crafter_config);
         eframe::set_value(storage, "SOLVER_CONFIG", &self.solver_config);
+        eframe::set_value(storage, "MEMORY_LIMIT", &self.memory_limit);
         eframe::set_value(storage, "MACRO_VIEW_CONFIG", &self.macro_view_config);
+        eframe::set_value(storage, "MACRO_LIBRARY", &self.macro_library);
+        eframe::set_value(storage, "COMPANY_CRAFT", &self.company_craft);
 
         eframe::set_value(storage, "CUSTOM_RECIPE", &self.custom_recipe);
         eframe::set_value(storage, "RECIPE_SEARCH_TEXT", &self.recipe_search_text);
         eframe::set_value(storage, "FOOD_SEARCH_TEXT", &self.food_search_text);
         eframe::set_value(storage, "POTION_SEARCH_TEXT", &self.potion_search_text);
+        eframe::set_value(storage, "GEAR_SEARCH_TEXT", &self.gear_search_text);
     }
 
     fn auto_save_interval(&self) -> std::time::Duration {
@@ -131,14 +325,22 @@ impl eframe::App for MacroSolverApp {
 
     /// Called each time the UI needs repainting, which may be many times per second.
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        self.load_locale_font(ctx);
+
         if let Some(update) = self.data_update.take() {
             log::debug!("Received update: {update:?}");
-            (self.actions, self.solver_pending) = update.unwrap_or((Vec::new(), true));
+            self.actions = update.actions;
+            self.solver_pending = update.pending;
+            self.quality_gap = update.quality_gap;
+            self.solver_stats = update.stats;
             if !self.solver_pending {
                 self.duration = Some(Instant::now() - self.start_time.unwrap());
+                self.store_company_craft_macro();
             }
         }
 
+        self.apply_profile_consumables();
+
         egui::TopBottomPanel::top("top_panel").show(ctx, |ui| {
             egui::menu::bar(ui, |ui| {
                 ui.label(egui::RichText::new("Raphael  |  FFXIV Crafting Solver").strong());
@@ -167,6 +369,16 @@ impl eframe::App for MacroSolverApp {
                             Locale::JP,
                             format!("{}", Locale::JP),
                         );
+                        ui.selectable_value(
+                            &mut self.locale,
+                            Locale::KO,
+                            format!("{}", Locale::KO),
+                        );
+                        ui.selectable_value(
+                            &mut self.locale,
+                            Locale::CN,
+                            format!("{}", Locale::CN),
+                        );
                     });
 
                 egui::widgets::global_dark_light_mode_buttons(ui);
@@ -196,19 +408,30 @@ impl eframe::App for MacroSolverApp {
                     )
                     .open_in_new_tab(true),
                 );
-                ui.with_layout(
-                    Layout::right_to_left(Align::Center),
-                    egui::warn_if_debug_build,
-                );
+                ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                    egui::warn_if_debug_build(ui);
+                    if ui
+                        .button("Share")
+                        .on_hover_text("Copy a link to the current setup to the clipboard")
+                        .clicked()
+                    {
+                        self.copy_share_link(ui.ctx());
+                    }
+                    ui.checkbox(&mut self.share_rotation, "Include rotation");
+                    if let Some(message) = &self.share_message {
+                        ui.label(message);
+                    }
+                });
             });
         });
 
-        let game_settings = game_data::get_game_settings(
+        let game_settings = game_data::get_game_settings_for_version(
             self.recipe_config,
             self.crafter_config.stats(),
             self.selected_food,
             self.selected_potion,
             self.solver_config.adversarial,
+            self.solver_config.game_version,
         );
 
         egui::CentralPanel::default().show(ctx, |ui| {
@@ -216,15 +439,30 @@ impl eframe::App for MacroSolverApp {
                 ui.horizontal(|ui| {
                     ui.with_layout(Layout::top_down_justified(Align::TOP), |ui| {
                         ui.set_max_width(885.0);
-                        ui.add(Simulator::new(
-                            &game_settings,
-                            &self.crafter_config,
-                            &self.actions,
-                            game_data::ITEMS
-                                .get(&self.recipe_config.recipe.item_id)
-                                .unwrap(),
-                            self.locale,
-                        ));
+                        match &game_settings {
+                            Ok(game_settings) => {
+                                ui.add(Simulator::new(
+                                    game_settings,
+                                    &self.crafter_config,
+                                    &self.actions,
+                                    self.recipe_config.recipe.item_id,
+                                    game_data::ITEMS
+                                        .get(&self.recipe_config.recipe.item_id)
+                                        .unwrap(),
+                                    self.locale,
+                                ));
+                            }
+                            Err(message) => {
+                                ui.group(|ui| {
+                                    ui.label(egui::RichText::new("Simulation").strong());
+                                    ui.separator();
+                                    ui.label(
+                                        egui::RichText::new(format!("⚠ {message}"))
+                                            .color(ui.visuals().warn_fg_color),
+                                    );
+                                });
+                            }
+                        }
                         ui.add_space(5.5);
                         ui.horizontal(|ui| {
                             ui.vertical(|ui| {
@@ -251,8 +489,7 @@ impl eframe::App for MacroSolverApp {
                                         !self.solver_pending,
                                         ConsumableSelect::new(
                                             "Food",
-                                            self.crafter_config.crafter_stats
-                                                [self.crafter_config.selected_job as usize],
+                                            self.crafter_config.stats(),
                                             game_data::MEALS,
                                             &mut self.food_search_text,
                                             &mut self.selected_food,
@@ -268,8 +505,7 @@ impl eframe::App for MacroSolverApp {
                                         !self.solver_pending,
                                         ConsumableSelect::new(
                                             "Potion",
-                                            self.crafter_config.crafter_stats
-                                                [self.crafter_config.selected_job as usize],
+                                            self.crafter_config.stats(),
                                             game_data::POTIONS,
                                             &mut self.potion_search_text,
                                             &mut self.selected_potion,
@@ -288,7 +524,13 @@ impl eframe::App for MacroSolverApp {
                     });
                     ui.add_sized(
                         [320.0, 730.0],
-                        MacroView::new(&mut self.actions, &mut self.macro_view_config, self.locale),
+                        MacroView::new(
+                            &mut self.actions,
+                            &mut self.macro_view_config,
+                            &mut self.macro_library,
+                            game_settings.as_ref().ok(),
+                            self.locale,
+                        ),
                     );
                     // fill remaining horizontal space
                     ui.with_layout(Layout::right_to_left(Align::Center), |_| {});
@@ -307,12 +549,107 @@ impl eframe::App for MacroSolverApp {
         .collapsible(false)
         .resizable(false)
         .show(ctx, |ui| {
-            ui.add(StatsEdit::new(self.locale, &mut self.crafter_config));
+            ui.add(StatsEdit::new(
+                self.locale,
+                &mut self.crafter_config,
+                self.selected_food,
+                self.selected_potion,
+            ));
+        });
+
+        egui::Window::new(
+            egui::RichText::new("Edit gearset")
+                .strong()
+                .text_style(TextStyle::Body),
+        )
+        .open(&mut self.gearset_edit_window_open)
+        .collapsible(false)
+        .resizable(false)
+        .show(ctx, |ui| {
+            ui.add(GearsetEdit::new(
+                self.locale,
+                &mut self.crafter_config,
+                &mut self.gear_search_text,
+            ));
+        });
+
+        egui::Window::new(
+            egui::RichText::new("Company crafts")
+                .strong()
+                .text_style(TextStyle::Body),
+        )
+        .open(&mut self.company_craft_window_open)
+        .collapsible(false)
+        .resizable(false)
+        .show(ctx, |ui| {
+            ui.add_enabled(
+                !self.solver_pending,
+                CompanyCraftView::new(
+                    self.locale,
+                    &mut self.company_craft,
+                    &mut self.crafter_config.selected_job,
+                    &mut self.recipe_config,
+                    &mut self.custom_recipe,
+                    &self.company_craft_macros,
+                ),
+            );
+        });
+
+        egui::Window::new(
+            egui::RichText::new("Recipe report")
+                .strong()
+                .text_style(TextStyle::Body),
+        )
+        .open(&mut self.feasibility_window_open)
+        .collapsible(false)
+        .resizable(false)
+        .show(ctx, |ui| {
+            ui.add(FeasibilityTable::new(
+                self.locale,
+                &mut self.feasibility_report,
+                self.crafter_config.selected_job,
+                self.crafter_config.stats(),
+                self.selected_food,
+                self.selected_potion,
+                self.solver_config.game_version,
+            ));
         });
     }
 }
 
 impl MacroSolverApp {
+    /// Selects the default consumables of the active profile whenever a different profile is activated.
+    fn apply_profile_consumables(&mut self) {
+        let job_id = self.crafter_config.selected_job;
+        let active_profile = (
+            job_id,
+            self.crafter_config.selected_profiles[job_id as usize],
+        );
+        if active_profile != self.active_profile {
+            self.active_profile = active_profile;
+            self.selected_food = self.crafter_config.profile().food;
+            self.selected_potion = self.crafter_config.profile().potion;
+        }
+    }
+
+    /// Keeps the solved macro if the recipe belongs to a supply item of the selected company craft.
+    fn store_company_craft_macro(&mut self) {
+        let Some(sequence) = self.company_craft.and_then(get_company_craft) else {
+            return;
+        };
+        let recipe = self.recipe_config.recipe;
+        let is_supply = sequence
+            .parts
+            .iter()
+            .flat_map(|part| part.phases)
+            .flat_map(|phase| phase.supplies)
+            .any(|supply| supply.item_id == recipe.item_id);
+        if is_supply && !self.actions.is_empty() {
+            self.company_craft_macros
+                .insert((recipe.job_id, recipe.item_id), self.actions.clone());
+        }
+    }
+
     fn draw_configuration_widget(&mut self, ui: &mut egui::Ui) {
         ui.vertical(|ui| {
             ui.horizontal(|ui| {
@@ -322,6 +659,15 @@ impl MacroSolverApp {
                     if ui.button("Edit").clicked() {
                         self.stats_edit_window_open = true;
                     }
+                    if ui.button("Gearset").clicked() {
+                        self.gearset_edit_window_open = true;
+                    }
+                    if ui.button("Company crafts").clicked() {
+                        self.company_craft_window_open = true;
+                    }
+                    if ui.button("Report").clicked() {
+                        self.feasibility_window_open = true;
+                    }
                     egui::ComboBox::from_id_source("SELECTED_JOB")
                         .width(20.0)
                         .selected_text(get_job_name(self.crafter_config.selected_job, self.locale))
@@ -334,6 +680,17 @@ impl MacroSolverApp {
                                 );
                             }
                         });
+                    let job_id = self.crafter_config.selected_job as usize;
+                    let profiles = &self.crafter_config.profiles[job_id];
+                    let selected = &mut self.crafter_config.selected_profiles[job_id];
+                    egui::ComboBox::from_id_source("SELECTED_PROFILE")
+                        .width(60.0)
+                        .selected_text(profiles[*selected].name.as_str())
+                        .show_ui(ui, |ui| {
+                            for (index, profile) in profiles.iter().enumerate() {
+                                ui.selectable_value(selected, index, profile.name.as_str());
+                            }
+                        });
                 });
             });
             ui.separator();
@@ -445,60 +802,79 @@ impl MacroSolverApp {
                 ui.label("Target quality");
                 ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
                     ui.style_mut().spacing.item_spacing = [4.0, 4.0].into();
-                    let game_settings = game_data::get_game_settings(
-                        self.recipe_config,
-                        self.crafter_config.crafter_stats
-                            [self.crafter_config.selected_job as usize],
-                        self.selected_food,
-                        self.selected_potion,
-                        self.solver_config.adversarial,
-                    );
-                    let mut current_value = self
-                        .solver_config
-                        .quality_target
-                        .get_target(game_settings.max_quality);
-                    match &mut self.solver_config.quality_target {
-                        QualityTarget::Custom(value) => {
-                            ui.add(egui::DragValue::new(value));
-                        }
-                        _ => {
-                            ui.add_enabled(false, egui::DragValue::new(&mut current_value));
-                        }
-                    };
-                    egui::ComboBox::from_id_source("TARGET_QUALITY")
-                        .selected_text(format!("{}", self.solver_config.quality_target))
-                        .show_ui(ui, |ui| {
-                            ui.selectable_value(
-                                &mut self.solver_config.quality_target,
-                                QualityTarget::Zero,
-                                format!("{}", QualityTarget::Zero),
-                            );
-                            ui.selectable_value(
-                                &mut self.solver_config.quality_target,
-                                QualityTarget::CollectableT1,
-                                format!("{}", QualityTarget::CollectableT1),
-                            );
-                            ui.selectable_value(
-                                &mut self.solver_config.quality_target,
-                                QualityTarget::CollectableT2,
-                                format!("{}", QualityTarget::CollectableT2),
-                            );
-                            ui.selectable_value(
-                                &mut self.solver_config.quality_target,
-                                QualityTarget::CollectableT3,
-                                format!("{}", QualityTarget::CollectableT3),
-                            );
-                            ui.selectable_value(
-                                &mut self.solver_config.quality_target,
-                                QualityTarget::Full,
-                                format!("{}", QualityTarget::Full),
-                            );
-                            ui.selectable_value(
-                                &mut self.solver_config.quality_target,
-                                QualityTarget::Custom(current_value),
-                                format!("{}", QualityTarget::Custom(0)),
-                            )
-                        });
+                    if self.recipe_config.recipe.required_quality != 0 {
+                        // the recipe can only be completed by reaching its required quality
+                        let mut required_quality = self.recipe_config.recipe.required_quality;
+                        ui.add_enabled(false, egui::DragValue::new(&mut required_quality));
+                        ui.label("Required by recipe");
+                    } else {
+                        let item_id = self.recipe_config.recipe.item_id;
+                        let mut current_value = self
+                            .solver_config
+                            .quality_target
+                            .get_target(item_id, self.recipe_config.recipe.quality);
+                        match &mut self.solver_config.quality_target {
+                            QualityTarget::Custom(value) => {
+                                ui.add(egui::DragValue::new(value));
+                            }
+                            QualityTarget::Hq(hq) => {
+                                ui.add(
+                                    egui::DragValue::new(hq).clamp_range(0..=100).suffix("% HQ"),
+                                );
+                            }
+                            _ => {
+                                ui.add_enabled(false, egui::DragValue::new(&mut current_value));
+                            }
+                        };
+                        egui::ComboBox::from_id_source("TARGET_QUALITY")
+                            .selected_text(self.solver_config.quality_target.label(item_id))
+                            .show_ui(ui, |ui| {
+                                ui.selectable_value(
+                                    &mut self.solver_config.quality_target,
+                                    QualityTarget::Zero,
+                                    format!("{}", QualityTarget::Zero),
+                                );
+                                ui.selectable_value(
+                                    &mut self.solver_config.quality_target,
+                                    QualityTarget::CollectableT1,
+                                    QualityTarget::CollectableT1.label(item_id),
+                                );
+                                ui.selectable_value(
+                                    &mut self.solver_config.quality_target,
+                                    QualityTarget::CollectableT2,
+                                    QualityTarget::CollectableT2.label(item_id),
+                                );
+                                ui.selectable_value(
+                                    &mut self.solver_config.quality_target,
+                                    QualityTarget::CollectableT3,
+                                    QualityTarget::CollectableT3.label(item_id),
+                                );
+                                ui.selectable_value(
+                                    &mut self.solver_config.quality_target,
+                                    QualityTarget::Full,
+                                    format!("{}", QualityTarget::Full),
+                                );
+                                ui.selectable_value(
+                                    &mut self.solver_config.quality_target,
+                                    QualityTarget::Custom(current_value),
+                                    format!("{}", QualityTarget::Custom(0)),
+                                );
+                                ui.selectable_value(
+                                    &mut self.solver_config.quality_target,
+                                    QualityTarget::MaximizeHq,
+                                    format!("{}", QualityTarget::MaximizeHq),
+                                );
+                                let current_hq = match self.recipe_config.recipe.quality {
+                                    0 => 0,
+                                    max_quality => hq_percentage(current_value, max_quality),
+                                };
+                                ui.selectable_value(
+                                    &mut self.solver_config.quality_target,
+                                    QualityTarget::Hq(current_hq),
+                                    format!("{}", QualityTarget::Hq(0)),
+                                )
+                            });
+                    }
                 });
             });
             ui.horizontal(|ui| {
@@ -525,42 +901,107 @@ impl MacroSolverApp {
                         .color(ui.visuals().warn_fg_color),
                 );
             }
+            ui.horizontal(|ui| {
+                ui.label("Game version:");
+                egui::ComboBox::from_id_source("GAME_VERSION")
+                    .selected_text(format!("{}", self.solver_config.game_version))
+                    .show_ui(ui, |ui| {
+                        for game_version in [GameVersion::Endwalker, GameVersion::Dawntrail] {
+                            ui.selectable_value(
+                                &mut self.solver_config.game_version,
+                                game_version,
+                                format!("{}", game_version),
+                            );
+                        }
+                    });
+                ui.add(HelpText::new("Simulate and solve with the actions and formulas of an older game version.\nVersions before Endwalker are not supported."));
+            });
+            ui.horizontal(|ui| {
+                let mut limit_memory = self.memory_limit.is_some();
+                ui.checkbox(&mut limit_memory, "Limit memory usage");
+                self.memory_limit = match (limit_memory, self.memory_limit) {
+                    (true, None) => Some(1024),
+                    (true, memory_limit) => memory_limit,
+                    (false, _) => None,
+                };
+                if let Some(memory_limit) = &mut self.memory_limit {
+                    ui.add(
+                        egui::DragValue::new(memory_limit)
+                            .clamp_range(64..=4096)
+                            .suffix(" MB"),
+                    );
+                }
+                ui.add(HelpText::new("Keep the memory usage of the solver below the limit by recomputing data instead of storing it.\n  ⊟ Longer solve-time.\n  ⊟ If the limit is too low, the solver stops early and the result may not be optimal."));
+            });
 
             ui.add_space(5.5);
             ui.horizontal(|ui| {
                 ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
-                    if ui.button("Solve").clicked() {
+                    let game_settings = game_data::get_game_settings_for_version(
+                        self.recipe_config,
+                        self.crafter_config.stats(),
+                        self.selected_food,
+                        self.selected_potion,
+                        self.solver_config.adversarial,
+                        self.solver_config.game_version,
+                    );
+                    if ui
+                        .add_enabled(game_settings.is_ok(), egui::Button::new("Solve"))
+                        .clicked()
+                    {
                         self.solver_pending = true;
                         self.start_time = Some(Instant::now());
-                        let mut game_settings = game_data::get_game_settings(
-                            self.recipe_config,
-                            self.crafter_config.crafter_stats
-                                [self.crafter_config.selected_job as usize],
-                            self.selected_food,
-                            self.selected_potion,
-                            self.solver_config.adversarial,
-                    );
-                        let target_quality = self
-                            .solver_config
+                        let mut game_settings = game_settings.unwrap();
+                        let quality_targets =
+                            self.solver_config.quality_target.recipe_solver_targets(
+                                &self.recipe_config.recipe,
+                                game_settings.max_quality,
+                            );
+                        self.solver_config
                             .quality_target
-                            .get_target(game_settings.max_quality);
-                        game_settings.max_quality =
-                            std::cmp::max(game_settings.initial_quality, target_quality);
-                        self.bridge
-                            .send((game_settings, self.solver_config.backload_progress));
+                            .apply(&self.recipe_config.recipe, &mut game_settings);
+                        self.bridge.send(SolverInput {
+                            settings: game_settings,
+                            backload_progress: self.solver_config.backload_progress,
+                            quality_targets,
+                            memory_limit: self
+                                .memory_limit
+                                .map(|memory_limit| memory_limit as usize * 1024 * 1024),
+                        });
                         log::debug!("Message send {game_settings:?}");
                     }
                     if self.solver_pending {
                         ui.spinner();
+                        if let Some(quality_gap) = self.quality_gap {
+                            ui.label(format!(
+                                "Approximate solution, at most {quality_gap} Quality below optimal"
+                            ));
+                        }
                     } else if let Some(duration) = self.duration {
+                        if let Some(stats) = self.solver_stats {
+                            ui.label(format!(
+                                "{} nodes, {} MB peak memory",
+                                stats.expanded_nodes,
+                                stats.peak_memory / (1024 * 1024)
+                            ));
+                        }
                         ui.label(format!("Time: {:.3}s", duration.as_secs_f64()));
+                        if self.solver_stats.is_some_and(|stats| stats.memory_limit_reached) {
+                            let message = match self.quality_gap {
+                                Some(quality_gap) => format!("⚠ Memory limit reached, the solution may be up to {quality_gap} Quality below optimal"),
+                                None => "⚠ Memory limit reached, the solution may not be optimal".to_string(),
+                            };
+                            ui.label(
+                                egui::RichText::new(message).color(ui.visuals().warn_fg_color),
+                            );
+                        }
                     }
                 });
             });
         });
     }
 
-    fn load_fonts(ctx: &egui::Context) {
+    fn load_fonts(ctx: &egui::Context) -> FontDefinitions {
         let mut fonts = FontDefinitions::default();
         fonts.font_data.insert(
             String::from("japanese_monospace"),
@@ -578,15 +1019,73 @@ impl MacroSolverApp {
             .get_mut(&FontFamily::Monospace)
             .unwrap()
             .push("japanese_monospace".to_owned());
-        ctx.set_fonts(fonts);
+        ctx.set_fonts(fonts.clone());
+        fonts
     }
+
+    /// Fonts that are only needed for some locales are fetched at runtime to keep the binary small.
+    fn load_locale_font(&mut self, ctx: &egui::Context) {
+        let font_update = std::mem::take(&mut *self.font_update.lock().unwrap());
+        for (font_name, font_data) in font_update {
+            self.fonts
+                .font_data
+                .insert(font_name.to_owned(), FontData::from_owned(font_data));
+            for family in [FontFamily::Proportional, FontFamily::Monospace] {
+                self.fonts
+                    .families
+                    .get_mut(&family)
+                    .unwrap()
+                    .push(font_name.to_owned());
+            }
+            ctx.set_fonts(self.fonts.clone());
+        }
+
+        let font_name = match self.locale {
+            Locale::KO => "NotoSansKR-Regular.otf",
+            Locale::CN => "NotoSansSC-Regular.otf",
+            _ => return,
+        };
+        if self.requested_fonts.contains(&font_name) {
+            return;
+        }
+        self.requested_fonts.push(font_name);
+
+        let ctx = ctx.clone();
+        let font_update = self.font_update.clone();
+        let request = ehttp::Request::get(format!("{}/cjk-fonts/{}", env!("BASE_URL"), font_name));
+        ehttp::fetch(request, move |response| match response {
+            Ok(response) if response.ok => {
+                font_update
+                    .lock()
+                    .unwrap()
+                    .push((font_name, response.bytes));
+                ctx.request_repaint();
+            }
+            Ok(response) => log::error!(
+                "Failed to fetch {font_name}: {} {}",
+                response.status,
+                response.status_text
+            ),
+            Err(error) => log::error!("Failed to fetch {font_name}: {error}"),
+        });
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SolverInput {
+    settings: Settings,
+    backload_progress: bool,
+    /// Quality targets for `solvers::QualityTargetSolver`.
+    quality_targets: Option<Vec<u16>>,
+    /// Memory limit of the solver in bytes.
+    memory_limit: Option<usize>,
 }
 
 pub struct WebWorker {}
 
 impl gloo_worker::Worker for WebWorker {
     type Message = u64;
-    type Input = (Settings, bool);
+    type Input = SolverInput;
     type Output = MacroResult;
 
     fn create(_scope: &gloo_worker::WorkerScope<Self>) -> Self {
@@ -602,17 +1101,83 @@ impl gloo_worker::Worker for WebWorker {
         msg: Self::Input,
         _id: gloo_worker::HandlerId,
     ) {
-        let settings = msg.0;
-        let backload_progress = msg.1;
+        let settings = msg.settings;
+        let backload_progress = msg.backload_progress;
         let callback = |v: &[Action]| {
-            scope.respond(_id, Some((v.to_vec(), true)));
+            scope.respond(
+                _id,
+                MacroResult {
+                    actions: v.to_vec(),
+                    pending: true,
+                    quality_gap: None,
+                    stats: None,
+                },
+            );
         };
 
+        // quick approximate solution to show while the exact solver is running
+        let approximate_solution =
+            solvers::BeamSearchSolver::new(settings, solvers::BeamSearchSolver::DEFAULT_BEAM_WIDTH)
+                .solve(InProgress::new(&settings), backload_progress);
+        if let Some(solution) = &approximate_solution {
+            scope.respond(
+                _id,
+                MacroResult {
+                    actions: solution.actions.clone(),
+                    pending: true,
+                    quality_gap: Some(solution.quality_gap()),
+                    stats: None,
+                },
+            );
+        }
+
+        let mut stats = SolverStats::default();
+        let result = match msg.quality_targets {
+            Some(quality_targets) => {
+                let mut solver = solvers::QualityTargetSolver::new(
+                    settings,
+                    &quality_targets,
+                    Box::new(callback),
+                );
+                if let Some(memory_limit) = msg.memory_limit {
+                    solver = solver.with_memory_limit(memory_limit);
+                }
+                let result = solver.solve(backload_progress).map(|(_, actions)| actions);
+                for search_stats in solver.search_stats() {
+                    stats.add(search_stats);
+                }
+                result
+            }
+            None => {
+                let mut solver = solvers::MacroSolver::new(settings, Box::new(callback));
+                if let Some(memory_limit) = msg.memory_limit {
+                    solver = solver.with_memory_limit(memory_limit);
+                }
+                let result = solver.solve(InProgress::new(&settings), backload_progress);
+                stats.add(&solver.search_stats());
+                result
+            }
+        };
+        let (actions, quality_gap) = match (result, approximate_solution) {
+            (Some(actions), _) => (actions, None),
+            // the exact search stopped at the memory limit before finding a rotation
+            (None, Some(solution)) => {
+                let quality_gap = solution.quality_gap();
+                (solution.actions, Some(quality_gap))
+            }
+            (None, None) => (Vec::new(), None),
+        };
+        if stats.memory_limit_reached {
+            log::warn!("Memory limit reached, the result may not be optimal: {stats:?}");
+        }
         scope.respond(
             _id,
-            solvers::MacroSolver::new(settings, Box::new(callback))
-                .solve(InProgress::new(&settings), backload_progress)
-                .map(|v| (v, false)),
+            MacroResult {
+                actions,
+                pending: false,
+                quality_gap,
+                stats: Some(stats),
+            },
         );
     }
 }
@@ -3,6 +3,4 @@
 mod app;
 pub use app::{MacroSolverApp, WebWorker};
 
-mod config;
-mod utils;
 mod widgets;
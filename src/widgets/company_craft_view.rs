@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+
+use egui::Widget;
+use game_data::{
+    action_name, get_company_craft, get_item_name, get_job_name, CompanyCraftPhase, Locale,
+    RecipeConfiguration, COMPANY_CRAFTS,
+};
+use simulator::Action;
+
+use super::HelpText;
+
+/// Lists the parts and phases of a company craft and loads the recipes of their supply items.
+pub struct CompanyCraftView<'a> {
+    locale: Locale,
+    selected_sequence: &'a mut Option<u32>,
+    selected_job: &'a mut u8,
+    recipe_config: &'a mut RecipeConfiguration,
+    custom_recipe: &'a mut bool,
+    /// Macros that were solved for the supply items, keyed by job id and item id.
+    macros: &'a HashMap<(u8, u32), Vec<Action>>,
+}
+
+impl<'a> CompanyCraftView<'a> {
+    pub fn new(
+        locale: Locale,
+        selected_sequence: &'a mut Option<u32>,
+        selected_job: &'a mut u8,
+        recipe_config: &'a mut RecipeConfiguration,
+        custom_recipe: &'a mut bool,
+        macros: &'a HashMap<(u8, u32), Vec<Action>>,
+    ) -> Self {
+        Self {
+            locale,
+            selected_sequence,
+            selected_job,
+            recipe_config,
+            custom_recipe,
+            macros,
+        }
+    }
+
+    fn phase_ui(&mut self, ui: &mut egui::Ui, phase: &CompanyCraftPhase) {
+        for supply in phase.supplies {
+            ui.label(format!(
+                "{} x{}",
+                get_item_name(supply.item_id, false, self.locale),
+                supply.total_quantity()
+            ));
+        }
+        for job_id in phase.jobs() {
+            for (supply, recipe) in phase.recipes(job_id) {
+                ui.horizontal(|ui| {
+                    if ui
+                        .button(get_job_name(job_id, self.locale))
+                        .on_hover_text("Load the recipe")
+                        .clicked()
+                    {
+                        *self.selected_job = job_id;
+                        *self.recipe_config = RecipeConfiguration {
+                            recipe,
+                            hq_ingredients: [0; 6],
+                        };
+                        *self.custom_recipe = false;
+                    }
+                    ui.label(get_item_name(supply.item_id, false, self.locale));
+                    match self.macros.get(&(job_id, supply.item_id)) {
+                        Some(actions) => {
+                            let action_names: Vec<&str> = actions
+                                .iter()
+                                .map(|action| action_name(*action, self.locale))
+                                .collect();
+                            ui.label(egui::RichText::new(action_names.join(", ")).small());
+                        }
+                        None => {
+                            ui.label(egui::RichText::new("Not solved").weak());
+                        }
+                    }
+                });
+            }
+        }
+    }
+}
+
+impl<'a> Widget for CompanyCraftView<'a> {
+    fn ui(mut self, ui: &mut egui::Ui) -> egui::Response {
+        ui.vertical(|ui| {
+            ui.horizontal(|ui| {
+                ui.label("Project:");
+                let selected_text = match *self.selected_sequence {
+                    Some(item_id) => get_item_name(item_id, false, self.locale),
+                    None => "None".to_string(),
+                };
+                egui::ComboBox::from_id_source("COMPANY_CRAFT")
+                    .width(240.0)
+                    .selected_text(selected_text)
+                    .show_ui(ui, |ui| {
+                        for sequence in COMPANY_CRAFTS {
+                            ui.selectable_value(
+                                self.selected_sequence,
+                                Some(sequence.item_id),
+                                get_item_name(sequence.item_id, false, self.locale),
+                            );
+                        }
+                    });
+                ui.add(HelpText::new("Every supply item is crafted with its own recipe, phases don't have a Progress, Quality or durability of their own and supply items are accepted at any Quality.\nLoad a recipe and solve it, the macro is kept here for every supply item."));
+            });
+            ui.separator();
+
+            let Some(sequence) = self.selected_sequence.and_then(get_company_craft) else {
+                ui.label("No project selected");
+                return;
+            };
+            egui::ScrollArea::vertical()
+                .max_height(480.0)
+                .show(ui, |ui| {
+                    for (part_index, part) in sequence.parts.iter().enumerate() {
+                        ui.label(egui::RichText::new(format!("Part {}", part_index + 1)).strong());
+                        for (phase_index, phase) in part.phases.iter().enumerate() {
+                            ui.label(format!("Phase {}", phase_index + 1));
+                            self.phase_ui(ui, phase);
+                        }
+                        ui.separator();
+                    }
+                });
+        })
+        .response
+    }
+}
@@ -1,8 +1,6 @@
 use egui::{Align, Layout, Widget};
 use egui_extras::Column;
-use game_data::{get_item_name, Consumable, CrafterStats, Locale};
-
-use crate::utils::contains_noncontiguous;
+use game_data::{contains_noncontiguous, get_item_name, Consumable, CrafterStats, Locale};
 
 pub struct ConsumableSelect<'a> {
     title: &'static str,
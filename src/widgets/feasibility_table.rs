@@ -0,0 +1,242 @@
+use egui::{Align, Layout, Widget};
+use egui_extras::Column;
+use game_data::{
+    feasibility_recipes, get_item_name, recipe_feasibility, Consumable, CrafterStats, Locale,
+    Recipe, RecipeFeasibility,
+};
+use simulator::GameVersion;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SortColumn {
+    Item,
+    Level,
+    Quality,
+    Hq,
+    NeedsConsumables,
+}
+
+/// Recipes that are checked in the background, one per frame, so that the UI stays responsive.
+pub struct FeasibilityReport {
+    crafter_stats: CrafterStats,
+    food: Option<Consumable>,
+    potion: Option<Consumable>,
+    game_version: GameVersion,
+    /// Recipes that still need to be checked, in reverse order.
+    pending: Vec<Recipe>,
+    report: Vec<RecipeFeasibility>,
+    sort_column: SortColumn,
+    sort_descending: bool,
+}
+
+impl Default for FeasibilityReport {
+    fn default() -> Self {
+        Self {
+            crafter_stats: CrafterStats::default(),
+            food: None,
+            potion: None,
+            game_version: GameVersion::default(),
+            pending: Vec::new(),
+            report: Vec::new(),
+            sort_column: SortColumn::Level,
+            sort_descending: false,
+        }
+    }
+}
+
+impl FeasibilityReport {
+    fn start(
+        &mut self,
+        job_id: u8,
+        crafter_stats: CrafterStats,
+        food: Option<Consumable>,
+        potion: Option<Consumable>,
+        game_version: GameVersion,
+    ) {
+        self.crafter_stats = crafter_stats;
+        self.food = food;
+        self.potion = potion;
+        self.game_version = game_version;
+        self.pending = feasibility_recipes(job_id, crafter_stats.level)
+            .copied()
+            .collect();
+        self.pending.reverse();
+        self.report = Vec::with_capacity(self.pending.len());
+    }
+
+    /// Checks the next pending recipe. Returns false if there was nothing left to check.
+    fn step(&mut self) -> bool {
+        let Some(recipe) = self.pending.pop() else {
+            return false;
+        };
+        self.report.push(recipe_feasibility(
+            recipe,
+            self.crafter_stats,
+            self.food,
+            self.potion,
+            self.game_version,
+            false,
+        ));
+        true
+    }
+
+    fn sort(&mut self, locale: Locale) {
+        match self.sort_column {
+            SortColumn::Item => self
+                .report
+                .sort_by_cached_key(|entry| get_item_name(entry.recipe.item_id, false, locale)),
+            SortColumn::Level => self
+                .report
+                .sort_by_key(|entry| (entry.recipe.level, entry.recipe.recipe_level)),
+            SortColumn::Quality => self.report.sort_by_key(|entry| entry.feasibility.quality),
+            SortColumn::Hq => self.report.sort_by_key(|entry| entry.hq()),
+            SortColumn::NeedsConsumables => {
+                self.report.sort_by_key(|entry| entry.needs_consumables)
+            }
+        }
+        if self.sort_descending {
+            self.report.reverse();
+        }
+    }
+}
+
+/// Checks every recipe of the job up to the crafter's level and shows the results in a sortable table.
+/// Only the fast checks are run, so the Quality is an upper bound. The exact Quality is only available from the `feasibility` binary.
+pub struct FeasibilityTable<'a> {
+    locale: Locale,
+    state: &'a mut FeasibilityReport,
+    job_id: u8,
+    crafter_stats: CrafterStats,
+    food: Option<Consumable>,
+    potion: Option<Consumable>,
+    game_version: GameVersion,
+}
+
+impl<'a> FeasibilityTable<'a> {
+    pub fn new(
+        locale: Locale,
+        state: &'a mut FeasibilityReport,
+        job_id: u8,
+        crafter_stats: CrafterStats,
+        food: Option<Consumable>,
+        potion: Option<Consumable>,
+        game_version: GameVersion,
+    ) -> Self {
+        Self {
+            locale,
+            state,
+            job_id,
+            crafter_stats,
+            food,
+            potion,
+            game_version,
+        }
+    }
+
+    fn header_button(&mut self, ui: &mut egui::Ui, label: &str, column: SortColumn) {
+        let text = match (self.state.sort_column == column, self.state.sort_descending) {
+            (true, false) => format!("{label} ⏶"),
+            (true, true) => format!("{label} ⏷"),
+            (false, _) => label.to_string(),
+        };
+        if ui.button(text).clicked() {
+            self.state.sort_descending =
+                self.state.sort_column == column && !self.state.sort_descending;
+            self.state.sort_column = column;
+            self.state.sort(self.locale);
+        }
+    }
+}
+
+impl<'a> Widget for FeasibilityTable<'a> {
+    fn ui(mut self, ui: &mut egui::Ui) -> egui::Response {
+        if self.state.step() {
+            if self.state.pending.is_empty() {
+                self.state.sort(self.locale);
+            }
+            ui.ctx().request_repaint();
+        }
+        ui.vertical(|ui| {
+            ui.horizontal(|ui| {
+                if ui.button("Check recipes").clicked() {
+                    self.state.start(
+                        self.job_id,
+                        self.crafter_stats,
+                        self.food,
+                        self.potion,
+                        self.game_version,
+                    );
+                }
+                ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                    if !self.state.pending.is_empty() {
+                        ui.spinner();
+                        ui.label(format!(
+                            "Checked {}/{}",
+                            self.state.report.len(),
+                            self.state.report.len() + self.state.pending.len()
+                        ));
+                    }
+                });
+            });
+            ui.label(
+                egui::RichText::new("Quality is an upper bound, assuming no HQ ingredients.")
+                    .small(),
+            );
+            ui.separator();
+
+            let text_height = egui::TextStyle::Body
+                .resolve(ui.style())
+                .size
+                .max(ui.spacing().interact_size.y);
+            egui_extras::TableBuilder::new(ui)
+                .auto_shrink(false)
+                .striped(true)
+                .resizable(false)
+                .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+                .column(Column::exact(240.0))
+                .column(Column::auto())
+                .column(Column::auto())
+                .column(Column::auto())
+                .column(Column::remainder())
+                .min_scrolled_height(0.0)
+                .max_scroll_height(400.0)
+                .header(text_height, |mut header| {
+                    header.col(|ui| self.header_button(ui, "Item", SortColumn::Item));
+                    header.col(|ui| self.header_button(ui, "Level", SortColumn::Level));
+                    header.col(|ui| self.header_button(ui, "Quality", SortColumn::Quality));
+                    header.col(|ui| self.header_button(ui, "HQ", SortColumn::Hq));
+                    header.col(|ui| {
+                        self.header_button(ui, "Consumables", SortColumn::NeedsConsumables)
+                    });
+                })
+                .body(|body| {
+                    body.rows(text_height, self.state.report.len(), |mut row| {
+                        let entry = self.state.report[row.index()];
+                        row.col(|ui| {
+                            ui.label(get_item_name(entry.recipe.item_id, false, self.locale));
+                        });
+                        row.col(|ui| {
+                            ui.label(entry.recipe.level.to_string());
+                        });
+                        row.col(|ui| {
+                            ui.label(match entry.feasibility.can_complete {
+                                true => format!(
+                                    "{}/{}",
+                                    entry.feasibility.quality, entry.recipe.quality
+                                ),
+                                false => "Cannot complete".to_string(),
+                            });
+                        });
+                        row.col(|ui| {
+                            ui.label(format!("{}%", entry.hq()));
+                        });
+                        row.col(|ui| {
+                            if entry.needs_consumables {
+                                ui.label("Needed");
+                            }
+                        });
+                    });
+                });
+        })
+        .response
+    }
+}
@@ -0,0 +1,192 @@
+use egui::{Align, Layout, Widget};
+use egui_extras::Column;
+use game_data::{
+    contains_noncontiguous, get_gear, get_item_name, get_job_name, CrafterConfig, GearPiece,
+    Locale, GEAR, ITEMS, MATERIA,
+};
+
+pub struct GearsetEdit<'a> {
+    locale: Locale,
+    crafter_config: &'a mut CrafterConfig,
+    search_text: &'a mut String,
+}
+
+impl<'a> GearsetEdit<'a> {
+    pub fn new(
+        locale: Locale,
+        crafter_config: &'a mut CrafterConfig,
+        search_text: &'a mut String,
+    ) -> Self {
+        Self {
+            locale,
+            crafter_config,
+            search_text,
+        }
+    }
+
+    fn materia_name(&self, item_id: Option<u32>) -> String {
+        match item_id {
+            Some(item_id) => get_item_name(item_id, false, self.locale),
+            None => "None".to_string(),
+        }
+    }
+}
+
+impl<'a> Widget for GearsetEdit<'a> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let job_id = self.crafter_config.selected_job;
+        ui.vertical(|ui| {
+            ui.label(
+                egui::RichText::new(format!(
+                    "{} ({})",
+                    get_job_name(job_id, self.locale),
+                    self.crafter_config.profile().name
+                ))
+                .strong(),
+            );
+            ui.separator();
+
+            let mut removed_piece = None;
+            let gearset = &self.crafter_config.profile().gearset;
+            let mut pieces = gearset.pieces.clone();
+            for (index, piece) in pieces.iter_mut().enumerate() {
+                let Some(item) = get_gear(piece.item_id) else {
+                    removed_piece = Some(index);
+                    continue;
+                };
+                ui.horizontal(|ui| {
+                    if ui.button("Remove").clicked() {
+                        removed_piece = Some(index);
+                    }
+                    ui.label(get_item_name(piece.item_id, false, self.locale));
+                    if ITEMS.get(&piece.item_id).is_some_and(|item| item.can_be_hq) {
+                        ui.checkbox(&mut piece.hq, "HQ");
+                    }
+                });
+                ui.horizontal(|ui| {
+                    for slot in 0..item.max_melds() as usize {
+                        let mut selected = piece.melds.get(slot).copied();
+                        egui::ComboBox::from_id_source(("MELD", index, slot))
+                            .width(120.0)
+                            .selected_text(self.materia_name(selected))
+                            .show_ui(ui, |ui| {
+                                ui.selectable_value(&mut selected, None, "None");
+                                // only some grades can be used for advanced melding
+                                let materia = MATERIA.iter().rev().filter(|materia| {
+                                    slot < item.materia_slots as usize
+                                        || materia.can_be_overmelded()
+                                });
+                                for materia in materia {
+                                    ui.selectable_value(
+                                        &mut selected,
+                                        Some(materia.item_id),
+                                        self.materia_name(Some(materia.item_id)),
+                                    );
+                                }
+                            });
+                        match selected {
+                            Some(item_id) if slot < piece.melds.len() => {
+                                piece.melds[slot] = item_id
+                            }
+                            Some(item_id) => piece.melds.push(item_id),
+                            None => piece.melds.truncate(slot),
+                        }
+                        if slot >= piece.melds.len() {
+                            // materia have to be melded in order
+                            break;
+                        }
+                    }
+                });
+                ui.separator();
+            }
+            if let Some(index) = removed_piece {
+                pieces.remove(index);
+            }
+            self.crafter_config.profile_mut().gearset.pieces = pieces;
+
+            let level = self.crafter_config.level();
+            let manipulation = self.crafter_config.manipulation();
+            match self
+                .crafter_config
+                .profile()
+                .gearset
+                .crafter_stats(job_id, level, manipulation)
+            {
+                Ok(stats) => {
+                    ui.horizontal(|ui| {
+                        ui.label(format!(
+                            "Craftsmanship: {}, Control: {}, CP: {}",
+                            stats.craftsmanship, stats.control, stats.cp
+                        ));
+                        ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                            if ui.button("Apply").clicked() {
+                                self.crafter_config.profile_mut().crafter_stats = stats;
+                            }
+                        });
+                    });
+                }
+                Err(message) => {
+                    ui.label(
+                        egui::RichText::new(format!("⚠ {message}"))
+                            .color(ui.visuals().warn_fg_color),
+                    );
+                }
+            }
+            ui.separator();
+
+            ui.horizontal(|ui| {
+                ui.label("Search:");
+                ui.text_edit_singleline(self.search_text);
+            });
+            let search_pattern = self.search_text.to_lowercase();
+            let mut search_result: Vec<_> = GEAR
+                .iter()
+                .filter(|item| item.can_be_equipped_by(job_id) && item.equip_level <= level)
+                .filter(|item| {
+                    let item_name = get_item_name(item.item_id, false, self.locale);
+                    contains_noncontiguous(&item_name.to_lowercase(), &search_pattern)
+                })
+                .collect();
+            search_result.sort_by_key(|item| std::cmp::Reverse(item.item_level));
+
+            let text_height = egui::TextStyle::Body
+                .resolve(ui.style())
+                .size
+                .max(ui.spacing().interact_size.y);
+            let table = egui_extras::TableBuilder::new(ui)
+                .auto_shrink(false)
+                .striped(true)
+                .resizable(false)
+                .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+                .column(Column::auto())
+                .column(Column::exact(280.0))
+                .column(Column::remainder())
+                .max_scroll_height(240.0);
+            table.body(|body| {
+                body.rows(text_height, search_result.len(), |mut row| {
+                    let item = search_result[row.index()];
+                    row.col(|ui| {
+                        if ui.button("Add").clicked() {
+                            self.crafter_config
+                                .profile_mut()
+                                .gearset
+                                .pieces
+                                .push(GearPiece {
+                                    item_id: item.item_id,
+                                    hq: ITEMS.get(&item.item_id).is_some_and(|item| item.can_be_hq),
+                                    melds: Vec::new(),
+                                });
+                        }
+                    });
+                    row.col(|ui| {
+                        ui.label(get_item_name(item.item_id, false, self.locale));
+                    });
+                    row.col(|ui| {
+                        ui.label(format!("iLvl {}", item.item_level));
+                    });
+                });
+            });
+        })
+        .response
+    }
+}
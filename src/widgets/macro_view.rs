@@ -1,7 +1,9 @@
 use egui::{Align, Id, Layout, Widget};
-use game_data::{action_name, Locale};
+use game_data::{
+    action_name, export_rotation, import_rotation, Locale, MacroLibrary, RotationFormat, SavedMacro,
+};
 use serde::{Deserialize, Serialize};
-use simulator::Action;
+use simulator::{Action, GameVersion, Settings};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
 pub struct MacroViewConfig {
@@ -15,6 +17,8 @@ pub struct MacroViewConfig {
     notification_sound: u8,
     #[serde(default)]
     macro_lock: bool,
+    #[serde(default)]
+    rotation_format: RotationFormat,
 }
 
 impl Default for MacroViewConfig {
@@ -25,6 +29,7 @@ impl Default for MacroViewConfig {
             notification_enabled: false,
             notification_sound: 1,
             macro_lock: false,
+            rotation_format: RotationFormat::default(),
         }
     }
 }
@@ -94,6 +99,9 @@ impl Widget for MacroTextBox {
 pub struct MacroView<'a> {
     actions: &'a mut Vec<Action>,
     config: &'a mut MacroViewConfig,
+    library: &'a mut MacroLibrary,
+    /// Settings that the saved macros are simulated with, if the current configuration is valid.
+    settings: Option<&'a Settings>,
     locale: Locale,
 }
 
@@ -101,18 +109,202 @@ impl<'a> MacroView<'a> {
     pub fn new(
         actions: &'a mut Vec<Action>,
         config: &'a mut MacroViewConfig,
+        library: &'a mut MacroLibrary,
+        settings: Option<&'a Settings>,
         locale: Locale,
     ) -> Self {
         Self {
             actions,
             config,
+            library,
+            settings,
             locale,
         }
     }
 }
 
+impl<'a> MacroView<'a> {
+    fn draw_rotation_import_export(&mut self, ui: &mut egui::Ui) {
+        let text_id = Id::new("ROTATION_IMPORT_TEXT");
+        let message_id = Id::new("ROTATION_IMPORT_MESSAGE");
+        let mut text: String = ui.data(|data| data.get_temp(text_id).unwrap_or_default());
+        ui.horizontal(|ui| {
+            ui.label("Format:");
+            egui::ComboBox::from_id_source("ROTATION_FORMAT")
+                .selected_text(format!("{}", self.config.rotation_format))
+                .show_ui(ui, |ui| {
+                    for format in [RotationFormat::Teamcraft, RotationFormat::CraftOpt] {
+                        ui.selectable_value(
+                            &mut self.config.rotation_format,
+                            format,
+                            format!("{}", format),
+                        );
+                    }
+                });
+            ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                if ui
+                    .add_enabled(!text.is_empty(), egui::Button::new("Import"))
+                    .clicked()
+                {
+                    let game_version = self
+                        .settings
+                        .map_or(GameVersion::default(), |settings| settings.game_version);
+                    let imported =
+                        import_rotation(&text, self.config.rotation_format, game_version);
+                    let message = match imported {
+                        Ok(rotation) if rotation.unknown_actions.is_empty() => {
+                            *self.actions = rotation.actions;
+                            text.clear();
+                            None
+                        }
+                        Ok(rotation) => {
+                            let unknown_actions: Vec<String> = rotation
+                                .unknown_actions
+                                .iter()
+                                .map(|action| format!("{} (step {})", action.id, action.index + 1))
+                                .collect();
+                            *self.actions = rotation.actions;
+                            text.clear();
+                            Some(format!(
+                                "⚠ Skipped unknown actions: {}",
+                                unknown_actions.join(", ")
+                            ))
+                        }
+                        Err(message) => Some(format!("⚠ {message}")),
+                    };
+                    ui.data_mut(|data| match message {
+                        Some(message) => data.insert_temp(message_id, message),
+                        None => data.remove::<String>(message_id),
+                    });
+                }
+                if ui
+                    .add_enabled(!self.actions.is_empty(), egui::Button::new("Copy JSON"))
+                    .clicked()
+                {
+                    let json = export_rotation(self.actions, self.config.rotation_format);
+                    ui.output_mut(|output| output.copied_text = json);
+                }
+            });
+        });
+        ui.add(
+            egui::TextEdit::singleline(&mut text)
+                .hint_text("Paste a rotation to import")
+                .desired_width(f32::INFINITY),
+        );
+        if let Some(message) = ui.data(|data| data.get_temp::<String>(message_id)) {
+            ui.label(
+                egui::RichText::new(message)
+                    .small()
+                    .color(ui.visuals().warn_fg_color),
+            );
+        }
+        ui.data_mut(|data| data.insert_temp(text_id, text));
+    }
+}
+
+impl<'a> MacroView<'a> {
+    fn draw_macro_library(&mut self, ui: &mut egui::Ui) {
+        let name_id = Id::new("MACRO_LIBRARY_NAME");
+        let tags_id = Id::new("MACRO_LIBRARY_TAGS");
+        let filter_id = Id::new("MACRO_LIBRARY_FILTER");
+        let mut name: String = ui.data(|data| data.get_temp(name_id).unwrap_or_default());
+        let mut tags: String = ui.data(|data| data.get_temp(tags_id).unwrap_or_default());
+        let mut filter: String = ui.data(|data| data.get_temp(filter_id).unwrap_or_default());
+        ui.horizontal(|ui| {
+            ui.add(
+                egui::TextEdit::singleline(&mut name)
+                    .hint_text("Name")
+                    .desired_width(100.0),
+            );
+            ui.add(
+                egui::TextEdit::singleline(&mut tags)
+                    .hint_text("Tags, comma separated")
+                    .desired_width(120.0),
+            );
+            if ui
+                .add_enabled(
+                    !name.trim().is_empty() && !self.actions.is_empty(),
+                    egui::Button::new("Save"),
+                )
+                .on_hover_text("Save the current rotation to the library")
+                .clicked()
+            {
+                self.library.save(SavedMacro {
+                    name: name.trim().to_string(),
+                    tags: tags
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|tag| !tag.is_empty())
+                        .map(String::from)
+                        .collect(),
+                    actions: self.actions.clone(),
+                });
+                name.clear();
+                tags.clear();
+            }
+        });
+        ui.add(
+            egui::TextEdit::singleline(&mut filter)
+                .hint_text("Filter by tag")
+                .desired_width(f32::INFINITY),
+        );
+        match self.settings {
+            Some(settings) => {
+                let matches = self.library.find_working(settings);
+                let failing = self.library.macros.len() - matches.len();
+                let mut load = None;
+                let mut remove = None;
+                for m in matches.iter() {
+                    let saved_macro = &self.library.macros[m.index];
+                    if !filter.trim().is_empty() && !saved_macro.has_tag(filter.trim()) {
+                        continue;
+                    }
+                    ui.horizontal(|ui| {
+                        ui.label(&saved_macro.name)
+                            .on_hover_text(saved_macro.tags.join(", "));
+                        ui.label(format!(
+                            "{} Quality | {}% HQ | {}s",
+                            m.quality, m.hq, m.duration
+                        ));
+                        ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                            if ui.button("Delete").clicked() {
+                                remove = Some(saved_macro.name.clone());
+                            }
+                            if ui.button("Load").clicked() {
+                                load = Some(saved_macro.actions.clone());
+                            }
+                        });
+                    });
+                }
+                if let Some(actions) = load {
+                    *self.actions = actions;
+                }
+                if let Some(name) = remove {
+                    self.library.remove(&name);
+                }
+                if failing != 0 {
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "{failing} saved macro(s) don't complete this recipe"
+                        ))
+                        .small(),
+                    );
+                }
+            }
+            None => {
+                ui.label("Saved macros are shown once the configuration is valid");
+            }
+        }
+        ui.data_mut(|data| {
+            data.insert_temp(name_id, name);
+            data.insert_temp(tags_id, tags);
+            data.insert_temp(filter_id, filter);
+        });
+    }
+}
+
 impl<'a> Widget for MacroView<'a> {
-    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+    fn ui(mut self, ui: &mut egui::Ui) -> egui::Response {
         ui.group(|ui| {
             ui.vertical(|ui| {
                 ui.horizontal(|ui| {
@@ -159,6 +351,12 @@ impl<'a> Widget for MacroView<'a> {
                             });
                     });
                 });
+                egui::CollapsingHeader::new("Import / export").show(ui, |ui| {
+                    self.draw_rotation_import_export(ui);
+                });
+                egui::CollapsingHeader::new("Macro library").show(ui, |ui| {
+                    self.draw_macro_library(ui);
+                });
                 ui.separator();
                 let chunk_size = match self.config.split_macro {
                     true => {
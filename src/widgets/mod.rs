@@ -13,5 +13,14 @@ pub use recipe_select::RecipeSelect;
 mod stats_edit;
 pub use stats_edit::StatsEdit;
 
+mod gearset_edit;
+pub use gearset_edit::GearsetEdit;
+
 mod help_text;
 pub use help_text::HelpText;
+
+mod company_craft_view;
+pub use company_craft_view::CompanyCraftView;
+
+mod feasibility_table;
+pub use feasibility_table::{FeasibilityReport, FeasibilityTable};
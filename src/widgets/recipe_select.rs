@@ -1,8 +1,8 @@
 use egui::{Align, Layout, Widget};
 use egui_extras::Column;
-use game_data::{get_item_name, get_job_name, Ingredient, Locale, RecipeConfiguration, RLVLS};
-
-use crate::utils::contains_noncontiguous;
+use game_data::{
+    get_item_name, get_job_name, Ingredient, Locale, RecipeConfiguration, RecipeSearch, RLVLS,
+};
 
 pub struct RecipeSelect<'a> {
     selected_job: &'a mut u8,
@@ -36,18 +36,7 @@ impl<'a> RecipeSelect<'a> {
         });
         ui.separator();
 
-        let search_pattern = self.search_text.to_lowercase();
-        let search_result: Vec<usize> = game_data::RECIPES
-            .iter()
-            .enumerate()
-            .filter_map(|(index, recipe)| {
-                let item_name = get_item_name(recipe.item_id, false, self.locale);
-                match contains_noncontiguous(&item_name.to_lowercase(), &search_pattern) {
-                    true => Some(index),
-                    false => None,
-                }
-            })
-            .collect();
+        let search_result = RecipeSearch::new(self.search_text.as_str()).indices(self.locale);
 
         let text_height = egui::TextStyle::Body
             .resolve(ui.style())
@@ -87,6 +76,9 @@ impl<'a> RecipeSelect<'a> {
     fn draw_custom_recipe_select(self, ui: &mut egui::Ui) {
         self.recipe_config.recipe.item_id = 0;
         self.recipe_config.recipe.material_quality_factor = 0;
+        self.recipe_config.recipe.required_craftsmanship = 0;
+        self.recipe_config.recipe.required_control = 0;
+        self.recipe_config.recipe.required_quality = 0;
         self.recipe_config.recipe.ingredients = [Ingredient {
             item_id: 0,
             amount: 0,
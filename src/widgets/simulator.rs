@@ -1,8 +1,8 @@
 use egui::{Align, Color32, Layout, Rounding, Widget};
-use game_data::{action_name, get_job_name, Item, Locale};
-use simulator::{Action, Settings, SimulationState};
-
-use crate::config::{CrafterConfig, QualityTarget};
+use game_data::{
+    action_name, get_collectable, get_job_name, CrafterConfig, Item, Locale, QualityTarget,
+};
+use simulator::{lint_rotation, Action, LintKind, Settings, SimulationState};
 
 use super::HelpText;
 
@@ -10,6 +10,7 @@ pub struct Simulator<'a> {
     settings: &'a Settings,
     crafter_config: &'a CrafterConfig,
     actions: &'a [Action],
+    item_id: u32,
     item: &'a Item,
     locale: Locale,
 }
@@ -19,6 +20,7 @@ impl<'a> Simulator<'a> {
         settings: &'a Settings,
         crafter_config: &'a CrafterConfig,
         actions: &'a [Action],
+        item_id: u32,
         item: &'a Item,
         locale: Locale,
     ) -> Self {
@@ -26,12 +28,40 @@ impl<'a> Simulator<'a> {
             settings,
             crafter_config,
             actions,
+            item_id,
             item,
             locale,
         }
     }
 }
 
+fn lint_message(kind: LintKind, locale: Locale) -> String {
+    match kind {
+        LintKind::UnusedEffect(action) => {
+            format!(
+                "{} wears off without being used",
+                action_name(action, locale)
+            )
+        }
+        LintKind::OverwrittenEffect(action) => format!(
+            "{} is overwritten before being used",
+            action_name(action, locale)
+        ),
+        LintKind::InnerQuietOvercap { lost_stacks } => {
+            format!("{lost_stacks} Inner Quiet stack(s) lost to the cap")
+        }
+        LintKind::WastedRepair { durability } => {
+            format!("{durability} Durability restored above the maximum")
+        }
+        LintKind::LeftoverCp { cp } => format!("{cp} CP left over while Quality is not maxed"),
+        LintKind::ProgressOvershoot { overshoot } => format!(
+            "Progress overshoots by {overshoot}, {} would have been enough",
+            action_name(Action::BasicSynthesis, locale)
+        ),
+        LintKind::RemovableStep => "Step can be removed without losing Progress or Quality".into(),
+    }
+}
+
 impl<'a> Widget for Simulator<'a> {
     fn ui(self, ui: &mut egui::Ui) -> egui::Response {
         let (game_state, errors) = SimulationState::from_macro_continue_on_error(
@@ -42,6 +72,8 @@ impl<'a> Widget for Simulator<'a> {
             self.actions,
         );
 
+        let lints = lint_rotation(self.settings, self.actions);
+
         let max_progress = self.settings.max_progress;
         let clamped_progress = self.settings.max_progress - game_state.missing_progress;
 
@@ -117,13 +149,25 @@ impl<'a> Widget for Simulator<'a> {
                             } else {
                                 "Calculated assuming Normal conditon on every step"
                             }));
-                            if self.item.is_collectable {
+                            if let Some(collectable) = get_collectable(self.item_id) {
+                                let tier = collectable.tier(quality);
+                                let scrips = match tier {
+                                    0 => 0,
+                                    tier => collectable.tiers[tier - 1].scrips,
+                                };
+                                ui.label(
+                                    egui::RichText::new(format!(
+                                        "Tier {tier} collectable reached ({scrips} scrips)"
+                                    ))
+                                    .strong(),
+                                );
+                            } else if self.item.is_collectable {
                                 let t1 = QualityTarget::CollectableT1
-                                    .get_target(self.settings.max_quality);
+                                    .get_target(self.item_id, self.settings.max_quality);
                                 let t2 = QualityTarget::CollectableT2
-                                    .get_target(self.settings.max_quality);
+                                    .get_target(self.item_id, self.settings.max_quality);
                                 let t3 = QualityTarget::CollectableT3
-                                    .get_target(self.settings.max_quality);
+                                    .get_target(self.item_id, self.settings.max_quality);
                                 let tier = match quality {
                                     quality if quality >= t3 => 3,
                                     quality if quality >= t2 => 2,
@@ -140,6 +184,11 @@ impl<'a> Widget for Simulator<'a> {
                                     _ => 0,
                                 };
                                 ui.label(egui::RichText::new(format!("{hq}% HQ")).strong());
+                                if let Some((missing_quality, next_hq)) =
+                                    game_data::next_hq_breakpoint(clamped_quality, max_quality)
+                                {
+                                    ui.label(format!("+{missing_quality} quality for {next_hq}%"));
+                                }
                             }
                         });
                     });
@@ -151,26 +200,44 @@ impl<'a> Widget for Simulator<'a> {
                     ui.set_height(30.0);
                     ui.set_width(ui.available_width());
                     ui.horizontal(|ui| {
-                        for (action, error) in self.actions.iter().zip(errors.into_iter()) {
+                        for (step, (action, error)) in
+                            self.actions.iter().zip(errors.into_iter()).enumerate()
+                        {
                             let image_path = format!(
                                 "{}/action-icons/{}/{}.png",
                                 env!("BASE_URL"),
                                 get_job_name(self.crafter_config.selected_job, Locale::EN),
                                 action_name(*action, Locale::EN)
                             );
+                            let mut hover_text = action_name(*action, self.locale).to_owned();
+                            let mut has_lints = false;
+                            for lint in lints.iter().filter(|lint| lint.step == step) {
+                                hover_text.push('\n');
+                                hover_text.push_str(&lint_message(lint.kind, self.locale));
+                                has_lints = true;
+                            }
                             ui.add(
                                 egui::Image::new(image_path)
                                     .fit_to_exact_size(egui::Vec2::new(30.0, 30.0))
                                     .rounding(4.0)
                                     .tint(match error {
+                                        Ok(_) if has_lints => Color32::from_rgb(255, 200, 96),
                                         Ok(_) => Color32::WHITE,
                                         Err(_) => Color32::from_rgb(255, 96, 96),
                                     }),
                             )
-                            .on_hover_text(action_name(*action, self.locale));
+                            .on_hover_text(hover_text);
                         }
                     });
                 });
+                for lint in lints.iter() {
+                    ui.label(format!(
+                        "{}. {}: {}",
+                        lint.step + 1,
+                        action_name(self.actions[lint.step], self.locale),
+                        lint_message(lint.kind, self.locale)
+                    ));
+                }
             });
         })
         .response
@@ -1,37 +1,125 @@
-use egui::Widget;
-use game_data::{get_job_name, Locale};
-
-use crate::config::CrafterConfig;
+use egui::{Align, Layout, Widget};
+use game_data::{get_item_name, get_job_name, Consumable, CrafterConfig, Locale};
+use web_sys::wasm_bindgen::{JsCast, JsValue};
 
 pub struct StatsEdit<'a> {
     locale: Locale,
     crafter_config: &'a mut CrafterConfig,
+    selected_food: Option<Consumable>,
+    selected_potion: Option<Consumable>,
 }
 
 impl<'a> StatsEdit<'a> {
-    pub fn new(locale: Locale, crafter_config: &'a mut CrafterConfig) -> Self {
+    pub fn new(
+        locale: Locale,
+        crafter_config: &'a mut CrafterConfig,
+        selected_food: Option<Consumable>,
+        selected_potion: Option<Consumable>,
+    ) -> Self {
         Self {
             locale,
             crafter_config,
+            selected_food,
+            selected_potion,
+        }
+    }
+
+    fn consumable_name(&self, consumable: Option<Consumable>) -> String {
+        match consumable {
+            Some(item) => get_item_name(item.item_id, item.hq, self.locale),
+            None => "None".to_string(),
         }
     }
 }
 
+/// Lets the browser download `contents` as a file.
+fn download_file(file_name: &str, contents: &str) -> Result<(), JsValue> {
+    let parts = web_sys::js_sys::Array::of1(&JsValue::from_str(contents));
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type("application/json");
+    let blob = web_sys::Blob::new_with_str_sequence_and_options(&parts, &options)?;
+    let url = web_sys::Url::create_object_url_with_blob(&blob)?;
+    let document = web_sys::window()
+        .and_then(|window| window.document())
+        .ok_or_else(|| JsValue::from_str("no document"))?;
+    let anchor: web_sys::HtmlAnchorElement = document.create_element("a")?.dyn_into()?;
+    anchor.set_href(&url);
+    anchor.set_download(file_name);
+    anchor.click();
+    web_sys::Url::revoke_object_url(&url)
+}
+
 impl<'a> Widget for StatsEdit<'a> {
     fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let message_id = egui::Id::new("PROFILE_IMPORT_MESSAGE");
+        let dropped_files = ui.ctx().input(|i| i.raw.dropped_files.clone());
+        for file in dropped_files {
+            let message = match file.bytes {
+                Some(bytes) => match std::str::from_utf8(&bytes) {
+                    Ok(json) => match self.crafter_config.import_profiles(json) {
+                        Ok(count) => format!("Imported {count} profile(s)"),
+                        Err(message) => format!("⚠ {message}"),
+                    },
+                    Err(_) => "⚠ Invalid profile file".to_string(),
+                },
+                None => "⚠ Failed to read file".to_string(),
+            };
+            ui.data_mut(|data| data.insert_temp(message_id, message));
+        }
+
         ui.vertical(|ui| {
-            for job_id in 0..8 {
-                if job_id != 0 {
-                    ui.separator();
+            ui.horizontal(|ui| {
+                if ui.button("Export profiles").clicked() {
+                    let json = self.crafter_config.export_profiles();
+                    if download_file("crafter_profiles.json", &json).is_err() {
+                        log::error!("Failed to export crafter profiles");
+                    }
                 }
+                ui.label("Drop an exported file here to import profiles");
+            });
+            if let Some(message) = ui.data(|data| data.get_temp::<String>(message_id)) {
+                ui.label(egui::RichText::new(message).small());
+            }
+            for job_id in 0..8 {
+                ui.separator();
                 ui.horizontal(|ui| {
                     ui.label(egui::RichText::new(get_job_name(job_id, self.locale)).strong());
-                    if ui.button("Copy to all").clicked() {
-                        let stats = self.crafter_config.crafter_stats[job_id as usize];
-                        self.crafter_config.crafter_stats = [stats; 8];
-                    }
+                    let profiles = &self.crafter_config.profiles[job_id as usize];
+                    let selected = &mut self.crafter_config.selected_profiles[job_id as usize];
+                    egui::ComboBox::from_id_source(("PROFILE", job_id))
+                        .width(120.0)
+                        .selected_text(profiles[*selected].name.as_str())
+                        .show_ui(ui, |ui| {
+                            for (index, profile) in profiles.iter().enumerate() {
+                                ui.selectable_value(selected, index, profile.name.as_str());
+                            }
+                        });
+                    ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                        if ui.button("Copy to all").clicked() {
+                            let stats = self.crafter_config.job_profile(job_id).crafter_stats;
+                            for job_id in 0..8 {
+                                self.crafter_config.job_profile_mut(job_id).crafter_stats = stats;
+                            }
+                        }
+                        let profile_count = self.crafter_config.profiles[job_id as usize].len();
+                        if ui
+                            .add_enabled(profile_count > 1, egui::Button::new("Delete"))
+                            .clicked()
+                        {
+                            self.crafter_config.remove_profile(job_id);
+                        }
+                        if ui.button("Duplicate").clicked() {
+                            let name = format!("Profile {}", profile_count + 1);
+                            self.crafter_config.duplicate_profile(job_id, name);
+                        }
+                    });
                 });
-                let stats = &mut self.crafter_config.crafter_stats[job_id as usize];
+                let profile = self.crafter_config.job_profile_mut(job_id);
+                ui.horizontal(|ui| {
+                    ui.label("Name:");
+                    ui.text_edit_singleline(&mut profile.name);
+                });
+                let stats = &mut profile.crafter_stats;
                 ui.horizontal(|ui| {
                     ui.label("Craftsmanship:");
                     ui.add(egui::DragValue::new(&mut stats.craftsmanship));
@@ -45,6 +133,21 @@ impl<'a> Widget for StatsEdit<'a> {
                 ui.horizontal(|ui| {
                     ui.checkbox(&mut stats.manipulation, "Manipulation");
                 });
+                let (food, potion) = (profile.food, profile.potion);
+                ui.horizontal(|ui| {
+                    ui.label(format!(
+                        "Food: {}, Potion: {}",
+                        self.consumable_name(food),
+                        self.consumable_name(potion)
+                    ));
+                    ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                        if ui.button("Use selected consumables").clicked() {
+                            let profile = self.crafter_config.job_profile_mut(job_id);
+                            profile.food = self.selected_food;
+                            profile.potion = self.selected_potion;
+                        }
+                    });
+                });
             }
         })
         .response